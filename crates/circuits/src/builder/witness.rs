@@ -4,12 +4,13 @@ use std::{cell::RefCell, marker::PhantomData, rc::Rc};
 
 use anyhow::{Error, anyhow};
 use binius_core::{
-	oracle::{MultilinearOracleSet, OracleId},
+	oracle::{MultilinearOracleSet, MultilinearPolyVariant, OracleId},
 	witness::{MultilinearExtensionIndex, MultilinearWitness},
 };
 use binius_field::{
 	ExtensionField, PackedField, TowerField,
 	as_packed_field::{PackScalar, PackedType},
+	packed::{get_packed_slice, len_packed_slice, set_packed_slice},
 	underlier::WithUnderlier,
 };
 use binius_math::MultilinearExtension;
@@ -165,6 +166,45 @@ impl<'arena> Builder<'arena> {
 		Ok(())
 	}
 
+	/// Fills the witness of a repeating oracle by tiling the witness of its inner oracle.
+	///
+	/// The witness of the inner oracle must already be set with tower level `FS`. The data is
+	/// copied, because witnesses must be contiguous, but whole packed elements are copied at once
+	/// when the inner oracle spans at least one of them.
+	pub fn fill_repeating<FS: TowerField>(&self, id: OracleId) -> Result<(), Error>
+	where
+		U: PackScalar<FS>,
+		F: ExtensionField<FS>,
+	{
+		let (inner_id, inner_n_vars) = {
+			let oracles = self.oracles.borrow();
+			if !oracles.is_valid_oracle_id(id) {
+				bail!(anyhow!("OracleId {id} does not exist in MultilinearOracleSet"));
+			}
+			let MultilinearPolyVariant::Repeating { id: inner_id, .. } = oracles[id].variant else {
+				bail!(anyhow!("{} is not a repeating oracle", oracles.label(id)));
+			};
+			(inner_id, oracles.n_vars(inner_id))
+		};
+
+		let inner = self.get::<FS>(inner_id)?;
+		let inner_packed = inner.packed();
+
+		let mut entry = self.new_column::<FS>(id);
+		let packed = entry.packed();
+		if inner_n_vars >= PackedType::<U, FS>::LOG_WIDTH {
+			for chunk in packed.chunks_exact_mut(inner_packed.len()) {
+				chunk.copy_from_slice(inner_packed);
+			}
+		} else {
+			for i in 0..len_packed_slice(packed) {
+				let value = get_packed_slice(inner_packed, i % (1 << inner_n_vars));
+				set_packed_slice(packed, i, value);
+			}
+		}
+		Ok(())
+	}
+
 	pub fn build(self) -> Result<MultilinearExtensionIndex<'arena, PackedType<U, F>>, Error> {
 		let mut result = MultilinearExtensionIndex::new();
 		let entries = Rc::into_inner(self.entries)
@@ -290,3 +330,73 @@ where
 		})
 	}
 }
+
+#[cfg(test)]
+mod tests {
+	use binius_field::{BinaryField1b, BinaryField8b, Field};
+	use binius_math::MultilinearQuery;
+	use rand::{SeedableRng, rngs::StdRng};
+
+	use super::*;
+	use crate::builder::ConstraintSystemBuilder;
+
+	fn check_fill_repeating<FS>(inner_n_vars: usize, log_count: usize)
+	where
+		FS: TowerField,
+		U: PackScalar<FS>,
+		F: ExtensionField<FS>,
+	{
+		let mut rng = StdRng::seed_from_u64(0);
+		let allocator = bumpalo::Bump::new();
+		let mut builder = ConstraintSystemBuilder::new_with_witness(&allocator);
+
+		let inner_id = builder.add_committed("inner", inner_n_vars, FS::TOWER_LEVEL);
+		let repeating_id = builder
+			.add_repeating("repeating", inner_id, log_count)
+			.unwrap();
+
+		let witness = builder.witness().unwrap();
+		witness
+			.new_column::<FS>(inner_id)
+			.packed()
+			.iter_mut()
+			.for_each(|packed| *packed = PackedField::random(&mut rng));
+		witness.fill_repeating::<FS>(repeating_id).unwrap();
+
+		let witness = builder.take_witness().unwrap();
+		let inner = witness.get_multilin_poly(inner_id).unwrap();
+		let repeating = witness.get_multilin_poly(repeating_id).unwrap();
+		assert_eq!(repeating.n_vars(), inner_n_vars + log_count);
+
+		let point = (0..inner_n_vars + log_count)
+			.map(|_| <F as Field>::random(&mut rng))
+			.collect::<Vec<_>>();
+		let repeating_eval = repeating
+			.evaluate(MultilinearQuery::expand(&point).to_ref())
+			.unwrap();
+		let inner_eval = inner
+			.evaluate(MultilinearQuery::expand(&point[..inner_n_vars]).to_ref())
+			.unwrap();
+		assert_eq!(repeating_eval, inner_eval);
+	}
+
+	#[test]
+	fn test_fill_repeating() {
+		check_fill_repeating::<BinaryField1b>(10, 2);
+		check_fill_repeating::<BinaryField8b>(2, 3);
+	}
+
+	#[test]
+	fn test_fill_repeating_requires_repeating_oracle() {
+		let allocator = bumpalo::Bump::new();
+		let mut builder = ConstraintSystemBuilder::new_with_witness(&allocator);
+		let id = builder.add_committed("committed", 4, BinaryField8b::TOWER_LEVEL);
+		assert!(
+			builder
+				.witness()
+				.unwrap()
+				.fill_repeating::<BinaryField8b>(id)
+				.is_err()
+		);
+	}
+}
@@ -0,0 +1,126 @@
+// Copyright 2025 Irreducible Inc.
+
+//! A reproducible random number generator for witness generation.
+
+use binius_utils::{
+	DeserializeBytes, SerializationError, SerializationMode, SerializeBytes,
+	bytes::{Buf, BufMut},
+	serialization::{assert_enough_data_for, assert_enough_space_for},
+};
+use rand::{CryptoRng, RngCore, SeedableRng};
+use rand_chacha::ChaCha12Rng;
+
+/// A seedable RNG whose position in the random stream can be serialized and restored.
+///
+/// The RNG produces the same stream as [`rand::rngs::StdRng`] seeded identically. Serializing it
+/// while a witness is being generated, for example when a proof fails, and deserializing it on
+/// another machine continues the identical sequence, so that the witness can be regenerated
+/// exactly.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct WitnessRng(ChaCha12Rng);
+
+impl WitnessRng {
+	const SERIALIZED_SIZE: usize = 32 + size_of::<u64>() + size_of::<u128>();
+}
+
+impl RngCore for WitnessRng {
+	fn next_u32(&mut self) -> u32 {
+		self.0.next_u32()
+	}
+
+	fn next_u64(&mut self) -> u64 {
+		self.0.next_u64()
+	}
+
+	fn fill_bytes(&mut self, dest: &mut [u8]) {
+		self.0.fill_bytes(dest)
+	}
+
+	fn try_fill_bytes(&mut self, dest: &mut [u8]) -> Result<(), rand::Error> {
+		self.0.try_fill_bytes(dest)
+	}
+}
+
+impl CryptoRng for WitnessRng {}
+
+impl SeedableRng for WitnessRng {
+	type Seed = <ChaCha12Rng as SeedableRng>::Seed;
+
+	fn from_seed(seed: Self::Seed) -> Self {
+		Self(ChaCha12Rng::from_seed(seed))
+	}
+}
+
+impl SerializeBytes for WitnessRng {
+	fn serialize(
+		&self,
+		mut write_buf: impl BufMut,
+		mode: SerializationMode,
+	) -> Result<(), SerializationError> {
+		assert_enough_space_for(&write_buf, Self::SERIALIZED_SIZE)?;
+		write_buf.put_slice(&self.0.get_seed());
+		self.0.get_stream().serialize(&mut write_buf, mode)?;
+		self.0.get_word_pos().serialize(write_buf, mode)
+	}
+}
+
+impl DeserializeBytes for WitnessRng {
+	fn deserialize(
+		mut read_buf: impl Buf,
+		mode: SerializationMode,
+	) -> Result<Self, SerializationError>
+	where
+		Self: Sized,
+	{
+		assert_enough_data_for(&read_buf, Self::SERIALIZED_SIZE)?;
+		let mut seed = <Self as SeedableRng>::Seed::default();
+		read_buf.copy_to_slice(&mut seed);
+		let mut rng = ChaCha12Rng::from_seed(seed);
+		rng.set_stream(u64::deserialize(&mut read_buf, mode)?);
+		rng.set_word_pos(u128::deserialize(read_buf, mode)?);
+		Ok(Self(rng))
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use std::iter::repeat_with;
+
+	use binius_field::{BinaryField128b, Field};
+	use rand::rngs::StdRng;
+
+	use super::*;
+
+	#[test]
+	fn test_matches_std_rng() {
+		let mut rng = WitnessRng::seed_from_u64(0);
+		let mut std_rng = StdRng::seed_from_u64(0);
+		for _ in 0..100 {
+			assert_eq!(rng.next_u64(), std_rng.next_u64());
+		}
+	}
+
+	#[test]
+	fn test_serialization_continues_sequence() {
+		let mut rng = WitnessRng::seed_from_u64(0);
+		// Draw an odd number of words to restore from the middle of a ChaCha block.
+		let _ = rng.next_u32();
+		let _ = repeat_with(|| BinaryField128b::random(&mut rng))
+			.take(37)
+			.collect::<Vec<_>>();
+
+		let mut buf = Vec::new();
+		rng.serialize(&mut buf, SerializationMode::Native).unwrap();
+		let mut restored =
+			WitnessRng::deserialize(buf.as_slice(), SerializationMode::Native).unwrap();
+		assert_eq!(restored, rng);
+
+		let expected = repeat_with(|| BinaryField128b::random(&mut rng))
+			.take(100)
+			.collect::<Vec<_>>();
+		let values = repeat_with(|| BinaryField128b::random(&mut restored))
+			.take(100)
+			.collect::<Vec<_>>();
+		assert_eq!(values, expected);
+	}
+}
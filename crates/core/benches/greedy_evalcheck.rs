@@ -0,0 +1,259 @@
+// Copyright 2025 Irreducible Inc.
+
+use std::{
+	iter::{self, repeat_with},
+	num::NonZeroUsize,
+};
+
+use binius_core::{
+	fiat_shamir::HasherChallenger,
+	oracle::{MultilinearOracleSet, ShiftVariant},
+	protocols::{
+		evalcheck::EvalcheckMultilinearClaim,
+		greedy_evalcheck::{AdaptiveSwitchover, Switchover, prove, prove_with_switchover},
+		sumcheck::standard_switchover_heuristic,
+	},
+	transcript::ProverTranscript,
+	witness::MultilinearExtensionIndex,
+};
+use binius_field::{
+	BinaryField1b, BinaryField8b, BinaryField128b, Field, PackedBinaryField1x128b,
+	PackedBinaryField128x1b, TowerField, packed::pack_slice,
+};
+use binius_hal::{ComputationBackendExt, make_portable_backend};
+use binius_hash::groestl::Groestl256;
+use binius_math::{ArithCircuit, DefaultEvaluationDomainFactory, MultilinearExtension};
+use criterion::{BatchSize, Criterion, Throughput, criterion_group, criterion_main};
+use rand::{SeedableRng, rngs::StdRng};
+
+type F = BinaryField128b;
+type P = PackedBinaryField1x128b;
+type PBits = PackedBinaryField128x1b;
+type FDomain = BinaryField8b;
+
+const N_VARS: usize = 16;
+const N_SHIFTS: usize = 4;
+
+/// Evaluation claims on several shifts of a committed bit column, which are reduced with
+/// bivariate sumchecks over 1-bit multilinears.
+fn shifted_bits_instance() -> (
+	MultilinearOracleSet<F>,
+	MultilinearExtensionIndex<'static, P>,
+	Vec<EvalcheckMultilinearClaim<F>>,
+) {
+	let mut rng = StdRng::seed_from_u64(0);
+	let backend = make_portable_backend();
+
+	let bits = (0..1 << N_VARS)
+		.map(|_| <BinaryField1b as Field>::random(&mut rng))
+		.collect::<Vec<_>>();
+
+	let mut oracles = MultilinearOracleSet::new();
+	let committed_id = oracles.add_committed(N_VARS, 0);
+
+	let mut witnesses = vec![(
+		committed_id,
+		MultilinearExtension::from_values(pack_slice::<PBits>(&bits))
+			.unwrap()
+			.specialize_arc_dyn(),
+	)];
+	let mut claims = Vec::new();
+	for shift_offset in 1..=N_SHIFTS {
+		let shifted_id = oracles
+			.add_shifted(committed_id, shift_offset, N_VARS, ShiftVariant::CircularLeft)
+			.unwrap();
+
+		let mut shifted_bits = bits.clone();
+		shifted_bits.rotate_right(shift_offset);
+		let shifted_witness =
+			MultilinearExtension::from_values(pack_slice::<PBits>(&shifted_bits)).unwrap();
+
+		let eval_point = (0..N_VARS)
+			.map(|_| <F as Field>::random(&mut rng))
+			.collect::<Vec<_>>();
+		let query = backend.multilinear_query::<F>(&eval_point).unwrap();
+		let eval = shifted_witness.evaluate(query.to_ref()).unwrap();
+
+		witnesses.push((shifted_id, shifted_witness.specialize_arc_dyn()));
+		claims.push(EvalcheckMultilinearClaim {
+			id: shifted_id,
+			eval_point: eval_point.into(),
+			eval,
+		});
+	}
+
+	let mut witness_index = MultilinearExtensionIndex::new();
+	witness_index.update_multilin_poly(witnesses).unwrap();
+
+	(oracles, witness_index, claims)
+}
+
+/// The number of proofs sharing the tuning of an adaptive switchover in one benchmark iteration.
+const N_PROOFS_PER_TUNING: usize = 8;
+
+/// Benchmarks a sequence of proofs, so that the adaptive switchover pays for its tuning once and
+/// amortizes it over the remaining proofs of the sequence.
+fn bench_switchover(c: &mut Criterion) {
+	let mut group = c.benchmark_group("greedy_evalcheck/switchover");
+	group.sample_size(10);
+
+	let backend = make_portable_backend();
+	let domain_factory = DefaultEvaluationDomainFactory::<FDomain>::default();
+
+	// A fixed offset, or `None` for an adaptive switchover.
+	let mut bench_with = |name: &str, fixed_offset: Option<isize>| {
+		group.bench_function(name, |bench| {
+			bench.iter_batched(
+				|| {
+					repeat_with(shifted_bits_instance)
+						.take(N_PROOFS_PER_TUNING)
+						.collect::<Vec<_>>()
+				},
+				|instances| {
+					// Every sequence starts untuned, so its timing includes the tuning.
+					let tuning = AdaptiveSwitchover::new(0);
+					instances
+						.into_iter()
+						.map(|(mut oracles, mut witness_index, claims)| {
+							let switchover: Switchover<Box<dyn Fn(usize) -> usize>> =
+								match fixed_offset {
+									Some(offset) => Switchover::Fixed(Box::new(
+										standard_switchover_heuristic(offset),
+									)),
+									None => Switchover::Adaptive(tuning.clone()),
+								};
+							let mut transcript =
+								ProverTranscript::<HasherChallenger<Groestl256>>::new();
+							prove_with_switchover::<_, _, FDomain, _, _>(
+								&mut oracles,
+								&mut witness_index,
+								claims,
+								NonZeroUsize::MAX,
+								switchover,
+								&mut transcript,
+								&domain_factory,
+								&backend,
+							)
+							.unwrap();
+							transcript.finalize()
+						})
+						.collect::<Vec<_>>()
+				},
+				BatchSize::LargeInput,
+			)
+		});
+	};
+
+	for offset in [-3, -2, 0] {
+		bench_with(&format!("fixed_{offset}"), Some(offset));
+	}
+	bench_with("adaptive", None);
+
+	group.finish()
+}
+
+/// Evaluation claims on the product of two committed columns and on a shift of the first, which
+/// are reduced with an MLE-check and a bivariate sumcheck.
+fn product_instance(
+	n_vars: usize,
+) -> (
+	MultilinearOracleSet<F>,
+	MultilinearExtensionIndex<'static, P>,
+	Vec<EvalcheckMultilinearClaim<F>>,
+) {
+	let mut rng = StdRng::seed_from_u64(0);
+	let backend = make_portable_backend();
+
+	let mut oracles = MultilinearOracleSet::new();
+	let a_id = oracles.add_committed(n_vars, F::TOWER_LEVEL);
+	let b_id = oracles.add_committed(n_vars, F::TOWER_LEVEL);
+	let product_id = oracles
+		.add_composite_mle(n_vars, [a_id, b_id], ArithCircuit::var(0) * ArithCircuit::var(1))
+		.unwrap();
+	let shifted_id = oracles
+		.add_shifted(a_id, 1, n_vars, ShiftVariant::CircularLeft)
+		.unwrap();
+
+	let a = repeat_with(|| <F as Field>::random(&mut rng))
+		.take(1 << n_vars)
+		.collect::<Vec<_>>();
+	let b = repeat_with(|| <F as Field>::random(&mut rng))
+		.take(1 << n_vars)
+		.collect::<Vec<_>>();
+	let product = iter::zip(&a, &b).map(|(&a, &b)| a * b).collect::<Vec<_>>();
+	let mut shifted = a.clone();
+	shifted.rotate_right(1);
+
+	let witnesses = [
+		(a_id, a),
+		(b_id, b),
+		(product_id, product),
+		(shifted_id, shifted),
+	]
+	.map(|(id, values)| (id, MultilinearExtension::from_values(pack_slice::<P>(&values)).unwrap()));
+
+	let eval_point = repeat_with(|| <F as Field>::random(&mut rng))
+		.take(n_vars)
+		.collect::<Vec<_>>();
+	let query = backend.multilinear_query::<F>(&eval_point).unwrap();
+	let claims = [product_id, shifted_id]
+		.into_iter()
+		.map(|id| {
+			let (_, witness) = witnesses.iter().find(|(other, _)| *other == id).unwrap();
+			EvalcheckMultilinearClaim {
+				id,
+				eval_point: eval_point.clone().into(),
+				eval: witness.evaluate(query.to_ref()).unwrap(),
+			}
+		})
+		.collect();
+
+	let mut witness_index = MultilinearExtensionIndex::new();
+	witness_index
+		.update_multilin_poly(witnesses.map(|(id, witness)| (id, witness.specialize_arc_dyn())))
+		.unwrap();
+
+	(oracles, witness_index, claims)
+}
+
+/// Measures the proving throughput in multiplications estimated by
+/// [`MultilinearOracleSet::estimate_prove_cost`], which stays within a constant factor across
+/// sizes if the estimate tracks the cost of the proof.
+fn bench_estimated_cost(c: &mut Criterion) {
+	let mut group = c.benchmark_group("greedy_evalcheck/estimated_mults");
+	group.sample_size(10);
+
+	let backend = make_portable_backend();
+	let domain_factory = DefaultEvaluationDomainFactory::<FDomain>::default();
+
+	for n_vars in [12, 14, 16, 18] {
+		let (oracles, _, claims) = product_instance(n_vars);
+		let estimate = oracles.estimate_prove_cost(claims).unwrap();
+		group.throughput(Throughput::Elements(estimate.n_mults));
+		group.bench_function(format!("n_vars={n_vars}"), |bench| {
+			bench.iter_batched(
+				|| product_instance(n_vars),
+				|(mut oracles, mut witness_index, claims)| {
+					let mut transcript = ProverTranscript::<HasherChallenger<Groestl256>>::new();
+					prove::<_, _, FDomain, _, _>(
+						&mut oracles,
+						&mut witness_index,
+						claims,
+						standard_switchover_heuristic(-2),
+						&mut transcript,
+						&domain_factory,
+						&backend,
+					)
+					.unwrap();
+					transcript.finalize()
+				},
+				BatchSize::LargeInput,
+			)
+		});
+	}
+
+	group.finish()
+}
+
+criterion_group!(greedy_evalcheck, bench_switchover, bench_estimated_cost);
+criterion_main!(greedy_evalcheck);
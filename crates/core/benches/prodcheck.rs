@@ -1,10 +1,11 @@
 // Copyright 2024-2025 Irreducible Inc.
 
-use std::iter::repeat_with;
+use std::iter::{self, repeat_with};
 
+use binius_compute_test_utils::rng::WitnessRng;
 use binius_core::{
 	fiat_shamir::HasherChallenger,
-	protocols::gkr_gpa::{self, GrandProductClaim, GrandProductWitness},
+	protocols::gkr_gpa::{self, GpaWitnessConfig, GrandProductClaim, GrandProductWitness},
 	transcript::ProverTranscript,
 };
 use binius_field::{
@@ -17,14 +18,14 @@ use binius_field::{
 };
 use binius_hal::{CpuBackend, make_portable_backend};
 use binius_hash::groestl::Groestl256;
-use binius_math::{EvaluationOrder, IsomorphicEvaluationDomainFactory};
+use binius_math::{EvaluationOrder, IsomorphicEvaluationDomainFactory, OptimalEvaluationOrder};
 use binius_maybe_rayon::iter::{IntoParallelIterator, ParallelIterator};
 use criterion::{Criterion, Throughput, criterion_group, criterion_main};
-use rand::{SeedableRng, rngs::StdRng};
+use rand::SeedableRng;
 
 // Creates T(x), a multilinear with evaluations over the n-dimensional boolean hypercube
 fn create_numerator<P: PackedField>(n_vars: usize) -> Vec<P> {
-	let mut rng = StdRng::seed_from_u64(0);
+	let mut rng = WitnessRng::seed_from_u64(0);
 	repeat_with(|| P::random(&mut rng))
 		.take(1 << (n_vars - P::LOG_WIDTH))
 		.collect()
@@ -181,19 +182,13 @@ fn bench_polyval_high_to_low(c: &mut Criterion) {
 }
 
 fn bench_binary_128b(c: &mut Criterion) {
-	bench_gpa::<PackedType<OptimalUnderlier, BinaryField128b>, BinaryField8b>(
-		"gpa_binary_128b",
-		EvaluationOrder::LowToHigh,
-		c,
-	);
+	type P = PackedType<OptimalUnderlier, BinaryField128b>;
+	bench_gpa::<P, BinaryField8b>("gpa_binary_128b", P::optimal_evaluation_order(), c);
 }
 
 fn bench_byte_sliced_aes_128b(c: &mut Criterion) {
-	bench_gpa::<PackedType<OptimalUnderlierByteSliced, AESTowerField128b>, AESTowerField8b>(
-		"gpa_byte_sliced_aes_128b",
-		EvaluationOrder::HighToLow,
-		c,
-	);
+	type P = PackedType<OptimalUnderlierByteSliced, AESTowerField128b>;
+	bench_gpa::<P, AESTowerField8b>("gpa_byte_sliced_aes_128b", P::optimal_evaluation_order(), c);
 }
 
 fn bench_binary_128b_isomorphic(c: &mut Criterion) {
@@ -204,6 +199,146 @@ fn bench_binary_128b_isomorphic(c: &mut Criterion) {
 	);
 }
 
+/// Measures batches of several claims.
+///
+/// The `witness_nested` and `witness_new_batch` functions compare constructing the witnesses in a
+/// parallel iterator over the claims, whose layer constructions are nested parallel iterators,
+/// with [`GrandProductWitness::new_batch`]. The `prove` function measures the whole batch proof.
+fn bench_gpa_batched(c: &mut Criterion) {
+	type P = PackedType<OptimalUnderlier, BinaryField128b>;
+	const N_VARS: usize = 16;
+
+	let mut group = c.benchmark_group("gpa_batched_128b");
+	group.sample_size(10);
+	let domain_factory = IsomorphicEvaluationDomainFactory::<BinaryField8b>::default();
+	let backend = make_portable_backend();
+	let numerator = create_numerator::<P>(N_VARS);
+
+	for n_claims in [8, 64] {
+		group.throughput(Throughput::Elements(((1 << N_VARS) * n_claims) as u64));
+
+		group.bench_function(format!("witness_nested/n_claims={n_claims}"), |bench| {
+			bench.iter(|| {
+				(0..n_claims)
+					.into_par_iter()
+					.map(|_| GrandProductWitness::<P>::new(N_VARS, numerator.clone()).unwrap())
+					.collect::<Vec<_>>()
+			});
+		});
+		group.bench_function(format!("witness_new_batch/n_claims={n_claims}"), |bench| {
+			bench.iter(|| {
+				let inputs = vec![(N_VARS, numerator.clone()); n_claims];
+				GrandProductWitness::new_batch(inputs).unwrap()
+			});
+		});
+		group.bench_function(format!("prove/n_claims={n_claims}"), |bench| {
+			bench.iter(|| {
+				let inputs = vec![(N_VARS, numerator.clone()); n_claims];
+				let witnesses = GrandProductWitness::new_batch(inputs).unwrap();
+				let claims = gkr_gpa::get_grand_products_from_witnesses(&witnesses)
+					.into_iter()
+					.map(|product| GrandProductClaim {
+						n_vars: N_VARS,
+						product,
+					})
+					.collect::<Vec<_>>();
+				let mut prover_transcript =
+					ProverTranscript::<HasherChallenger<Groestl256>>::default();
+				gkr_gpa::batch_prove::<BinaryField128b, P, BinaryField8b, _, _>(
+					P::optimal_evaluation_order(),
+					witnesses,
+					&claims,
+					domain_factory.clone(),
+					&mut prover_transcript,
+					&backend,
+				)
+				.unwrap()
+			});
+		});
+	}
+
+	// A batch whose work is dominated by one claim, which the split of `new_batch` constructs
+	// with parallel layers.
+	let large_numerator = create_numerator::<P>(N_VARS + 4);
+	let skewed_inputs = iter::once((N_VARS + 4, large_numerator))
+		.chain(iter::repeat_n((N_VARS, numerator), 15))
+		.collect::<Vec<_>>();
+	let skewed_len = skewed_inputs
+		.iter()
+		.map(|(n_vars, _)| 1u64 << n_vars)
+		.sum::<u64>();
+	group.throughput(Throughput::Elements(skewed_len));
+	group.bench_function("witness_nested/skewed", |bench| {
+		bench.iter(|| {
+			skewed_inputs
+				.clone()
+				.into_par_iter()
+				.map(|(n_vars, input_layer)| {
+					GrandProductWitness::<P>::new(n_vars, input_layer).unwrap()
+				})
+				.collect::<Vec<_>>()
+		});
+	});
+	group.bench_function("witness_new_batch/skewed", |bench| {
+		bench.iter(|| GrandProductWitness::new_batch(skewed_inputs.clone()).unwrap());
+	});
+	group.finish()
+}
+
+fn bench_witness_construction(c: &mut Criterion) {
+	type P = PackedType<OptimalUnderlier, BinaryField128b>;
+	const N_VARS: usize = 24;
+
+	let mut group = c.benchmark_group("gpa_witness_128b");
+	group.throughput(Throughput::Elements(1 << N_VARS));
+	group.sample_size(10);
+
+	let numerator = create_numerator::<P>(N_VARS);
+	for par_chunk_log_size in [0, 4, 8, 12] {
+		let config = GpaWitnessConfig { par_chunk_log_size };
+		group.bench_function(
+			format!("n_vars={N_VARS}/par_chunk_log_size={par_chunk_log_size}"),
+			|bench| {
+				bench.iter(|| {
+					GrandProductWitness::<P>::new_with_config(N_VARS, numerator.clone(), config)
+						.unwrap()
+				});
+			},
+		);
+	}
+	group.finish()
+}
+
+/// Compares the witness construction of an input layer whose first half is one with and without
+/// [`GrandProductWitness::new_with_identity_prefix`].
+fn bench_witness_identity_prefix(c: &mut Criterion) {
+	type P = PackedType<OptimalUnderlier, BinaryField128b>;
+	const N_VARS: usize = 20;
+
+	let mut group = c.benchmark_group("gpa_witness_identity_prefix_128b");
+	group.throughput(Throughput::Elements(1 << N_VARS));
+	group.sample_size(10);
+
+	let identity_count = 1 << (N_VARS - 1);
+	let mut numerator = create_numerator::<P>(N_VARS);
+	numerator[..identity_count >> P::LOG_WIDTH].fill(P::one());
+
+	group.bench_function(format!("n_vars={N_VARS}/new"), |bench| {
+		bench.iter(|| GrandProductWitness::<P>::new(N_VARS, numerator.clone()).unwrap());
+	});
+	group.bench_function(format!("n_vars={N_VARS}/new_with_identity_prefix"), |bench| {
+		bench.iter(|| {
+			GrandProductWitness::<P>::new_with_identity_prefix(
+				N_VARS,
+				numerator.clone(),
+				identity_count,
+			)
+			.unwrap()
+		});
+	});
+	group.finish()
+}
+
 criterion_main!(prodcheck);
 criterion_group!(
 	prodcheck,
@@ -211,5 +346,8 @@ criterion_group!(
 	bench_polyval_high_to_low,
 	bench_binary_128b,
 	bench_byte_sliced_aes_128b,
-	bench_binary_128b_isomorphic
+	bench_binary_128b_isomorphic,
+	bench_gpa_batched,
+	bench_witness_construction,
+	bench_witness_identity_prefix
 );
@@ -0,0 +1,110 @@
+// Copyright 2025 Irreducible Inc.
+
+use std::iter::repeat_with;
+
+use binius_core::{
+	composition::ProductComposition,
+	polynomial::MultilinearComposite,
+	protocols::sumcheck::{
+		CompositeSumClaim,
+		prove::{RegularSumcheckProver, SumcheckProver},
+	},
+};
+use binius_field::{BinaryField8b, BinaryField128b, Field, PackedBinaryField2x128b, PackedField};
+use binius_hal::{ComputationBackend, make_portable_backend};
+use binius_math::{
+	CompositionPoly, EvaluationDomain, EvaluationDomainFactory, EvaluationOrder,
+	IsomorphicEvaluationDomainFactory, MLEDirectAdapter, MultilinearExtension,
+};
+use binius_maybe_rayon::prelude::*;
+use criterion::{BatchSize, Criterion, criterion_group, criterion_main};
+use rand::{SeedableRng, rngs::StdRng};
+
+/// Compares [`ComputationBackend::sumcheck_round_evals`] with the first round of the regular
+/// sumcheck prover on the same multilinears and composition.
+///
+/// The round variable of the backend method is the highest-indexed one, so the prover runs in
+/// [`EvaluationOrder::HighToLow`]. The backend method evaluates at the finite points of the
+/// interpolation domain the prover creates for the composition.
+fn bench_product<const N: usize>(c: &mut Criterion, log_size: usize) {
+	type F = BinaryField128b;
+	type P = PackedBinaryField2x128b;
+	type FDomain = BinaryField8b;
+
+	let mut rng = StdRng::seed_from_u64(0);
+	let multilinears = repeat_with(|| {
+		repeat_with(|| P::random(&mut rng))
+			.take(1 << (log_size - P::LOG_WIDTH))
+			.collect::<Vec<_>>()
+	})
+	.take(N)
+	.collect::<Vec<_>>();
+	let multilinear_slices = multilinears.iter().map(Vec::as_slice).collect::<Vec<_>>();
+	let adapters = multilinears
+		.iter()
+		.map(|values| {
+			MLEDirectAdapter::from(MultilinearExtension::new(log_size, values.clone()).unwrap())
+		})
+		.collect::<Vec<_>>();
+
+	let composition = ProductComposition::<N>;
+	let sum = MultilinearComposite::new(log_size, composition, adapters.clone())
+		.map(|witness| {
+			(0..1 << log_size)
+				.into_par_iter()
+				.map(|index| witness.evaluate_on_hypercube(index).unwrap())
+				.sum::<F>()
+		})
+		.unwrap();
+
+	let backend = make_portable_backend();
+	let domain_factory = IsomorphicEvaluationDomainFactory::<FDomain>::default();
+	let interpolation_domain: EvaluationDomain<FDomain> = domain_factory
+		.create(CompositionPoly::<P>::degree(&composition) + 1)
+		.unwrap();
+	let domain =
+		EvaluationDomain::from_points(interpolation_domain.finite_points().to_vec(), false)
+			.unwrap();
+
+	// The first two finite points are 0 and 1, whose evaluations sum to the claimed sum.
+	let round_evals = backend
+		.sumcheck_round_evals(&multilinear_slices, &composition, &domain)
+		.unwrap();
+	assert_eq!(round_evals[0] + round_evals[1], sum);
+
+	let mut group = c.benchmark_group("sumcheck_round_evals");
+	group.throughput(criterion::Throughput::Elements(1 << log_size));
+	group.bench_function(format!("prover/{N}x{log_size}"), |bench| {
+		bench.iter_batched(
+			|| {
+				RegularSumcheckProver::<FDomain, P, _, _, _>::new(
+					EvaluationOrder::HighToLow,
+					adapters.iter().collect(),
+					[CompositeSumClaim { composition, sum }],
+					&domain_factory,
+					|_| 0,
+					&backend,
+				)
+				.unwrap()
+			},
+			|mut prover| prover.execute(F::ONE).unwrap(),
+			BatchSize::SmallInput,
+		);
+	});
+	group.bench_function(format!("backend/{N}x{log_size}"), |bench| {
+		bench.iter(|| {
+			backend
+				.sumcheck_round_evals(&multilinear_slices, &composition, &domain)
+				.unwrap()
+		});
+	});
+	group.finish();
+}
+
+fn bench_sumcheck_round_evals(c: &mut Criterion) {
+	bench_product::<2>(c, 16);
+	bench_product::<3>(c, 16);
+}
+
+criterion_group!(sumcheck_round_evals, bench_sumcheck_round_evals);
+criterion_main!(sumcheck_round_evals);
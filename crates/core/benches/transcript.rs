@@ -0,0 +1,37 @@
+// Copyright 2025 Irreducible Inc.
+
+use binius_core::{
+	fiat_shamir::{CanSample, HasherChallenger},
+	transcript::ProverTranscript,
+};
+use binius_field::BinaryField128b;
+use binius_hash::groestl::Groestl256;
+use criterion::{Criterion, Throughput, criterion_group, criterion_main};
+
+type F = BinaryField128b;
+
+const N_CHALLENGES: usize = 64;
+
+fn bench_sample_challenges(c: &mut Criterion) {
+	let mut group = c.benchmark_group("transcript/sample");
+	group.throughput(Throughput::Elements(N_CHALLENGES as u64));
+
+	let mut transcript = ProverTranscript::<HasherChallenger<Groestl256>>::new();
+	group.bench_function(format!("{N_CHALLENGES}x128b/batched"), |bench| {
+		bench.iter(|| CanSample::<F>::sample_vec(&mut transcript, N_CHALLENGES));
+	});
+
+	let mut transcript = ProverTranscript::<HasherChallenger<Groestl256>>::new();
+	group.bench_function(format!("{N_CHALLENGES}x128b/individual"), |bench| {
+		bench.iter(|| {
+			(0..N_CHALLENGES)
+				.map(|_| CanSample::<F>::sample(&mut transcript))
+				.collect::<Vec<_>>()
+		});
+	});
+
+	group.finish()
+}
+
+criterion_group!(transcript, bench_sample_challenges);
+criterion_main!(transcript);
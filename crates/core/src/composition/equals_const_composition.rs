@@ -0,0 +1,98 @@
+// Copyright 2025 Irreducible Inc.
+
+use binius_field::{ExtensionField, PackedField, TowerField};
+use binius_math::{ArithCircuit, CompositionPoly};
+use binius_utils::bail;
+
+/// The univariate composition `x - c`, which vanishes exactly when `x == c`.
+///
+/// Used in zerocheck protocols to constrain a column to equal a constant, for example a lookup
+/// selector. The composition is affine, so its degree is 1. In fields of characteristic 2 it
+/// evaluates to `x + c`.
+#[derive(Debug, Default, Copy, Clone)]
+pub struct EqualsConstComposition<F: TowerField> {
+	pub c: F,
+}
+
+impl<F: TowerField> EqualsConstComposition<F> {
+	pub const fn new(c: F) -> Self {
+		Self { c }
+	}
+
+	pub const fn n_vars(&self) -> usize {
+		1
+	}
+
+	pub const fn degree(&self) -> usize {
+		1
+	}
+}
+
+impl<F, P> CompositionPoly<P> for EqualsConstComposition<F>
+where
+	F: TowerField,
+	P: PackedField<Scalar: ExtensionField<F>>,
+{
+	fn n_vars(&self) -> usize {
+		self.n_vars()
+	}
+
+	fn degree(&self) -> usize {
+		self.degree()
+	}
+
+	fn expression(&self) -> ArithCircuit<P::Scalar> {
+		ArithCircuit::var(0) - ArithCircuit::constant(self.c.into())
+	}
+
+	fn evaluate(&self, query: &[P]) -> Result<P, binius_math::Error> {
+		if query.len() != 1 {
+			bail!(binius_math::Error::IncorrectQuerySize {
+				expected: 1,
+				actual: query.len(),
+			});
+		}
+		Ok(query[0] - P::broadcast(self.c.into()))
+	}
+
+	fn binary_tower_level(&self) -> usize {
+		self.c.min_tower_level()
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use binius_field::{BinaryField8b, BinaryField128b, Field, PackedBinaryField16x8b};
+
+	use super::*;
+
+	#[test]
+	fn test_vanishes_exactly_at_constant() {
+		let composition = EqualsConstComposition::new(BinaryField8b::new(0x2a));
+		for x in 0..=u8::MAX {
+			let x = BinaryField8b::new(x);
+			let eval = CompositionPoly::evaluate(&composition, &[x]).unwrap();
+			assert_eq!(eval == BinaryField8b::ZERO, x == composition.c);
+		}
+	}
+
+	#[test]
+	fn test_packed_matches_expression() {
+		let composition = EqualsConstComposition::new(BinaryField8b::new(0x2a));
+		let query =
+			PackedBinaryField16x8b::from_scalars((0..16).map(|i| BinaryField8b::new(0x20 + i)));
+		let eval = CompositionPoly::evaluate(&composition, &[query]).unwrap();
+
+		let expr = CompositionPoly::<BinaryField128b>::expression(&composition);
+		assert_eq!(expr.degree(), 1);
+		for (x, eval) in query.iter().zip(eval.iter()) {
+			assert_eq!(expr.evaluate(&[x.into()]).unwrap(), BinaryField128b::from(eval));
+		}
+	}
+
+	#[test]
+	fn test_binary_tower_level() {
+		let composition = EqualsConstComposition::new(BinaryField128b::new(3));
+		assert_eq!(CompositionPoly::<BinaryField128b>::binary_tower_level(&composition), 1);
+	}
+}
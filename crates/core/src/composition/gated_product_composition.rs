@@ -0,0 +1,145 @@
+// Copyright 2025 Irreducible Inc.
+
+use binius_field::PackedField;
+use binius_math::{ArithCircuit, CompositionPoly};
+use binius_utils::bail;
+
+/// The bivariate composition `s * v + (1 - s)` of a selector `s` and a value `v`.
+///
+/// It evaluates to `v` where `s == 1` and to `1` where `s == 0`, so feeding it into a grand product
+/// multiplies only the selected values, as lookup arguments need. The selector must be constrained
+/// to be boolean elsewhere for soundness: at other values of `s` the composition is an arbitrary
+/// affine combination of `v` and `1`, which lets a prover contribute any factor it likes.
+#[derive(Debug, Default, Copy, Clone)]
+pub struct GatedProductComposition;
+
+impl GatedProductComposition {
+	pub const fn n_vars(&self) -> usize {
+		2
+	}
+
+	pub const fn degree(&self) -> usize {
+		2
+	}
+}
+
+impl<P: PackedField> CompositionPoly<P> for GatedProductComposition {
+	fn n_vars(&self) -> usize {
+		self.n_vars()
+	}
+
+	fn degree(&self) -> usize {
+		self.degree()
+	}
+
+	fn expression(&self) -> ArithCircuit<P::Scalar> {
+		ArithCircuit::var(0) * ArithCircuit::var(1) + ArithCircuit::one() - ArithCircuit::var(0)
+	}
+
+	fn evaluate(&self, query: &[P]) -> Result<P, binius_math::Error> {
+		if query.len() != 2 {
+			bail!(binius_math::Error::IncorrectQuerySize {
+				expected: 2,
+				actual: query.len(),
+			});
+		}
+		let (selector, value) = (query[0], query[1]);
+		Ok(selector * value + P::one() - selector)
+	}
+
+	fn binary_tower_level(&self) -> usize {
+		0
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use std::iter::repeat_with;
+
+	use binius_field::{BinaryField128b, Field, PackedBinaryField2x128b};
+	use rand::{Rng, SeedableRng, rngs::StdRng};
+
+	use super::*;
+	use crate::protocols::gkr_gpa::GrandProductWitness;
+
+	#[test]
+	fn test_gates_value_by_selector() {
+		let mut rng = StdRng::seed_from_u64(0);
+		for _ in 0..16 {
+			let value = <BinaryField128b as Field>::random(&mut rng);
+			let query = [BinaryField128b::ONE, value];
+			assert_eq!(CompositionPoly::evaluate(&GatedProductComposition, &query).unwrap(), value);
+			let query = [BinaryField128b::ZERO, value];
+			assert_eq!(
+				CompositionPoly::evaluate(&GatedProductComposition, &query).unwrap(),
+				BinaryField128b::ONE
+			);
+		}
+	}
+
+	#[test]
+	fn test_expression_matches_evaluate() {
+		let mut rng = StdRng::seed_from_u64(0);
+		let expr = CompositionPoly::<BinaryField128b>::expression(&GatedProductComposition);
+		assert_eq!(expr.degree(), 2);
+		assert_eq!(expr.n_vars(), 2);
+		for _ in 0..16 {
+			let query = [(); 2].map(|_| <BinaryField128b as Field>::random(&mut rng));
+			assert_eq!(
+				expr.evaluate(&query).unwrap(),
+				CompositionPoly::evaluate(&GatedProductComposition, &query).unwrap()
+			);
+		}
+	}
+
+	#[test]
+	fn test_grand_product_skips_unselected_factors() {
+		type P = PackedBinaryField2x128b;
+
+		let n_vars = 4;
+		let mut rng = StdRng::seed_from_u64(0);
+		let selectors = repeat_with(|| rng.gen_bool(0.5))
+			.take(1 << n_vars)
+			.collect::<Vec<_>>();
+		let values = repeat_with(|| <BinaryField128b as Field>::random(&mut rng))
+			.take(1 << n_vars)
+			.collect::<Vec<_>>();
+
+		let selector_scalars = selectors
+			.iter()
+			.map(|&selected| {
+				if selected {
+					BinaryField128b::ONE
+				} else {
+					BinaryField128b::ZERO
+				}
+			})
+			.collect::<Vec<_>>();
+		let gated = selector_scalars
+			.chunks(P::WIDTH)
+			.zip(values.chunks(P::WIDTH))
+			.map(|(selector, value)| {
+				let query = [
+					P::from_scalars(selector.iter().copied()),
+					P::from_scalars(value.iter().copied()),
+				];
+				CompositionPoly::evaluate(&GatedProductComposition, &query).unwrap()
+			})
+			.collect::<Vec<_>>();
+
+		let witness = GrandProductWitness::new(n_vars, gated).unwrap();
+		let expected = selectors
+			.iter()
+			.zip(&values)
+			.filter(|(selected, _)| **selected)
+			.map(|(_, &value)| value)
+			.product::<BinaryField128b>();
+		assert_eq!(witness.grand_product_evaluation(), expected);
+	}
+
+	#[test]
+	fn test_incorrect_query_size() {
+		let query = [BinaryField128b::ONE; 3];
+		assert!(CompositionPoly::evaluate(&GatedProductComposition, &query).is_err());
+	}
+}
@@ -2,8 +2,20 @@
 
 //! Commonly used composition polynomials.
 
+pub mod equals_const_composition;
+pub mod gated_product_composition;
 pub mod index;
+pub mod power_composition;
 pub mod product_composition;
+pub mod registry;
+pub mod select_composition;
+pub mod sub_composition;
 
+pub use equals_const_composition::*;
+pub use gated_product_composition::*;
 pub use index::*;
+pub use power_composition::*;
 pub use product_composition::*;
+pub use registry::*;
+pub use select_composition::*;
+pub use sub_composition::*;
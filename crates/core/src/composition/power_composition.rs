@@ -0,0 +1,87 @@
+// Copyright 2025 Irreducible Inc.
+
+use binius_field::PackedField;
+use binius_math::{ArithCircuit, CompositionPoly};
+use binius_utils::bail;
+
+/// The univariate power `x^exponent`.
+#[derive(Debug, Copy, Clone)]
+pub struct PowerComposition {
+	exponent: u64,
+}
+
+impl PowerComposition {
+	pub const fn new(exponent: u64) -> Self {
+		Self { exponent }
+	}
+
+	pub const fn exponent(&self) -> u64 {
+		self.exponent
+	}
+
+	pub const fn n_vars(&self) -> usize {
+		1
+	}
+
+	pub const fn degree(&self) -> usize {
+		self.exponent as usize
+	}
+}
+
+impl<P: PackedField> CompositionPoly<P> for PowerComposition {
+	fn n_vars(&self) -> usize {
+		self.n_vars()
+	}
+
+	fn degree(&self) -> usize {
+		self.degree()
+	}
+
+	fn expression(&self) -> ArithCircuit<P::Scalar> {
+		ArithCircuit::var(0).pow(self.exponent)
+	}
+
+	fn evaluate(&self, query: &[P]) -> Result<P, binius_math::Error> {
+		if query.len() != 1 {
+			bail!(binius_math::Error::IncorrectQuerySize {
+				expected: 1,
+				actual: query.len(),
+			});
+		}
+		Ok(PackedField::pow(query[0], self.exponent))
+	}
+
+	fn binary_tower_level(&self) -> usize {
+		0
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use binius_field::{BinaryField128b, Field};
+	use rand::{SeedableRng, rngs::StdRng};
+
+	use super::*;
+
+	#[test]
+	fn test_expression_matches_evaluate() {
+		let mut rng = StdRng::seed_from_u64(0);
+		for exponent in [0, 1, 2, 5] {
+			let composition = PowerComposition::new(exponent);
+			let expr = CompositionPoly::<BinaryField128b>::expression(&composition);
+			assert_eq!(expr.degree(), exponent as usize);
+			for _ in 0..16 {
+				let x = <BinaryField128b as Field>::random(&mut rng);
+				let expected = (0..exponent).fold(BinaryField128b::ONE, |acc, _| acc * x);
+				assert_eq!(CompositionPoly::evaluate(&composition, &[x]).unwrap(), expected);
+				assert_eq!(expr.evaluate(&[x]).unwrap(), expected);
+			}
+		}
+	}
+
+	#[test]
+	fn test_incorrect_query_size() {
+		let query = [BinaryField128b::ONE; 2];
+		assert!(CompositionPoly::evaluate(&PowerComposition::new(2), &query).is_err());
+	}
+}
@@ -0,0 +1,261 @@
+// Copyright 2025 Irreducible Inc.
+
+//! Serialization of type-erased composition polynomials.
+//!
+//! Composite oracles hold their composition as an `Arc<dyn CompositionPoly<F>>`, which cannot be
+//! deserialized without knowing the concrete type. A composition that implements
+//! [`NamedComposition`] describes itself as a [`SerializableComposition`], a string tag together
+//! with its serialized parameters, and a [`CompositionRegistry`] maps tags back to constructors.
+//!
+//! Unlike the transparent polynomial deserializers, which are collected into a global registry,
+//! composition registries are explicit values, so that callers control which compositions may
+//! appear in the data they deserialize.
+
+use std::{collections::HashMap, sync::Arc};
+
+use binius_field::Field;
+use binius_macros::{DeserializeBytes, SerializeBytes};
+use binius_math::CompositionPoly;
+use binius_utils::{DeserializeBytes, SerializationError, SerializationMode, SerializeBytes};
+
+use super::{PowerComposition, ProductComposition, SelectComposition, SubComposition};
+
+/// A composition polynomial identified by a tag and its serialized parameters.
+#[derive(Debug, Clone, PartialEq, Eq, SerializeBytes, DeserializeBytes)]
+pub struct SerializableComposition {
+	pub tag: String,
+	pub params: Vec<u8>,
+}
+
+impl SerializableComposition {
+	/// Creates a descriptor with parameters serialized in canonical mode.
+	pub fn new(tag: impl Into<String>, params: &impl SerializeBytes) -> Self {
+		let mut buf = Vec::new();
+		params
+			.serialize(&mut buf, SerializationMode::CanonicalTower)
+			.expect("serialization into a Vec cannot run out of space");
+		Self {
+			tag: tag.into(),
+			params: buf,
+		}
+	}
+
+	/// Deserializes the parameters, which must be consumed entirely.
+	pub fn params<T: DeserializeBytes>(&self) -> Result<T, SerializationError> {
+		let mut buf = self.params.as_slice();
+		let params = T::deserialize(&mut buf, SerializationMode::CanonicalTower)?;
+		if !buf.is_empty() {
+			return Err(SerializationError::InvalidConstruction {
+				name: "SerializableComposition",
+			});
+		}
+		Ok(params)
+	}
+}
+
+/// A composition polynomial that can describe itself as a [`SerializableComposition`].
+pub trait NamedComposition<F: Field>: CompositionPoly<F> {
+	fn to_serializable(&self) -> SerializableComposition;
+}
+
+/// Constructs a composition from the parameters of a [`SerializableComposition`].
+pub type CompositionConstructor<F> =
+	fn(&SerializableComposition) -> Result<Arc<dyn CompositionPoly<F>>, SerializationError>;
+
+/// A mapping from composition tags to constructors.
+#[derive(Debug, Clone)]
+pub struct CompositionRegistry<F: Field> {
+	constructors: HashMap<String, CompositionConstructor<F>>,
+}
+
+impl<F: Field> CompositionRegistry<F> {
+	/// Creates an empty registry.
+	pub fn new() -> Self {
+		Self {
+			constructors: HashMap::new(),
+		}
+	}
+
+	/// Creates a registry with the compositions defined in this crate.
+	pub fn with_builtins() -> Self {
+		let mut registry = Self::new();
+		registry
+			.register(PRODUCT_COMPOSITION_TAG, construct_product_composition)
+			.expect("registry is empty");
+		registry
+			.register(SUB_COMPOSITION_TAG, |_| Ok(Arc::new(SubComposition)))
+			.expect("tags of the builtin compositions are distinct");
+		registry
+			.register(SELECT_COMPOSITION_TAG, |_| Ok(Arc::new(SelectComposition)))
+			.expect("tags of the builtin compositions are distinct");
+		registry
+			.register(POWER_COMPOSITION_TAG, |serializable| {
+				Ok(Arc::new(PowerComposition::new(serializable.params::<u64>()?)))
+			})
+			.expect("tags of the builtin compositions are distinct");
+		registry
+	}
+
+	/// Registers a constructor for the compositions with the given tag.
+	///
+	/// ## Throws
+	///
+	/// * `SerializationError::DeserializerNameConflict` if the tag is already registered
+	pub fn register(
+		&mut self,
+		tag: impl Into<String>,
+		constructor: CompositionConstructor<F>,
+	) -> Result<(), SerializationError> {
+		let tag = tag.into();
+		if self.constructors.contains_key(&tag) {
+			return Err(SerializationError::DeserializerNameConflict { name: tag });
+		}
+		self.constructors.insert(tag, constructor);
+		Ok(())
+	}
+
+	/// Reconstructs the composition described by `serializable`.
+	///
+	/// ## Throws
+	///
+	/// * `SerializationError::DeserializerNotImplemented` if the tag is not registered
+	pub fn construct(
+		&self,
+		serializable: &SerializableComposition,
+	) -> Result<Arc<dyn CompositionPoly<F>>, SerializationError> {
+		let constructor = self
+			.constructors
+			.get(&serializable.tag)
+			.ok_or(SerializationError::DeserializerNotImplemented)?;
+		constructor(serializable)
+	}
+}
+
+impl<F: Field> Default for CompositionRegistry<F> {
+	fn default() -> Self {
+		Self::new()
+	}
+}
+
+/// Tag of [`ProductComposition`], parameterized by its number of variables.
+pub const PRODUCT_COMPOSITION_TAG: &str = "ProductComposition";
+
+/// Tag of [`SubComposition`], which has no parameters.
+pub const SUB_COMPOSITION_TAG: &str = "SubComposition";
+
+/// Tag of [`SelectComposition`], which has no parameters.
+pub const SELECT_COMPOSITION_TAG: &str = "SelectComposition";
+
+/// Tag of [`PowerComposition`], parameterized by its exponent.
+pub const POWER_COMPOSITION_TAG: &str = "PowerComposition";
+
+impl<F: Field, const N: usize> NamedComposition<F> for ProductComposition<N> {
+	fn to_serializable(&self) -> SerializableComposition {
+		SerializableComposition::new(PRODUCT_COMPOSITION_TAG, &N)
+	}
+}
+
+impl<F: Field> NamedComposition<F> for SubComposition {
+	fn to_serializable(&self) -> SerializableComposition {
+		SerializableComposition {
+			tag: SUB_COMPOSITION_TAG.into(),
+			params: Vec::new(),
+		}
+	}
+}
+
+impl<F: Field> NamedComposition<F> for SelectComposition {
+	fn to_serializable(&self) -> SerializableComposition {
+		SerializableComposition {
+			tag: SELECT_COMPOSITION_TAG.into(),
+			params: Vec::new(),
+		}
+	}
+}
+
+impl<F: Field> NamedComposition<F> for PowerComposition {
+	fn to_serializable(&self) -> SerializableComposition {
+		SerializableComposition::new(POWER_COMPOSITION_TAG, &self.exponent())
+	}
+}
+
+fn construct_product_composition<F: Field>(
+	serializable: &SerializableComposition,
+) -> Result<Arc<dyn CompositionPoly<F>>, SerializationError> {
+	macro_rules! product_composition {
+		($n_vars:expr; $($n:literal),*) => {
+			match $n_vars {
+				$($n => Ok(Arc::new(ProductComposition::<$n>)),)*
+				_ => Err(SerializationError::InvalidConstruction {
+					name: "ProductComposition",
+				}),
+			}
+		};
+	}
+
+	// The builtin constructor supports products of up to 8 variables.
+	let n_vars = serializable.params::<usize>()?;
+	product_composition!(n_vars; 1, 2, 3, 4, 5, 6, 7, 8)
+}
+
+#[cfg(test)]
+mod tests {
+	use binius_field::BinaryField128b;
+
+	use super::*;
+	use crate::composition::{BivariateProduct, TrivariateProduct};
+
+	type F = BinaryField128b;
+
+	fn assert_equivalent(lhs: &dyn CompositionPoly<F>, rhs: &dyn CompositionPoly<F>) {
+		assert_eq!(lhs.n_vars(), rhs.n_vars());
+		assert_eq!(lhs.degree(), rhs.degree());
+		assert_eq!(lhs.binary_tower_level(), rhs.binary_tower_level());
+		assert_eq!(lhs.expression(), rhs.expression());
+	}
+
+	#[test]
+	fn test_builtin_roundtrip() {
+		let registry = CompositionRegistry::<F>::with_builtins();
+		let compositions: [&dyn NamedComposition<F>; 6] = [
+			&BivariateProduct {},
+			&TrivariateProduct {},
+			&SubComposition,
+			&SelectComposition,
+			&PowerComposition::new(1),
+			&PowerComposition::new(7),
+		];
+		for composition in compositions {
+			let constructed = registry.construct(&composition.to_serializable()).unwrap();
+			assert_equivalent(constructed.as_ref(), composition);
+		}
+	}
+
+	#[test]
+	fn test_unknown_tag() {
+		let registry = CompositionRegistry::<F>::new();
+		let serializable = NamedComposition::<F>::to_serializable(&SubComposition);
+		assert!(matches!(
+			registry.construct(&serializable),
+			Err(SerializationError::DeserializerNotImplemented)
+		));
+	}
+
+	#[test]
+	fn test_duplicate_tag() {
+		let mut registry = CompositionRegistry::<F>::with_builtins();
+		assert!(matches!(
+			registry.register(SUB_COMPOSITION_TAG, |_| Ok(Arc::new(SubComposition))),
+			Err(SerializationError::DeserializerNameConflict { .. })
+		));
+	}
+
+	#[test]
+	fn test_product_arity_out_of_range() {
+		let registry = CompositionRegistry::<F>::with_builtins();
+		let serializable = SerializableComposition::new(PRODUCT_COMPOSITION_TAG, &0usize);
+		assert!(registry.construct(&serializable).is_err());
+		let serializable = SerializableComposition::new(PRODUCT_COMPOSITION_TAG, &9usize);
+		assert!(registry.construct(&serializable).is_err());
+	}
+}
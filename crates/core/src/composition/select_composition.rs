@@ -0,0 +1,93 @@
+// Copyright 2025 Irreducible Inc.
+
+use binius_field::PackedField;
+use binius_math::{ArithCircuit, CompositionPoly};
+use binius_utils::bail;
+
+/// The trivariate selection `s * a + (1 - s) * b` of `a` and `b` by a selector `s`.
+///
+/// It evaluates to `a` where `s == 1` and to `b` where `s == 0`. The selector must be constrained
+/// to be boolean elsewhere for the composition to act as a selection.
+#[derive(Debug, Default, Copy, Clone)]
+pub struct SelectComposition;
+
+impl SelectComposition {
+	pub const fn n_vars(&self) -> usize {
+		3
+	}
+
+	pub const fn degree(&self) -> usize {
+		2
+	}
+}
+
+impl<P: PackedField> CompositionPoly<P> for SelectComposition {
+	fn n_vars(&self) -> usize {
+		self.n_vars()
+	}
+
+	fn degree(&self) -> usize {
+		self.degree()
+	}
+
+	fn expression(&self) -> ArithCircuit<P::Scalar> {
+		ArithCircuit::var(0) * (ArithCircuit::var(1) - ArithCircuit::var(2)) + ArithCircuit::var(2)
+	}
+
+	fn evaluate(&self, query: &[P]) -> Result<P, binius_math::Error> {
+		if query.len() != 3 {
+			bail!(binius_math::Error::IncorrectQuerySize {
+				expected: 3,
+				actual: query.len(),
+			});
+		}
+		let (selector, a, b) = (query[0], query[1], query[2]);
+		Ok(selector * (a - b) + b)
+	}
+
+	fn binary_tower_level(&self) -> usize {
+		0
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use binius_field::{BinaryField128b, Field};
+	use rand::{SeedableRng, rngs::StdRng};
+
+	use super::*;
+
+	#[test]
+	fn test_selects_by_selector() {
+		let mut rng = StdRng::seed_from_u64(0);
+		for _ in 0..16 {
+			let a = <BinaryField128b as Field>::random(&mut rng);
+			let b = <BinaryField128b as Field>::random(&mut rng);
+			let query = [BinaryField128b::ONE, a, b];
+			assert_eq!(CompositionPoly::evaluate(&SelectComposition, &query).unwrap(), a);
+			let query = [BinaryField128b::ZERO, a, b];
+			assert_eq!(CompositionPoly::evaluate(&SelectComposition, &query).unwrap(), b);
+		}
+	}
+
+	#[test]
+	fn test_expression_matches_evaluate() {
+		let mut rng = StdRng::seed_from_u64(0);
+		let expr = CompositionPoly::<BinaryField128b>::expression(&SelectComposition);
+		assert_eq!(expr.degree(), 2);
+		assert_eq!(expr.n_vars(), 3);
+		for _ in 0..16 {
+			let query = [(); 3].map(|_| <BinaryField128b as Field>::random(&mut rng));
+			assert_eq!(
+				expr.evaluate(&query).unwrap(),
+				CompositionPoly::evaluate(&SelectComposition, &query).unwrap()
+			);
+		}
+	}
+
+	#[test]
+	fn test_incorrect_query_size() {
+		let query = [BinaryField128b::ONE; 2];
+		assert!(CompositionPoly::evaluate(&SelectComposition, &query).is_err());
+	}
+}
@@ -0,0 +1,82 @@
+// Copyright 2025 Irreducible Inc.
+
+use binius_field::PackedField;
+use binius_math::{ArithCircuit, CompositionPoly};
+use binius_utils::bail;
+
+/// The bivariate difference `a - b`.
+///
+/// Lookup and range-check arguments are naturally stated in terms of differences. In fields of
+/// characteristic 2, subtraction coincides with addition, so this composition evaluates to the
+/// same values as `a + b`. It exists to make the intent of constraints explicit.
+#[derive(Debug, Default, Copy, Clone)]
+pub struct SubComposition;
+
+impl SubComposition {
+	pub const fn n_vars(&self) -> usize {
+		2
+	}
+
+	pub const fn degree(&self) -> usize {
+		1
+	}
+}
+
+impl<P: PackedField> CompositionPoly<P> for SubComposition {
+	fn n_vars(&self) -> usize {
+		self.n_vars()
+	}
+
+	fn degree(&self) -> usize {
+		self.degree()
+	}
+
+	fn expression(&self) -> ArithCircuit<P::Scalar> {
+		ArithCircuit::var(0) - ArithCircuit::var(1)
+	}
+
+	fn evaluate(&self, query: &[P]) -> Result<P, binius_math::Error> {
+		if query.len() != 2 {
+			bail!(binius_math::Error::IncorrectQuerySize {
+				expected: 2,
+				actual: query.len(),
+			});
+		}
+		Ok(query[0] - query[1])
+	}
+
+	fn binary_tower_level(&self) -> usize {
+		0
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use binius_field::{BinaryField128b, Field};
+	use rand::{SeedableRng, rngs::StdRng};
+
+	use super::*;
+
+	#[test]
+	fn test_sub_equals_add_in_char_2() {
+		let mut rng = StdRng::seed_from_u64(0);
+		for _ in 0..16 {
+			let a = <BinaryField128b as Field>::random(&mut rng);
+			let b = <BinaryField128b as Field>::random(&mut rng);
+			assert_eq!(CompositionPoly::evaluate(&SubComposition, &[a, b]).unwrap(), a + b);
+		}
+	}
+
+	#[test]
+	fn test_expression_degree() {
+		let expr = CompositionPoly::<BinaryField128b>::expression(&SubComposition);
+		assert_eq!(expr.degree(), 1);
+		assert_eq!(expr.n_vars(), 2);
+	}
+
+	#[test]
+	fn test_incorrect_query_size() {
+		let query = [BinaryField128b::ONE; 3];
+		assert!(CompositionPoly::evaluate(&SubComposition, &query).is_err());
+	}
+}
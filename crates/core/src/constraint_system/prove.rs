@@ -41,7 +41,7 @@ use crate::{
 	fiat_shamir::{CanSample, Challenger},
 	merkle_tree::BinaryMerkleTreeProver,
 	oracle::{Constraint, MultilinearOracleSet, OracleId},
-	piop,
+	parallelism, piop,
 	protocols::{
 		fri::CommitOutput,
 		gkr_exp,
@@ -60,6 +60,45 @@ use crate::{
 /// Generates a proof that a witness satisfies a constraint system with the standard FRI PCS.
 #[instrument("constraint_system::prove", skip_all, level = "debug")]
 pub fn prove<U, Tower, Hash, Compress, Challenger_, Backend>(
+	constraint_system: &ConstraintSystem<FExt<Tower>>,
+	log_inv_rate: usize,
+	security_bits: usize,
+	boundaries: &[Boundary<FExt<Tower>>],
+	witness: MultilinearExtensionIndex<PackedType<U, FExt<Tower>>>,
+	backend: &Backend,
+) -> Result<Proof, Error>
+where
+	U: ProverTowerUnderlier<Tower>,
+	Tower: ProverTowerFamily,
+	Tower::B128: PackedTop<Tower>,
+	Hash: Digest + BlockSizeUser + FixedOutputReset + Send + Sync + Clone,
+	Compress: PseudoCompressionFunction<Output<Hash>, 2> + Default + Sync,
+	Challenger_: Challenger + Default,
+	Backend: ComputationBackend,
+	// REVIEW: Consider changing TowerFamily and associated traits to shorten/remove these bounds
+	PackedType<U, Tower::B128>: PackedTop<Tower>
+		+ PackedFieldIndexable // REVIEW: remove this bound after piop::commit is adjusted
+		+ RepackedExtension<PackedType<U, Tower::B8>>
+		+ RepackedExtension<PackedType<U, Tower::B16>>
+		+ RepackedExtension<PackedType<U, Tower::B32>>
+		+ RepackedExtension<PackedType<U, Tower::B64>>
+		+ RepackedExtension<PackedType<U, Tower::B128>>
+		+ PackedTransformationFactory<PackedType<U, Tower::FastB128>>,
+	PackedType<U, Tower::FastB128>: PackedTransformationFactory<PackedType<U, Tower::B128>>,
+{
+	parallelism::install(|| {
+		prove_on_current_pool::<U, Tower, Hash, Compress, Challenger_, _>(
+			constraint_system,
+			log_inv_rate,
+			security_bits,
+			boundaries,
+			witness,
+			backend,
+		)
+	})
+}
+
+fn prove_on_current_pool<U, Tower, Hash, Compress, Challenger_, Backend>(
 	constraint_system: &ConstraintSystem<FExt<Tower>>,
 	log_inv_rate: usize,
 	security_bits: usize,
@@ -232,10 +271,7 @@ where
 		perfetto_category = "phase.sub"
 	)
 	.entered();
-	let non_zero_prodcheck_witnesses = non_zero_fast_witnesses
-		.into_par_iter()
-		.map(|(n_vars, evals)| GrandProductWitness::new(n_vars, evals))
-		.collect::<Result<Vec<_>, _>>()?;
+	let non_zero_prodcheck_witnesses = GrandProductWitness::new_batch(non_zero_fast_witnesses)?;
 	drop(nonzero_prodcheck_compute_layer_span);
 
 	let non_zero_products =
@@ -291,10 +327,7 @@ where
 		perfetto_category = "phase.sub"
 	)
 	.entered();
-	let flush_prodcheck_witnesses = flush_witnesses
-		.into_par_iter()
-		.map(|(n_vars, evals)| GrandProductWitness::new(n_vars, evals))
-		.collect::<Result<Vec<_>, _>>()?;
+	let flush_prodcheck_witnesses = GrandProductWitness::new_batch(flush_witnesses)?;
 	drop(flush_prodcheck_compute_layer_span);
 
 	let flush_products = gkr_gpa::get_grand_products_from_witnesses(&flush_prodcheck_witnesses);
@@ -423,6 +456,7 @@ where
 	let GreedyEvalcheckProveOutput {
 		eval_claims,
 		memoized_data,
+		..
 	} = greedy_evalcheck::prove::<_, _, FDomain<Tower>, _, _>(
 		&mut oracles,
 		&mut witness,
@@ -2,6 +2,7 @@
 
 use std::{cmp::min, mem};
 
+use binius_hash::groestl::Groestl256;
 use bytes::{Buf, BufMut, buf::UninitSlice};
 use digest::{
 	Digest, FixedOutputReset, Output,
@@ -51,6 +52,35 @@ where
 	}
 }
 
+impl<H> HasherChallenger<H>
+where
+	H: Digest + BlockSizeUser + Clone,
+{
+	/// Returns a Grøstl-256 digest of the current challenger state, leaving the state unchanged.
+	///
+	/// The digest commits to the mode of the challenger, the output of the inner hasher over the
+	/// data it has absorbed, and the buffered bytes together with the position within them. Two
+	/// challengers with equal digests produce the same challenges for any further observations.
+	pub fn state_digest(&self) -> [u8; 32] {
+		let mut state_hasher = Groestl256::new();
+		match self {
+			Self::Observer(observer) => {
+				Digest::update(&mut state_hasher, [0]);
+				Digest::update(&mut state_hasher, observer.hasher.clone().finalize());
+				Digest::update(&mut state_hasher, (observer.index as u64).to_le_bytes());
+				Digest::update(&mut state_hasher, &observer.buffer[..observer.index]);
+			}
+			Self::Sampler(sampler) => {
+				Digest::update(&mut state_hasher, [1]);
+				Digest::update(&mut state_hasher, sampler.hasher.clone().finalize());
+				Digest::update(&mut state_hasher, (sampler.index as u64).to_le_bytes());
+				Digest::update(&mut state_hasher, &sampler.buffer);
+			}
+		}
+		state_hasher.finalize().into()
+	}
+}
+
 impl<H> Default for HasherChallenger<H>
 where
 	H: Digest + BlockSizeUser + FixedOutputReset,
@@ -201,7 +231,7 @@ where
 
 #[cfg(test)]
 mod tests {
-	use binius_hash::groestl::Groestl256;
+	use binius_hash::groestl::{Groestl256, Groestl512};
 	use rand::{RngCore, thread_rng};
 
 	use super::*;
@@ -304,4 +334,64 @@ mod tests {
 		let final_hasher_out = hasher.finalize_reset();
 		assert_eq!(final_hasher_out[..7], out_again);
 	}
+
+	#[test]
+	fn test_state_digest() {
+		let mut challenger = HasherChallenger::<Groestl256>::default();
+		let initial_digest = challenger.state_digest();
+
+		challenger.observer().put_slice(b"binius");
+		let observed_digest = challenger.state_digest();
+		assert_ne!(observed_digest, initial_digest);
+		assert_eq!(challenger.state_digest(), observed_digest);
+
+		// Reading the digest does not disturb the challenger.
+		let mut out = [0u8; 16];
+		challenger.sampler().copy_to_slice(&mut out);
+		let mut undisturbed = HasherChallenger::<Groestl256>::default();
+		undisturbed.observer().put_slice(b"binius");
+		let mut undisturbed_out = [0u8; 16];
+		undisturbed.sampler().copy_to_slice(&mut undisturbed_out);
+		assert_eq!(out, undisturbed_out);
+		assert_eq!(challenger.state_digest(), undisturbed.state_digest());
+
+		assert_eq!(challenger.state_digest(), STATE_DIGEST_TEST_VECTOR);
+	}
+
+	/// State digest after observing `b"binius"` and sampling 16 bytes from a fresh challenger.
+	const STATE_DIGEST_TEST_VECTOR: [u8; 32] = [
+		0x22, 0x9d, 0x0e, 0x01, 0x86, 0x78, 0x21, 0xf7, 0x58, 0xae, 0x86, 0x2c, 0xf9, 0xae, 0xff,
+		0x1a, 0xd8, 0x3d, 0x6e, 0x7f, 0x29, 0x51, 0x2a, 0xe5, 0x4c, 0xdf, 0x96, 0x5b, 0xb4, 0x36,
+		0x0e, 0x0a,
+	];
+
+	#[test]
+	fn test_groestl512_sampler() {
+		let mut challenger = HasherChallenger::<Groestl512>::default();
+		challenger.observer().put_slice(b"statement");
+
+		// A single squeeze yields the full 512-bit digest.
+		let mut out = [0u8; 64];
+		challenger.sampler().copy_to_slice(&mut out);
+
+		let mut hasher = Groestl512::default();
+		Digest::update(&mut hasher, Groestl512::digest([]));
+		hasher.update([0, 0, 0, 0, 0, 0, 0, 0]);
+		hasher.update(b"statement");
+		assert_eq!(hasher.finalize()[..], out);
+
+		// Sampling is deterministic.
+		let mut challenger_again = HasherChallenger::<Groestl512>::default();
+		challenger_again.observer().put_slice(b"statement");
+		let mut out_again = [0u8; 64];
+		challenger_again.sampler().copy_to_slice(&mut out_again);
+		assert_eq!(out, out_again);
+
+		// And distinct from the Grøstl-256 path.
+		let mut challenger_256 = HasherChallenger::<Groestl256>::default();
+		challenger_256.observer().put_slice(b"statement");
+		let mut out_256 = [0u8; 64];
+		challenger_256.sampler().copy_to_slice(&mut out_256);
+		assert_ne!(out, out_256);
+	}
 }
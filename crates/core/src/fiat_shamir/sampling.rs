@@ -26,3 +26,47 @@ pub trait CanSample<T> {
 pub trait CanSampleBits<T> {
 	fn sample_bits(&mut self, bits: usize) -> T;
 }
+
+/// The subfield of the binary tower that a challenge is sampled from.
+///
+/// Protocols that only need a small challenge, such as grinding, can sample it from a subfield
+/// and consume fewer bytes of the challenger's output than a full field element.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct ChallengeSpec {
+	tower_level: usize,
+}
+
+impl ChallengeSpec {
+	/// A challenge from the subfield at `tower_level` of the canonical binary tower.
+	pub const fn new(tower_level: usize) -> Self {
+		Self { tower_level }
+	}
+
+	/// A challenge from the whole field `F`.
+	pub const fn full<F: binius_field::TowerField>() -> Self {
+		Self::new(F::TOWER_LEVEL)
+	}
+
+	/// The tower level of the subfield the challenge is sampled from.
+	pub const fn tower_level(&self) -> usize {
+		self.tower_level
+	}
+
+	/// The number of bytes consumed from the challenger to sample the challenge.
+	///
+	/// This is the byte size of the subfield, `2^tower_level / 8`, except for the subfields of
+	/// fewer than 8 bits, which consume a single byte whose upper bits are discarded.
+	pub const fn n_bytes(&self) -> usize {
+		1 << self.tower_level.saturating_sub(3)
+	}
+}
+
+#[auto_impl::auto_impl(&mut)]
+pub trait CanSampleWithSpec<T> {
+	/// Samples a challenge from the subfield given by `spec`, embedded into `T`.
+	///
+	/// ## Preconditions
+	///
+	/// * the tower level of `spec` must not exceed the tower level of `T`
+	fn sample_with_spec(&mut self, spec: ChallengeSpec) -> T;
+}
@@ -17,6 +17,7 @@ pub mod constraint_system;
 pub mod fiat_shamir;
 pub mod merkle_tree;
 pub mod oracle;
+pub mod parallelism;
 pub mod piop;
 pub mod polynomial;
 pub mod protocols;
@@ -24,8 +25,11 @@ pub mod protocols;
 pub mod reed_solomon;
 pub mod ring_switch;
 pub mod tensor_algebra;
+#[doc(hidden)]
+pub mod test_utils;
 pub mod transcript;
 pub mod transparent;
 pub mod witness;
 
 pub use inventory;
+pub use parallelism::set_single_threaded;
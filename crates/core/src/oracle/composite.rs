@@ -2,17 +2,24 @@
 
 use std::sync::Arc;
 
-use binius_field::TowerField;
-use binius_math::CompositionPoly;
-use binius_utils::bail;
+use binius_field::{BinaryField128b, PackedField, TowerField};
+use binius_math::{CompositionPoly, MultilinearPoly};
+use binius_utils::{DeserializeBytes, SerializationError, SerializationMode, SerializeBytes, bail};
+use itertools::izip;
 
-use crate::oracle::{Error, MultilinearPolyOracle, OracleId};
+use crate::{
+	composition::{CompositionRegistry, NamedComposition, SerializableComposition},
+	oracle::{Error, MultilinearPolyOracle, OracleId},
+	witness::{Error as WitnessError, MultilinearExtensionIndex},
+};
 
 #[derive(Debug, Clone)]
 pub struct CompositePolyOracle<F: TowerField> {
 	n_vars: usize,
 	inner: Vec<MultilinearPolyOracle<F>>,
 	composition: Arc<dyn CompositionPoly<F>>,
+	/// Description of the composition, present if the oracle can be serialized.
+	serializable_composition: Option<SerializableComposition>,
 }
 
 impl<F: TowerField> CompositePolyOracle<F> {
@@ -20,25 +27,66 @@ impl<F: TowerField> CompositePolyOracle<F> {
 		n_vars: usize,
 		inner: Vec<MultilinearPolyOracle<F>>,
 		composition: C,
+	) -> Result<Self, Error> {
+		Self::with_arc_composition(n_vars, inner, Arc::new(composition), None)
+	}
+
+	/// Creates a composite oracle that can be serialized and later deserialized with a
+	/// [`CompositionRegistry`] that knows the composition's tag.
+	pub fn new_named<C: NamedComposition<F> + 'static>(
+		n_vars: usize,
+		inner: Vec<MultilinearPolyOracle<F>>,
+		composition: C,
+	) -> Result<Self, Error> {
+		let serializable_composition = composition.to_serializable();
+		Self::with_arc_composition(
+			n_vars,
+			inner,
+			Arc::new(composition),
+			Some(serializable_composition),
+		)
+	}
+
+	fn with_arc_composition(
+		n_vars: usize,
+		inner: Vec<MultilinearPolyOracle<F>>,
+		composition: Arc<dyn CompositionPoly<F>>,
+		serializable_composition: Option<SerializableComposition>,
 	) -> Result<Self, Error> {
 		if inner.len() != composition.n_vars() {
 			bail!(Error::CompositionMismatch);
 		}
-		for poly in &inner {
+		for (index, poly) in inner.iter().enumerate() {
 			if poly.n_vars() != n_vars {
-				bail!(Error::IncorrectNumberOfVariables { expected: n_vars });
+				bail!(Error::InnerPolyVariableMismatch {
+					index,
+					expected: n_vars,
+					actual: poly.n_vars(),
+				});
 			}
 		}
 		Ok(Self {
 			n_vars,
 			inner,
-			composition: Arc::new(composition),
+			composition,
+			serializable_composition,
 		})
 	}
 
-	pub fn max_individual_degree(&self) -> usize {
+	/// Returns the maximum individual degree of the composite, the degree of its composition.
+	///
+	/// In debug builds, the degree reported by the composition is first cross-checked against the
+	/// degree of its expression with [`check_composition_degree`].
+	///
+	/// ## Throws
+	///
+	/// * `Error::CompositionDegreeUnderreported` in debug builds, if the degree check fails
+	pub fn max_individual_degree(&self) -> Result<usize, Error> {
+		if cfg!(debug_assertions) {
+			check_composition_degree(self.composition.as_ref())?;
+		}
 		// Maximum individual degree of the multilinear composite equals composition degree
-		self.composition.degree()
+		Ok(self.composition.degree())
 	}
 
 	pub fn n_multilinears(&self) -> usize {
@@ -70,15 +118,173 @@ impl<F: TowerField> CompositePolyOracle<F> {
 	pub fn composition(&self) -> Arc<dyn CompositionPoly<F>> {
 		self.composition.clone()
 	}
+
+	pub const fn serializable_composition(&self) -> Option<&SerializableComposition> {
+		self.serializable_composition.as_ref()
+	}
+
+	/// Evaluates the composition at the evaluations of the inner polynomials.
+	///
+	/// A verifier uses this to reduce an evaluation claim on the composite to claims on the inner
+	/// polynomials at the same point, given their claimed evaluations.
+	///
+	/// ## Throws
+	///
+	/// * `Error::CompositionMismatch` if `inner_evals` has a length other than
+	///   [`Self::n_multilinears`]
+	pub fn evaluate_composition_at(&self, inner_evals: &[F]) -> Result<F, Error> {
+		if inner_evals.len() != self.n_multilinears() {
+			bail!(Error::CompositionMismatch);
+		}
+		Ok(self.composition.evaluate(inner_evals)?)
+	}
+
+	/// Evaluates the composition at every hypercube point, returning the composite multilinear.
+	///
+	/// This materializes the composite column from the witnesses of the inner polynomials, which
+	/// is useful for debugging, e.g. to check that a constraint column is zero everywhere.
+	///
+	/// ## Throws
+	///
+	/// * `WitnessError::MissingWitness` if an inner polynomial has no witness in `witness`
+	/// * `WitnessError::IncorrectNumberOfVariables` if a witness has a number of variables other
+	///   than [`Self::n_vars`]
+	pub fn evaluate_over_hypercube<P: PackedField<Scalar = F>>(
+		&self,
+		witness: &MultilinearExtensionIndex<P>,
+	) -> Result<Vec<P>, WitnessError> {
+		let inner = self
+			.inner
+			.iter()
+			.map(|oracle| {
+				let id = oracle.id();
+				let poly = witness.get_multilin_poly(id)?;
+				if poly.n_vars() != self.n_vars {
+					bail!(WitnessError::IncorrectNumberOfVariables {
+						id,
+						expected: self.n_vars,
+						actual: poly.n_vars(),
+					});
+				}
+				Ok(poly)
+			})
+			.collect::<Result<Vec<_>, WitnessError>>()?;
+
+		let mut query = vec![F::ZERO; inner.len()];
+		let evals = (0..1 << self.n_vars)
+			.map(|index| {
+				for (query_elem, poly) in izip!(&mut query, &inner) {
+					*query_elem = poly.evaluate_on_hypercube(index)?;
+				}
+				self.composition.evaluate(&query)
+			})
+			.collect::<Result<Vec<_>, _>>()?;
+
+		Ok(evals
+			.chunks(P::WIDTH)
+			.map(|chunk| P::from_scalars(chunk.iter().copied()))
+			.collect())
+	}
+
+	/// Returns the smallest tower level containing every value of the composite over the
+	/// hypercube, given the witnesses of the inner polynomials.
+	///
+	/// [`Self::binary_tower_level`] is a static upper bound on this level, which may be loose for
+	/// a particular witness, e.g. when the product of two byte columns only takes the values 0 and
+	/// 1. A prover may then commit the composite column over the smaller field.
+	///
+	/// ## Throws
+	///
+	/// * `WitnessError::MissingWitness` if an inner polynomial has no witness in `witness`
+	/// * `WitnessError::IncorrectNumberOfVariables` if a witness has a number of variables other
+	///   than [`Self::n_vars`]
+	pub fn observed_output_tower_level<P: PackedField<Scalar = F>>(
+		&self,
+		witness: &MultilinearExtensionIndex<P>,
+	) -> Result<usize, WitnessError> {
+		let evals = self.evaluate_over_hypercube(witness)?;
+		Ok(P::iter_slice(&evals)
+			.take(1 << self.n_vars)
+			.map(TowerField::min_tower_level)
+			.max()
+			.unwrap_or(0))
+	}
+}
+
+/// Checks that `composition` has at most the degree it reports.
+///
+/// The degree is derived structurally from the arithmetic circuit of
+/// [`CompositionPoly::expression`], which is what composite oracles are built from, as in the debug
+/// check of [`CompositePolyOracle::max_individual_degree`].
+///
+/// ## Throws
+///
+/// * `Error::CompositionDegreeUnderreported` if the expression has a higher degree than the claimed
+///   degree
+pub fn check_composition_degree<F: TowerField>(
+	composition: &(impl CompositionPoly<F> + ?Sized),
+) -> Result<(), Error> {
+	let claimed = composition.degree();
+	if composition.expression().degree() > claimed {
+		bail!(Error::CompositionDegreeUnderreported { claimed });
+	}
+	Ok(())
+}
+
+/// Serializes the oracle together with the description of its composition.
+///
+/// Fails with `SerializationError::SerializationNotImplemented` if the oracle was not created with
+/// [`CompositePolyOracle::new_named`].
+impl<F: TowerField> SerializeBytes for CompositePolyOracle<F> {
+	fn serialize(
+		&self,
+		mut write_buf: impl bytes::BufMut,
+		mode: SerializationMode,
+	) -> Result<(), SerializationError> {
+		let serializable_composition = self
+			.serializable_composition
+			.as_ref()
+			.ok_or(SerializationError::SerializationNotImplemented)?;
+		self.n_vars.serialize(&mut write_buf, mode)?;
+		self.inner.serialize(&mut write_buf, mode)?;
+		serializable_composition.serialize(write_buf, mode)
+	}
+}
+
+impl CompositePolyOracle<BinaryField128b> {
+	/// Deserializes an oracle, reconstructing its composition with `registry`.
+	pub fn deserialize_with_registry(
+		mut read_buf: impl bytes::Buf,
+		mode: SerializationMode,
+		registry: &CompositionRegistry<BinaryField128b>,
+	) -> Result<Self, SerializationError> {
+		let n_vars = usize::deserialize(&mut read_buf, mode)?;
+		let inner =
+			Vec::<MultilinearPolyOracle<BinaryField128b>>::deserialize(&mut read_buf, mode)?;
+		let serializable_composition = SerializableComposition::deserialize(read_buf, mode)?;
+		let composition = registry.construct(&serializable_composition)?;
+		Self::with_arc_composition(n_vars, inner, composition, Some(serializable_composition))
+			.map_err(|_| SerializationError::InvalidConstruction {
+				name: "CompositePolyOracle",
+			})
+	}
 }
 
 #[cfg(test)]
 mod tests {
-	use binius_field::{BinaryField2b, BinaryField8b, BinaryField32b, BinaryField128b, TowerField};
-	use binius_math::{ArithCircuit, ArithExpr};
+	use std::iter::repeat_with;
+
+	use assert_matches::assert_matches;
+	use binius_field::{
+		BinaryField2b, BinaryField8b, BinaryField32b, BinaryField128b, Field,
+		PackedBinaryField2x128b, TowerField,
+	};
+	use binius_macros::composition;
+	use binius_math::{ArithCircuit, ArithExpr, MultilinearExtension};
+	use rand::{SeedableRng, rngs::StdRng};
 
 	use super::*;
-	use crate::oracle::MultilinearOracleSet;
+	use crate::{composition::BivariateProduct, oracle::MultilinearOracleSet};
 
 	#[derive(Clone, Debug)]
 	struct TestByteComposition;
@@ -88,7 +294,7 @@ mod tests {
 		}
 
 		fn degree(&self) -> usize {
-			1
+			2
 		}
 
 		fn expression(&self) -> ArithCircuit<BinaryField128b> {
@@ -109,6 +315,14 @@ mod tests {
 		}
 	}
 
+	#[test]
+	fn test_expression_infix_string() {
+		assert_eq!(
+			TestByteComposition.expression().to_infix_string(),
+			"x0 * x1 + x2 * 0x0000000000000000000000000000007d"
+		);
+	}
+
 	#[test]
 	fn test_composite_tower_level() {
 		type F = BinaryField128b;
@@ -157,4 +371,340 @@ mod tests {
 		.unwrap();
 		assert_eq!(composite.binary_tower_level(), BinaryField32b::TOWER_LEVEL);
 	}
+
+	#[test]
+	fn test_composite_inner_poly_variable_mismatch() {
+		type F = BinaryField128b;
+
+		let mut oracles = MultilinearOracleSet::<F>::new();
+		let poly = oracles.add_committed(5, BinaryField8b::TOWER_LEVEL);
+		let short_poly = oracles.add_committed(4, BinaryField8b::TOWER_LEVEL);
+
+		let result = CompositePolyOracle::new(
+			5,
+			vec![
+				oracles[poly].clone(),
+				oracles[short_poly].clone(),
+				oracles[poly].clone(),
+			],
+			TestByteComposition,
+		);
+		assert_matches!(
+			result,
+			Err(Error::InnerPolyVariableMismatch {
+				index: 1,
+				expected: 5,
+				actual: 4,
+			})
+		);
+	}
+
+	/// The cube of a single variable, reporting a configurable degree.
+	#[derive(Clone, Debug)]
+	struct CubeComposition {
+		reported_degree: usize,
+	}
+
+	impl CompositionPoly<BinaryField128b> for CubeComposition {
+		fn n_vars(&self) -> usize {
+			1
+		}
+
+		fn degree(&self) -> usize {
+			self.reported_degree
+		}
+
+		fn expression(&self) -> ArithCircuit<BinaryField128b> {
+			ArithCircuit::var(0).pow(3)
+		}
+
+		fn evaluate(
+			&self,
+			query: &[BinaryField128b],
+		) -> Result<BinaryField128b, binius_math::Error> {
+			Ok(query[0] * query[0] * query[0])
+		}
+
+		fn binary_tower_level(&self) -> usize {
+			0
+		}
+	}
+
+	#[test]
+	fn test_composite_degree_matches_expression() {
+		type F = BinaryField128b;
+
+		let mut oracles = MultilinearOracleSet::<F>::new();
+		let poly = oracles.add_committed(5, BinaryField8b::TOWER_LEVEL);
+
+		let composite =
+			CompositePolyOracle::new(5, vec![oracles[poly].clone(); 3], TestByteComposition)
+				.unwrap();
+		assert_eq!(composite.max_individual_degree().unwrap(), 2);
+
+		// Overreporting the degree is sound, so it is accepted in every build.
+		for reported_degree in [3, 4] {
+			let composite = CompositePolyOracle::new(
+				5,
+				vec![oracles[poly].clone()],
+				CubeComposition { reported_degree },
+			)
+			.unwrap();
+			assert_eq!(composite.max_individual_degree().unwrap(), reported_degree);
+		}
+	}
+
+	#[cfg(debug_assertions)]
+	#[test]
+	fn test_composite_underreported_degree_rejected() {
+		type F = BinaryField128b;
+
+		let mut oracles = MultilinearOracleSet::<F>::new();
+		let poly = oracles.add_committed(5, BinaryField8b::TOWER_LEVEL);
+
+		let composite = CompositePolyOracle::new(
+			5,
+			vec![oracles[poly].clone()],
+			CubeComposition { reported_degree: 2 },
+		)
+		.unwrap();
+		assert_matches!(
+			composite.max_individual_degree(),
+			Err(Error::CompositionDegreeUnderreported { claimed: 2 })
+		);
+	}
+
+	#[test]
+	fn test_composite_serialization_with_registry() {
+		type F = BinaryField128b;
+
+		let n_vars = 5;
+		let mut oracles = MultilinearOracleSet::<F>::new();
+		let poly_8b = oracles.add_committed(n_vars, BinaryField8b::TOWER_LEVEL);
+		let poly_32b = oracles.add_committed(n_vars, BinaryField32b::TOWER_LEVEL);
+
+		let composite = CompositePolyOracle::new_named(
+			n_vars,
+			vec![oracles[poly_8b].clone(), oracles[poly_32b].clone()],
+			BivariateProduct {},
+		)
+		.unwrap();
+
+		let mut buf = Vec::new();
+		composite
+			.serialize(&mut buf, SerializationMode::CanonicalTower)
+			.unwrap();
+
+		let registry = CompositionRegistry::with_builtins();
+		let deserialized = CompositePolyOracle::deserialize_with_registry(
+			buf.as_slice(),
+			SerializationMode::CanonicalTower,
+			&registry,
+		)
+		.unwrap();
+
+		assert_eq!(deserialized.n_vars(), composite.n_vars());
+		assert_eq!(deserialized.inner_polys(), composite.inner_polys());
+		assert_eq!(deserialized.serializable_composition(), composite.serializable_composition());
+		assert_eq!(deserialized.composition().n_vars(), 2);
+		assert_eq!(deserialized.composition().degree(), 2);
+		assert_eq!(deserialized.composition().expression(), composite.composition().expression());
+		assert_eq!(deserialized.binary_tower_level(), composite.binary_tower_level());
+
+		// Deserialization fails for compositions missing from the registry.
+		assert!(
+			CompositePolyOracle::deserialize_with_registry(
+				buf.as_slice(),
+				SerializationMode::CanonicalTower,
+				&CompositionRegistry::new(),
+			)
+			.is_err()
+		);
+	}
+
+	#[test]
+	fn test_unnamed_composite_is_not_serializable() {
+		let mut oracles = MultilinearOracleSet::<BinaryField128b>::new();
+		let poly = oracles.add_committed(3, BinaryField8b::TOWER_LEVEL);
+		let composite =
+			CompositePolyOracle::new(3, vec![oracles[poly].clone(); 3], TestByteComposition)
+				.unwrap();
+
+		let mut buf = Vec::new();
+		assert!(
+			composite
+				.serialize(&mut buf, SerializationMode::CanonicalTower)
+				.is_err()
+		);
+	}
+
+	#[test]
+	fn test_evaluate_composition_at() {
+		type F = BinaryField128b;
+
+		let mut oracles = MultilinearOracleSet::<F>::new();
+		let poly = oracles.add_committed(3, BinaryField8b::TOWER_LEVEL);
+		let composite =
+			CompositePolyOracle::new(3, vec![oracles[poly].clone(); 3], TestByteComposition)
+				.unwrap();
+
+		let mut rng = StdRng::seed_from_u64(0);
+		let query = repeat_with(|| <F as Field>::random(&mut rng))
+			.take(3)
+			.collect::<Vec<_>>();
+		assert_eq!(
+			composite.evaluate_composition_at(&query).unwrap(),
+			query[0] * query[1] + query[2] * F::new(125)
+		);
+
+		assert!(matches!(
+			composite.evaluate_composition_at(&query[..2]),
+			Err(Error::CompositionMismatch)
+		));
+	}
+
+	#[test]
+	fn test_evaluate_over_hypercube() {
+		type F = BinaryField128b;
+		type P = PackedBinaryField2x128b;
+
+		let n_vars = 3;
+		let mut oracles = MultilinearOracleSet::<F>::new();
+		let ids = oracles.add_committed_multiple::<3>(n_vars, BinaryField8b::TOWER_LEVEL);
+		let composite = CompositePolyOracle::new(
+			n_vars,
+			ids.iter().map(|&id| oracles[id].clone()).collect(),
+			TestByteComposition,
+		)
+		.unwrap();
+
+		let mut rng = StdRng::seed_from_u64(0);
+		let columns = repeat_with(|| {
+			repeat_with(|| F::from(<BinaryField8b as Field>::random(&mut rng)))
+				.take(1 << n_vars)
+				.collect::<Vec<_>>()
+		})
+		.take(3)
+		.collect::<Vec<_>>();
+
+		let mut witness = MultilinearExtensionIndex::<P>::new();
+		assert!(matches!(
+			composite.evaluate_over_hypercube(&witness),
+			Err(WitnessError::MissingWitness { .. })
+		));
+
+		witness
+			.update_multilin_poly(izip!(ids, &columns).map(|(id, column)| {
+				let packed = column
+					.chunks(P::WIDTH)
+					.map(|chunk| P::from_scalars(chunk.iter().copied()))
+					.collect::<Vec<_>>();
+				(
+					id,
+					MultilinearExtension::new(n_vars, packed)
+						.unwrap()
+						.specialize_arc_dyn(),
+				)
+			}))
+			.unwrap();
+
+		let evals = composite.evaluate_over_hypercube(&witness).unwrap();
+		assert_eq!(evals.len(), (1 << n_vars) / P::WIDTH);
+		for (index, eval) in P::iter_slice(&evals).enumerate() {
+			let query = [columns[0][index], columns[1][index], columns[2][index]];
+			assert_eq!(eval, TestByteComposition.evaluate(&query).unwrap());
+		}
+	}
+
+	#[test]
+	fn test_observed_output_tower_level() {
+		type F = BinaryField128b;
+		type P = PackedBinaryField2x128b;
+
+		let n_vars = 3;
+		let mut oracles = MultilinearOracleSet::<F>::new();
+		let ids = oracles.add_committed_multiple::<2>(n_vars, BinaryField8b::TOWER_LEVEL);
+		let composite = CompositePolyOracle::new(
+			n_vars,
+			ids.iter().map(|&id| oracles[id].clone()).collect(),
+			BivariateProduct {},
+		)
+		.unwrap();
+		assert_eq!(composite.binary_tower_level(), BinaryField8b::TOWER_LEVEL);
+
+		let add_columns = |witness: &mut MultilinearExtensionIndex<P>, columns: [[u8; 8]; 2]| {
+			witness
+				.update_multilin_poly(izip!(ids, columns).map(|(id, column)| {
+					let packed = column
+						.chunks(P::WIDTH)
+						.map(|chunk| P::from_scalars(chunk.iter().map(|&x| F::from(x as u128))))
+						.collect::<Vec<_>>();
+					(
+						id,
+						MultilinearExtension::new(n_vars, packed)
+							.unwrap()
+							.specialize_arc_dyn(),
+					)
+				}))
+				.unwrap();
+		};
+
+		let mut witness = MultilinearExtensionIndex::<P>::new();
+		add_columns(&mut witness, [[1, 0, 1, 1, 0, 1, 0, 1], [1, 1, 0, 1, 0, 1, 1, 0]]);
+		assert_eq!(composite.observed_output_tower_level(&witness).unwrap(), 0);
+
+		add_columns(&mut witness, [[1, 0, 1, 1, 0, 1, 0, 1], [1, 1, 0, 1, 0, 1, 1, 2]]);
+		assert_eq!(composite.observed_output_tower_level(&witness).unwrap(), 1);
+	}
+
+	#[test]
+	fn test_evaluate_partial_composition() {
+		type F = BinaryField128b;
+
+		let mut rng = StdRng::seed_from_u64(0);
+		let [a, b] = [(); 2].map(|_| <F as Field>::random(&mut rng));
+		assert_eq!(
+			TestByteComposition
+				.evaluate_partial(&[Some(a), Some(b), None])
+				.unwrap(),
+			a * b
+		);
+		assert_eq!(
+			TestByteComposition
+				.expression()
+				.evaluate_partial(&[Some(a), Some(b), None])
+				.unwrap(),
+			a * b
+		);
+	}
+
+	#[test]
+	fn test_composition_macro_matches_hand_written_impl() {
+		type F = BinaryField128b;
+
+		let composition = composition!(v0 * v1 + v2 * 125);
+		assert_eq!(CompositionPoly::<F>::n_vars(&composition), TestByteComposition.n_vars());
+		assert_eq!(CompositionPoly::<F>::degree(&composition), TestByteComposition.degree());
+		assert_eq!(
+			CompositionPoly::<F>::binary_tower_level(&composition),
+			TestByteComposition.binary_tower_level()
+		);
+		assert_eq!(
+			CompositionPoly::<F>::expression(&composition),
+			TestByteComposition.expression()
+		);
+		check_composition_degree::<F>(&composition).unwrap();
+		check_composition_degree(&TestByteComposition).unwrap();
+
+		let mut rng = StdRng::seed_from_u64(0);
+		for _ in 0..16 {
+			let query = repeat_with(|| <F as Field>::random(&mut rng))
+				.take(3)
+				.collect::<Vec<_>>();
+			assert_eq!(
+				composition.evaluate(&query).unwrap(),
+				TestByteComposition.evaluate(&query).unwrap()
+			);
+		}
+	}
 }
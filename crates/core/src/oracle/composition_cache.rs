@@ -0,0 +1,61 @@
+// Copyright 2025 Irreducible Inc.
+
+use std::{collections::HashMap, sync::Arc};
+
+use binius_fast_compute::arith_circuit::ArithCircuitPoly;
+use binius_field::TowerField;
+use binius_math::ArithCircuit;
+
+use crate::oracle::Error;
+
+/// Interns compositions by the structure of their expressions.
+///
+/// Structurally identical compositions over the same number of variables are interned to a single
+/// [`ArithCircuitPoly`], so the composite oracles added with
+/// [`MultilinearOracleSet::add_composite_interned`](super::MultilinearOracleSet::add_composite_interned)
+/// share its memory and can be compared by pointer.
+#[derive(Debug, Clone, Default)]
+pub struct CompositionCache<F: TowerField> {
+	compositions: HashMap<(usize, ArithCircuit<F>), Arc<ArithCircuitPoly<F>>>,
+}
+
+impl<F: TowerField> CompositionCache<F> {
+	pub fn new() -> Self {
+		Self {
+			compositions: HashMap::new(),
+		}
+	}
+
+	/// Returns the interned composition of `comp` over `n_vars` variables, creating it if it is
+	/// not interned yet.
+	///
+	/// ## Throws
+	///
+	/// * [`Error::CompositionMismatch`] if `comp` has more than `n_vars` variables
+	pub fn intern(
+		&mut self,
+		n_vars: usize,
+		comp: ArithCircuit<F>,
+	) -> Result<Arc<ArithCircuitPoly<F>>, Error> {
+		if let Some(composition) = self.compositions.get(&(n_vars, comp.clone())) {
+			return Ok(composition.clone());
+		}
+
+		let composition = Arc::new(
+			ArithCircuitPoly::with_n_vars(n_vars, comp.clone())
+				.map_err(|_| Error::CompositionMismatch)?,
+		);
+		self.compositions
+			.insert((n_vars, comp), composition.clone());
+		Ok(composition)
+	}
+
+	/// The number of distinct interned compositions.
+	pub fn len(&self) -> usize {
+		self.compositions.len()
+	}
+
+	pub fn is_empty(&self) -> bool {
+		self.compositions.is_empty()
+	}
+}
@@ -14,16 +14,32 @@ pub enum Error {
 	InvalidNonzeroIndex { expected: usize },
 	#[error("expected the polynomial to have {expected} variables")]
 	IncorrectNumberOfVariables { expected: usize },
+	#[error("expected inner polynomial {index} to have {expected} variables, but it has {actual}")]
+	InnerPolyVariableMismatch {
+		index: usize,
+		expected: usize,
+		actual: usize,
+	},
 	#[error("attempted to project more variables {values_len} than inner polynomial has {n_vars}")]
 	InvalidProjection { values_len: usize, n_vars: usize },
 	#[error("invalid polynomial index in committed batch")]
 	InvalidPolynomialIndex,
+	#[error("the composition has a higher degree than the claimed degree {claimed}")]
+	CompositionDegreeUnderreported { claimed: usize },
+	#[error("math error: {0}")]
+	Math(#[from] binius_math::Error),
 	#[error("polynomial error")]
 	Polynomial(#[from] crate::polynomial::Error),
 	#[error("n_vars ({n_vars}) must be at least as big as the requested log_degree ({log_degree})")]
 	NotEnoughVarsForPacking { n_vars: usize, log_degree: usize },
 	#[error("no oracle exists in this MultilinearOracleSet with id {0}")]
 	InvalidOracleId(OracleId),
+	#[error("oracle {0} is not a composite oracle")]
+	NotCompositeOracle(OracleId),
+	#[error("a zerocheck combination needs at least one composite oracle")]
+	EmptyZerocheckCombination,
+	#[error("witness error: {0}")]
+	Witness(#[from] crate::witness::Error),
 	#[error("tower_level ({tower_level}) exceeds maximum")]
 	TowerLevelTooHigh { tower_level: usize },
 	#[error("constraint set is empty")]
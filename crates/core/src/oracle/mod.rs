@@ -7,12 +7,14 @@
 //! [DP23]: <https://eprint.iacr.org/2023/1784>
 
 mod composite;
+mod composition_cache;
 mod constraint;
 mod error;
 mod multilinear;
 mod oracle_id;
 
 pub use composite::*;
+pub use composition_cache::CompositionCache;
 pub use constraint::*;
 pub use error::Error;
 pub use multilinear::*;
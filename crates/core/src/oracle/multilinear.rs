@@ -1,29 +1,48 @@
 // Copyright 2024-2025 Irreducible Inc.
 
-use std::{array, sync::Arc};
+use std::{array, iter, sync::Arc};
 
 use binius_fast_compute::arith_circuit::ArithCircuitPoly;
-use binius_field::{BinaryField128b, Field, TowerField};
+use binius_field::{BinaryField128b, Field, PackedField, TowerField};
 use binius_macros::{DeserializeBytes, SerializeBytes};
-use binius_math::ArithCircuit;
+use binius_math::{ArithCircuit, CompositionPoly, MultilinearPoly, eq_ind_partial_eval};
 use binius_utils::{
 	DeserializeBytes, SerializationError, SerializationMode, SerializeBytes, bail, ensure,
 };
 use getset::{CopyGetters, Getters};
+use itertools::{Either, Itertools, izip};
 
 use crate::{
-	oracle::{CompositePolyOracle, Error, OracleId},
+	oracle::{CompositePolyOracle, CompositionCache, Error, OracleId, check_composition_degree},
 	polynomial::{Error as PolynomialError, IdentityCompositionPoly, MultivariatePoly},
+	transparent::constant::Constant,
+	witness::MultilinearExtensionIndex,
 };
 
 /// Meta struct that lets you add optional `name` for the Multilinear before adding to the
 /// [`MultilinearOracleSet`]
 pub struct MultilinearOracleSetAddition<'a, F: TowerField> {
 	name: Option<String>,
+	dedup: bool,
 	mut_ref: &'a mut MultilinearOracleSet<F>,
 }
 
 impl<F: TowerField> MultilinearOracleSetAddition<'_, F> {
+	/// Returns the id of an existing identical oracle instead of adding a new one.
+	///
+	/// Two oracles are identical if they have the same number of variables, tower level and
+	/// variant, which for derived oracles compares the ids of the inner oracles. The name of a
+	/// derived oracle is ignored, so the returned oracle may have another name or none. Committed
+	/// oracles have no definition besides their shape, so they are only identical if they also
+	/// have the same name, and unnamed committed oracles are always added. Transparent oracles are
+	/// only identical if they share the same polynomial instance.
+	///
+	/// The search for an identical oracle is linear in the size of the set.
+	pub const fn dedup(mut self) -> Self {
+		self.dedup = true;
+		self
+	}
+
 	pub fn transparent(self, poly: impl MultivariatePoly<F> + 'static) -> Result<OracleId, Error> {
 		if poly.binary_tower_level() > F::TOWER_LEVEL {
 			bail!(Error::TowerLevelTooHigh {
@@ -41,7 +60,14 @@ impl<F: TowerField> MultilinearOracleSetAddition<'_, F> {
 			variant: MultilinearPolyVariant::Transparent(inner),
 		};
 
-		Ok(self.mut_ref.add_to_set(oracle))
+		Ok(self.mut_ref.add_to_set(self.dedup, oracle))
+	}
+
+	/// Adds a transparent oracle with the value `value` on the whole hypercube, at the minimal
+	/// tower level containing `value`.
+	pub fn constant(self, n_vars: usize, value: F) -> OracleId {
+		self.transparent(Constant::with_min_tower_level(n_vars, value))
+			.expect("the minimal tower level of a value in F is at most the tower level of F")
 	}
 
 	pub fn structured(self, n_vars: usize, expr: ArithCircuit<F>) -> Result<OracleId, Error> {
@@ -59,7 +85,7 @@ impl<F: TowerField> MultilinearOracleSetAddition<'_, F> {
 			variant: MultilinearPolyVariant::Structured(expr),
 		};
 
-		Ok(self.mut_ref.add_to_set(oracle))
+		Ok(self.mut_ref.add_to_set(self.dedup, oracle))
 	}
 
 	pub fn committed(mut self, n_vars: usize, tower_level: usize) -> OracleId {
@@ -101,7 +127,7 @@ impl<F: TowerField> MultilinearOracleSetAddition<'_, F> {
 			},
 		};
 
-		Ok(self.mut_ref.add_to_set(oracle))
+		Ok(self.mut_ref.add_to_set(self.dedup, oracle))
 	}
 
 	pub fn shifted(
@@ -139,7 +165,7 @@ impl<F: TowerField> MultilinearOracleSetAddition<'_, F> {
 			variant: MultilinearPolyVariant::Shifted(shifted),
 		};
 
-		Ok(self.mut_ref.add_to_set(oracle))
+		Ok(self.mut_ref.add_to_set(self.dedup, oracle))
 	}
 
 	pub fn packed(self, inner_id: OracleId, log_degree: usize) -> Result<OracleId, Error> {
@@ -168,7 +194,7 @@ impl<F: TowerField> MultilinearOracleSetAddition<'_, F> {
 			variant: MultilinearPolyVariant::Packed(packed),
 		};
 
-		Ok(self.mut_ref.add_to_set(oracle))
+		Ok(self.mut_ref.add_to_set(self.dedup, oracle))
 	}
 
 	pub fn projected(
@@ -201,7 +227,7 @@ impl<F: TowerField> MultilinearOracleSetAddition<'_, F> {
 			variant: MultilinearPolyVariant::Projected(projected),
 		};
 
-		Ok(self.mut_ref.add_to_set(oracle))
+		Ok(self.mut_ref.add_to_set(self.dedup, oracle))
 	}
 
 	pub fn projected_last_vars(
@@ -234,7 +260,7 @@ impl<F: TowerField> MultilinearOracleSetAddition<'_, F> {
 			variant: MultilinearPolyVariant::Projected(projected),
 		};
 
-		Ok(self.mut_ref.add_to_set(oracle))
+		Ok(self.mut_ref.add_to_set(self.dedup, oracle))
 	}
 
 	pub fn linear_combination(
@@ -282,7 +308,40 @@ impl<F: TowerField> MultilinearOracleSetAddition<'_, F> {
 			variant: MultilinearPolyVariant::LinearCombination(linear_combination),
 		};
 
-		Ok(self.mut_ref.add_to_set(oracle))
+		Ok(self.mut_ref.add_to_set(self.dedup, oracle))
+	}
+
+	/// Adds the random linear combination $\sum_i \alpha^i C_i$ of the composite oracles $C_i$
+	/// with ids `composite_ids`, where $\alpha$ is `challenge`.
+	///
+	/// The combination vanishes on the hypercube if all of the composites do, and otherwise
+	/// vanishes at a vertex where some composite does not with probability at most
+	/// $(n - 1) / |F|$ over the challenge, for $n$ composites. A single zerocheck on the
+	/// combination therefore covers all of the composites.
+	///
+	/// ## Throws
+	///
+	/// * `Error::EmptyZerocheckCombination` if `composite_ids` is empty
+	/// * `Error::NotCompositeOracle` if one of the oracles is not a composite oracle
+	/// * `Error::IncorrectNumberOfVariables` if the composites have different numbers of variables
+	pub fn zerocheck_combination(
+		self,
+		composite_ids: &[OracleId],
+		challenge: F,
+	) -> Result<OracleId, Error> {
+		let Some(&first_id) = composite_ids.first() else {
+			bail!(Error::EmptyZerocheckCombination);
+		};
+		for &id in composite_ids {
+			ensure!(self.mut_ref.is_valid_oracle_id(id), Error::InvalidOracleId(id));
+			if !matches!(self.mut_ref[id].variant, MultilinearPolyVariant::Composite(_)) {
+				bail!(Error::NotCompositeOracle(id));
+			}
+		}
+
+		let n_vars = self.mut_ref.n_vars(first_id);
+		let coeffs = iter::successors(Some(F::ONE), |&power| Some(power * challenge));
+		self.linear_combination(n_vars, iter::zip(composite_ids.iter().copied(), coeffs))
 	}
 
 	pub fn composite_mle(
@@ -290,6 +349,53 @@ impl<F: TowerField> MultilinearOracleSetAddition<'_, F> {
 		n_vars: usize,
 		inner: impl IntoIterator<Item = OracleId>,
 		comp: ArithCircuit<F>,
+	) -> Result<OracleId, Error> {
+		self.composite_mle_with(n_vars, inner, None, |inner| CompositeMLE::new(n_vars, inner, comp))
+	}
+
+	/// Like [`Self::composite_mle`], but declares the tower level of the composite instead of
+	/// deriving it from the inner oracles.
+	///
+	/// A level below that of some inner oracle is only sound if the composition maps the values
+	/// of the inner oracles into the smaller subfield. Such narrowing composites are reported by
+	/// [`MultilinearOracleSet::check_tower_level_monotone`].
+	pub fn composite_mle_with_tower_level(
+		self,
+		n_vars: usize,
+		inner: impl IntoIterator<Item = OracleId>,
+		comp: ArithCircuit<F>,
+		tower_level: usize,
+	) -> Result<OracleId, Error> {
+		if tower_level > F::TOWER_LEVEL {
+			bail!(Error::TowerLevelTooHigh { tower_level });
+		}
+		self.composite_mle_with(n_vars, inner, Some(tower_level), |inner| {
+			CompositeMLE::new(n_vars, inner, comp)
+		})
+	}
+
+	/// Like [`Self::composite_mle`], but interns the composition in `cache`, so that oracles with
+	/// structurally identical compositions share one [`ArithCircuitPoly`].
+	pub fn composite_mle_interned(
+		self,
+		cache: &mut CompositionCache<F>,
+		n_vars: usize,
+		inner: impl IntoIterator<Item = OracleId>,
+		comp: ArithCircuit<F>,
+	) -> Result<OracleId, Error> {
+		let inner = inner.into_iter().collect::<Vec<_>>();
+		let c = cache.intern(inner.len(), comp)?;
+		self.composite_mle_with(n_vars, inner, None, |inner| {
+			CompositeMLE::with_shared_composition(n_vars, inner, c)
+		})
+	}
+
+	fn composite_mle_with(
+		self,
+		n_vars: usize,
+		inner: impl IntoIterator<Item = OracleId>,
+		tower_level: Option<usize>,
+		composite_mle: impl FnOnce(Vec<MultilinearPolyOracle<F>>) -> Result<CompositeMLE<F>, Error>,
 	) -> Result<OracleId, Error> {
 		let inner = inner
 			.into_iter()
@@ -305,13 +411,15 @@ impl<F: TowerField> MultilinearOracleSetAddition<'_, F> {
 			})
 			.collect::<Result<Vec<_>, _>>()?;
 
-		let tower_level = inner
-			.iter()
-			.map(|oracle| oracle.binary_tower_level())
-			.max()
-			.unwrap_or(0);
+		let tower_level = tower_level.unwrap_or_else(|| {
+			inner
+				.iter()
+				.map(|oracle| oracle.binary_tower_level())
+				.max()
+				.unwrap_or(0)
+		});
 
-		let composite_mle = CompositeMLE::new(n_vars, inner, comp)?;
+		let composite_mle = composite_mle(inner)?;
 
 		let oracle = |id: OracleId| MultilinearPolyOracle {
 			id,
@@ -321,7 +429,7 @@ impl<F: TowerField> MultilinearOracleSetAddition<'_, F> {
 			variant: MultilinearPolyVariant::Composite(composite_mle),
 		};
 
-		Ok(self.mut_ref.add_to_set(oracle))
+		Ok(self.mut_ref.add_to_set(self.dedup, oracle))
 	}
 
 	pub fn zero_padded(
@@ -350,7 +458,7 @@ impl<F: TowerField> MultilinearOracleSetAddition<'_, F> {
 			variant: MultilinearPolyVariant::ZeroPadded(padded),
 		};
 
-		Ok(self.mut_ref.add_to_set(oracle))
+		Ok(self.mut_ref.add_to_set(self.dedup, oracle))
 	}
 
 	fn add_committed_with_name(
@@ -367,7 +475,7 @@ impl<F: TowerField> MultilinearOracleSetAddition<'_, F> {
 			variant: MultilinearPolyVariant::Committed,
 		};
 
-		self.mut_ref.add_to_set(oracle)
+		self.mut_ref.add_to_set(self.dedup, oracle)
 	}
 }
 
@@ -414,6 +522,7 @@ impl<F: TowerField> MultilinearOracleSet<F> {
 	pub const fn add(&mut self) -> MultilinearOracleSetAddition<F> {
 		MultilinearOracleSetAddition {
 			name: None,
+			dedup: false,
 			mut_ref: self,
 		}
 	}
@@ -421,6 +530,7 @@ impl<F: TowerField> MultilinearOracleSet<F> {
 	pub fn add_named<S: ToString>(&mut self, s: S) -> MultilinearOracleSetAddition<F> {
 		MultilinearOracleSetAddition {
 			name: Some(s.to_string()),
+			dedup: false,
 			mut_ref: self,
 		}
 	}
@@ -429,12 +539,38 @@ impl<F: TowerField> MultilinearOracleSet<F> {
 		id.index() < self.oracles.len()
 	}
 
+	/// Splits the oracle ids into the ids of committed oracles and the ids of virtual oracles.
+	///
+	/// Committed oracles are the leaves of the oracle graph, whose witnesses the prover commits to,
+	/// whereas the witnesses of virtual oracles are derived from other oracles. Both lists are in
+	/// increasing order.
+	pub fn partition(&self) -> (Vec<OracleId>, Vec<OracleId>) {
+		self.iter().partition_map(|(id, oracle)| {
+			if oracle.is_committed() {
+				Either::Left(id)
+			} else {
+				Either::Right(id)
+			}
+		})
+	}
+
 	fn add_to_set(
 		&mut self,
+		dedup: bool,
 		oracle: impl FnOnce(OracleId) -> MultilinearPolyOracle<F>,
 	) -> OracleId {
 		let id = OracleId::from_index(self.oracles.len());
-		self.oracles.push(oracle(id));
+		let oracle = oracle(id);
+		if dedup {
+			if let Some(existing) = self
+				.oracles
+				.iter()
+				.find(|existing| existing.is_identical(&oracle))
+			{
+				return existing.id;
+			}
+		}
+		self.oracles.push(oracle);
 		id
 	}
 
@@ -445,10 +581,30 @@ impl<F: TowerField> MultilinearOracleSet<F> {
 		self.add().transparent(poly)
 	}
 
+	/// Adds a constant oracle, see [`MultilinearOracleSetAddition::constant`].
+	///
+	/// Constant oracles are transparent, so their witness need not be committed.
+	pub fn add_constant(&mut self, n_vars: usize, value: F) -> OracleId {
+		self.add().constant(n_vars, value)
+	}
+
 	pub fn add_committed(&mut self, n_vars: usize, tower_level: usize) -> OracleId {
 		self.add().committed(n_vars, tower_level)
 	}
 
+	/// Adds a named committed oracle, or returns the id of the committed oracle with the same name
+	/// and shape if one was already added.
+	///
+	/// See [`MultilinearOracleSetAddition::dedup`].
+	pub fn add_committed_dedup<S: ToString>(
+		&mut self,
+		name: S,
+		n_vars: usize,
+		tower_level: usize,
+	) -> OracleId {
+		self.add_named(name).dedup().committed(n_vars, tower_level)
+	}
+
 	pub fn add_committed_multiple<const N: usize>(
 		&mut self,
 		n_vars: usize,
@@ -523,6 +679,46 @@ impl<F: TowerField> MultilinearOracleSet<F> {
 			.zero_padded(id, n_pad_vars, nonzero_index, start_index)
 	}
 
+	/// Adds a composite oracle for a composition given as a [`CompositionPoly`].
+	///
+	/// In debug builds, the degree reported by the composition is first checked against its
+	/// expression with [`check_composition_degree`], since an underreported degree breaks the
+	/// soundness of sumchecks over the oracle far downstream. In release builds, this is equivalent
+	/// to [`Self::add_composite_mle`] with the composition's expression.
+	///
+	/// ## Throws
+	///
+	/// * `Error::CompositionMismatch` if the number of inner oracles differs from the number of
+	///   composition variables
+	/// * `Error::CompositionDegreeUnderreported` in debug builds, if the degree check fails
+	pub fn add_composite_checked(
+		&mut self,
+		n_vars: usize,
+		inner: impl IntoIterator<Item = OracleId>,
+		composition: &(impl CompositionPoly<F> + ?Sized),
+	) -> Result<OracleId, Error> {
+		let inner = inner.into_iter().collect::<Vec<_>>();
+		if inner.len() != composition.n_vars() {
+			bail!(Error::CompositionMismatch);
+		}
+
+		if cfg!(debug_assertions) {
+			check_composition_degree(composition)?;
+		}
+
+		self.add_composite_mle(n_vars, inner, composition.expression())
+	}
+
+	/// Adds a random linear combination of composite oracles for a single zerocheck, see
+	/// [`MultilinearOracleSetAddition::zerocheck_combination`].
+	pub fn add_zerocheck_combination(
+		&mut self,
+		composite_ids: &[OracleId],
+		challenge: F,
+	) -> Result<OracleId, Error> {
+		self.add().zerocheck_combination(composite_ids, challenge)
+	}
+
 	pub fn add_composite_mle(
 		&mut self,
 		n_vars: usize,
@@ -532,6 +728,19 @@ impl<F: TowerField> MultilinearOracleSet<F> {
 		self.add().composite_mle(n_vars, inner, comp)
 	}
 
+	/// Like [`Self::add_composite_mle`], but interns the composition in `cache`, so that oracles
+	/// with structurally identical compositions share one [`ArithCircuitPoly`].
+	pub fn add_composite_interned(
+		&mut self,
+		cache: &mut CompositionCache<F>,
+		n_vars: usize,
+		inner: impl IntoIterator<Item = OracleId>,
+		comp: ArithCircuit<F>,
+	) -> Result<OracleId, Error> {
+		self.add()
+			.composite_mle_interned(cache, n_vars, inner, comp)
+	}
+
 	pub fn n_vars(&self, id: OracleId) -> usize {
 		self[id].n_vars()
 	}
@@ -544,6 +753,158 @@ impl<F: TowerField> MultilinearOracleSet<F> {
 	pub fn tower_level(&self, id: OracleId) -> usize {
 		self[id].binary_tower_level()
 	}
+
+	/// Returns the minimum and maximum tower level over all oracles, or `None` if the set is empty.
+	pub fn tower_level_range(&self) -> Option<(usize, usize)> {
+		self.polys()
+			.map(MultilinearPolyOracle::binary_tower_level)
+			.fold(None, |range, level| match range {
+				None => Some((level, level)),
+				Some((min, max)) => Some((min.min(level), max.max(level))),
+			})
+	}
+
+	/// Returns the minimum number of variables over all oracles, or `None` if the set is empty.
+	pub fn min_n_vars(&self) -> Option<usize> {
+		self.polys().map(MultilinearPolyOracle::n_vars).min()
+	}
+
+	/// Returns the maximum number of variables over all oracles, or `None` if the set is empty.
+	pub fn max_n_vars(&self) -> Option<usize> {
+		self.polys().map(MultilinearPolyOracle::n_vars).max()
+	}
+
+	/// Checks that no composite oracle has an inner oracle of a higher tower level than its own.
+	///
+	/// A composite whose inner oracle exceeds its declared tower level usually indicates a
+	/// misconfigured field size. Returns the ids of all such composites, in ascending order.
+	pub fn check_tower_level_monotone(&self) -> Result<(), Vec<OracleId>> {
+		self.check_tower_level_monotone_with(|_| false)
+	}
+
+	/// Like [`Self::check_tower_level_monotone`], but accepts the composites for which
+	/// `may_narrow` returns true, for circuits in which some composites legitimately narrow the
+	/// values of their inner oracles into a smaller subfield.
+	pub fn check_tower_level_monotone_with(
+		&self,
+		may_narrow: impl Fn(&MultilinearPolyOracle<F>) -> bool,
+	) -> Result<(), Vec<OracleId>> {
+		let violations = self
+			.polys()
+			.filter(|oracle| {
+				let MultilinearPolyVariant::Composite(composite) = &oracle.variant else {
+					return false;
+				};
+				composite
+					.polys()
+					.any(|inner_id| self.tower_level(inner_id) > oracle.tower_level)
+					&& !may_narrow(oracle)
+			})
+			.map(|oracle| oracle.id)
+			.collect::<Vec<_>>();
+
+		if violations.is_empty() {
+			Ok(())
+		} else {
+			Err(violations)
+		}
+	}
+
+	/// Evaluates the composite oracles `composite_ids` at `point`, given the witnesses of their
+	/// inner oracles.
+	///
+	/// A composite oracle is the multilinear extension of its composition applied to the inner
+	/// oracles over the hypercube, so its evaluation at `point` is the sum over the hypercube of
+	/// the composition values weighted by the equality indicator at `point`. The composites are
+	/// evaluated in a single pass over the hypercube, in which the value of every inner oracle is
+	/// read once per hypercube point and shared by all composites over that inner oracle.
+	///
+	/// ## Throws
+	///
+	/// * `Error::NotCompositeOracle` if an id is not a composite oracle
+	/// * `Error::IncorrectNumberOfVariables` if a composite does not have `point.len()` variables
+	/// * `Error::Witness` if the witness of an inner oracle is missing
+	pub fn evaluate_composites_at<P: PackedField<Scalar = F>>(
+		&self,
+		composite_ids: &[OracleId],
+		point: &[F],
+		witness: &MultilinearExtensionIndex<P>,
+	) -> Result<Vec<F>, Error> {
+		self.evaluate_composites_with(composite_ids, point, |id| {
+			let poly = witness.get_multilin_poly(id)?;
+			Ok(move |index| Ok(poly.evaluate_on_hypercube(index)?))
+		})
+	}
+
+	/// Like [`Self::evaluate_composites_at`], but reads the values of the inner oracles with the
+	/// readers returned by `inner_reader(id)`, which is called once per distinct inner oracle.
+	fn evaluate_composites_with<R>(
+		&self,
+		composite_ids: &[OracleId],
+		point: &[F],
+		inner_reader: impl FnMut(OracleId) -> Result<R, Error>,
+	) -> Result<Vec<F>, Error>
+	where
+		R: Fn(usize) -> Result<F, Error>,
+	{
+		let composites = composite_ids
+			.iter()
+			.map(|&id| {
+				let MultilinearPolyVariant::Composite(composite) = &self[id].variant else {
+					bail!(Error::NotCompositeOracle(id));
+				};
+				if composite.n_vars() != point.len() {
+					bail!(Error::IncorrectNumberOfVariables {
+						expected: point.len(),
+					});
+				}
+				Ok(composite)
+			})
+			.collect::<Result<Vec<_>, Error>>()?;
+
+		// The distinct inner oracles, and the positions of every composite's inner oracles in it.
+		let shared_ids = composites
+			.iter()
+			.flat_map(|composite| composite.polys())
+			.unique()
+			.collect::<Vec<_>>();
+		let query_indices = composites
+			.iter()
+			.map(|composite| {
+				composite
+					.polys()
+					.map(|id| {
+						shared_ids
+							.iter()
+							.position(|&shared_id| shared_id == id)
+							.expect("shared_ids contains the inner oracles of every composite")
+					})
+					.collect::<Vec<_>>()
+			})
+			.collect::<Vec<_>>();
+
+		let shared_readers = shared_ids
+			.iter()
+			.copied()
+			.map(inner_reader)
+			.collect::<Result<Vec<_>, Error>>()?;
+
+		let eq_ind = eq_ind_partial_eval::<F>(point);
+		let mut shared_evals = vec![F::ZERO; shared_ids.len()];
+		let mut query = Vec::new();
+		let mut evals = vec![F::ZERO; composites.len()];
+		for (index, &eq_ind_eval) in eq_ind.iter().enumerate() {
+			for (shared_eval, reader) in iter::zip(&mut shared_evals, &shared_readers) {
+				*shared_eval = reader(index)?;
+			}
+			for (eval, composite, indices) in izip!(&mut evals, &composites, &query_indices) {
+				query.clear();
+				query.extend(indices.iter().map(|&i| shared_evals[i]));
+				*eval += eq_ind_eval * composite.c().evaluate(&query)?;
+			}
+		}
+		Ok(evals)
+	}
 }
 
 impl<F: TowerField> std::ops::Index<OracleId> for MultilinearOracleSet<F> {
@@ -573,6 +934,11 @@ impl<F: TowerField> std::ops::Index<OracleId> for MultilinearOracleSet<F> {
 ///    instead admits an interactive reduction for evaluation queries to evaluation queries to other
 ///    oracles. This is formalized in [DP23] Section 4.
 ///
+/// Oracles compare equal if they have the same id, name, shape and definition. Derived oracles
+/// refer to their inner oracles by id, compositions are compared by their expressions and
+/// transparent oracles by the identity of their polynomial. See [`Self::is_identical`] for a
+/// comparison ignoring ids.
+///
 /// [DP23]: <https://eprint.iacr.org/2023/1784>
 #[derive(Debug, Clone, PartialEq, Eq, SerializeBytes, DeserializeBytes)]
 #[deserialize_bytes(eval_generics(F = BinaryField128b))]
@@ -612,6 +978,22 @@ impl<F: TowerField> MultilinearPolyVariant<F> {
 	pub fn is_committed(&self) -> bool {
 		matches!(self, Self::Committed)
 	}
+
+	/// Returns the ids of the oracles this variant is defined in terms of.
+	///
+	/// Committed, transparent and structured oracles have no inner oracles.
+	pub fn inner_oracle_ids(&self) -> Vec<OracleId> {
+		match self {
+			Self::Committed | Self::Transparent(_) | Self::Structured(_) => Vec::new(),
+			Self::Repeating { id, .. } => vec![*id],
+			Self::Projected(projected) => vec![projected.id()],
+			Self::Shifted(shifted) => vec![shifted.id()],
+			Self::Packed(packed) => vec![packed.id()],
+			Self::ZeroPadded(padded) => vec![padded.id()],
+			Self::LinearCombination(linear_combination) => linear_combination.polys().collect(),
+			Self::Composite(composite) => composite.polys().collect(),
+		}
+	}
 }
 
 impl DeserializeBytes for MultilinearPolyVariant<BinaryField128b> {
@@ -897,8 +1279,7 @@ pub struct CompositeMLE<F: TowerField> {
 	#[getset(get = "pub")]
 	inner: Vec<OracleId>,
 	/// $C$
-	#[getset(get = "pub")]
-	c: ArithCircuitPoly<F>,
+	c: Arc<ArithCircuitPoly<F>>,
 }
 
 impl<F: TowerField> CompositeMLE<F> {
@@ -907,7 +1288,35 @@ impl<F: TowerField> CompositeMLE<F> {
 		inner: impl IntoIterator<Item = MultilinearPolyOracle<F>>,
 		c: ArithCircuit<F>,
 	) -> Result<Self, Error> {
-		let inner = inner
+		let inner = Self::inner_ids(n_vars, inner)?;
+		let c = ArithCircuitPoly::with_n_vars(inner.len(), c)
+			.map_err(|_| Error::CompositionMismatch)?; // occurs if `c` has more variables than `inner.len()`
+		Ok(Self {
+			n_vars,
+			inner,
+			c: Arc::new(c),
+		})
+	}
+
+	/// Creates a composite MLE whose composition is shared with other oracles, as interned by a
+	/// [`CompositionCache`].
+	pub fn with_shared_composition(
+		n_vars: usize,
+		inner: impl IntoIterator<Item = MultilinearPolyOracle<F>>,
+		c: Arc<ArithCircuitPoly<F>>,
+	) -> Result<Self, Error> {
+		let inner = Self::inner_ids(n_vars, inner)?;
+		if <_ as CompositionPoly<F>>::n_vars(&*c) != inner.len() {
+			bail!(Error::CompositionMismatch);
+		}
+		Ok(Self { n_vars, inner, c })
+	}
+
+	fn inner_ids(
+		n_vars: usize,
+		inner: impl IntoIterator<Item = MultilinearPolyOracle<F>>,
+	) -> Result<Vec<OracleId>, Error> {
+		inner
 			.into_iter()
 			.map(|oracle| {
 				if oracle.n_vars() == n_vars {
@@ -916,10 +1325,18 @@ impl<F: TowerField> CompositeMLE<F> {
 					Err(Error::IncorrectNumberOfVariables { expected: n_vars })
 				}
 			})
-			.collect::<Result<Vec<_>, _>>()?;
-		let c = ArithCircuitPoly::with_n_vars(inner.len(), c)
-			.map_err(|_| Error::CompositionMismatch)?; // occurs if `c` has more variables than `inner.len()`
-		Ok(Self { n_vars, inner, c })
+			.collect()
+	}
+
+	/// $C$
+	pub fn c(&self) -> &ArithCircuitPoly<F> {
+		&self.c
+	}
+
+	/// The shared pointer to $C$, which is the same for all oracles added with the same interned
+	/// composition.
+	pub const fn c_arc(&self) -> &Arc<ArithCircuitPoly<F>> {
+		&self.c
 	}
 
 	pub fn polys(&self) -> impl Iterator<Item = OracleId> + '_ {
@@ -971,6 +1388,23 @@ impl<F: TowerField> MultilinearPolyOracle<F> {
 		self.tower_level
 	}
 
+	/// Returns true if the oracle is committed, as opposed to virtual.
+	pub fn is_committed(&self) -> bool {
+		self.variant.is_committed()
+	}
+
+	/// Whether the oracles define the same polynomial, in the sense of
+	/// [`MultilinearOracleSetAddition::dedup`].
+	///
+	/// Unlike `==`, this ignores the ids of the oracles and the names of derived oracles, so it
+	/// also holds for identical oracles added at different positions of a set.
+	pub fn is_identical(&self, other: &Self) -> bool {
+		self.n_vars == other.n_vars
+			&& self.tower_level == other.tower_level
+			&& self.variant == other.variant
+			&& (!self.variant.is_committed() || (self.name.is_some() && self.name == other.name))
+	}
+
 	pub fn into_composite(self) -> CompositePolyOracle<F> {
 		let composite =
 			CompositePolyOracle::new(self.n_vars(), vec![self], IdentityCompositionPoly);
@@ -980,9 +1414,516 @@ impl<F: TowerField> MultilinearPolyOracle<F> {
 
 #[cfg(test)]
 mod tests {
-	use binius_field::{BinaryField1b, BinaryField128b, Field, TowerField};
+	use std::{cell::Cell, iter::repeat_with, sync::Arc};
+
+	use assert_matches::assert_matches;
+	use binius_field::{
+		BinaryField1b, BinaryField2b, BinaryField8b, BinaryField32b, BinaryField128b, Field,
+		PackedBinaryField1x128b, PackedField, TowerField,
+	};
+	use binius_math::{
+		ArithCircuit, CompositionPoly, MLEDirectAdapter, MultilinearExtension, MultilinearPoly,
+		MultilinearQuery,
+	};
+	use itertools::izip;
+	use rand::{SeedableRng, rngs::StdRng};
+
+	use super::{MultilinearOracleSet, MultilinearPolyVariant};
+	use crate::{
+		composition::BivariateProduct,
+		oracle::{CompositionCache, Error},
+		witness::MultilinearExtensionIndex,
+	};
+
+	/// The bivariate product, mislabeled as having degree 1.
+	#[derive(Debug)]
+	struct MislabeledProduct;
+
+	impl<P: PackedField> CompositionPoly<P> for MislabeledProduct {
+		fn n_vars(&self) -> usize {
+			2
+		}
+
+		fn degree(&self) -> usize {
+			1
+		}
+
+		fn expression(&self) -> ArithCircuit<P::Scalar> {
+			ArithCircuit::var(0) * ArithCircuit::var(1)
+		}
+
+		fn evaluate(&self, query: &[P]) -> Result<P, binius_math::Error> {
+			Ok(query[0] * query[1])
+		}
+
+		fn binary_tower_level(&self) -> usize {
+			0
+		}
+	}
+
+	#[test]
+	fn test_add_composite_checked() {
+		type F = BinaryField128b;
+		let mut oracles = MultilinearOracleSet::<F>::new();
+		let [a, b] = oracles.add_committed_multiple(5, BinaryField1b::TOWER_LEVEL);
+
+		let composite = oracles
+			.add_composite_checked(5, [a, b], &BivariateProduct {})
+			.unwrap();
+		assert_eq!(oracles.n_vars(composite), 5);
+
+		assert_matches!(
+			oracles.add_composite_checked(5, [a], &BivariateProduct {}),
+			Err(Error::CompositionMismatch)
+		);
+
+		let result = oracles.add_composite_checked(5, [a, b], &MislabeledProduct);
+		if cfg!(debug_assertions) {
+			assert_matches!(result, Err(Error::CompositionDegreeUnderreported { claimed: 1 }));
+		} else {
+			assert!(result.is_ok());
+		}
+	}
+
+	#[test]
+	fn test_partition() {
+		type F = BinaryField128b;
+		let mut oracles = MultilinearOracleSet::<F>::new();
+		let [a, b] = oracles.add_committed_multiple(5, BinaryField1b::TOWER_LEVEL);
+		let composite = oracles
+			.add_composite_mle(5, [a, b], ArithCircuit::var(0) * ArithCircuit::var(1))
+			.unwrap();
+
+		assert!(oracles[a].is_committed());
+		assert!(!oracles[composite].is_committed());
+		assert_eq!(oracles.partition(), (vec![a, b], vec![composite]));
+	}
+
+	#[test]
+	fn test_add_constant() {
+		type F = BinaryField128b;
+		type P = PackedBinaryField1x128b;
+
+		let mut oracles = MultilinearOracleSet::<F>::new();
+		let one = oracles.add_constant(4, F::ONE);
+		assert_eq!(oracles.tower_level(one), 0);
+
+		let value = F::from(BinaryField8b::new(0x2A));
+		let constant = oracles.add_constant(4, value);
+		assert_eq!(oracles.tower_level(constant), BinaryField8b::TOWER_LEVEL);
+		assert_eq!(oracles.n_vars(constant), 4);
+
+		let witness = MultilinearExtensionIndex::<P>::new()
+			.materialize(&oracles[constant])
+			.unwrap();
+		for i in 0..1 << 4 {
+			assert_eq!(witness.evaluate_on_hypercube(i).unwrap(), value);
+		}
+
+		let MultilinearPolyVariant::Transparent(transparent) = &oracles[constant].variant else {
+			panic!("constant oracles are transparent");
+		};
+		let mut rng = StdRng::seed_from_u64(0);
+		let point = repeat_with(|| <F as Field>::random(&mut rng))
+			.take(4)
+			.collect::<Vec<_>>();
+		assert_eq!(transparent.poly().evaluate(&point).unwrap(), value);
+	}
+
+	#[test]
+	fn test_add_zerocheck_combination() {
+		type F = BinaryField128b;
+		type P = PackedBinaryField1x128b;
+		const N_VARS: usize = 3;
+
+		let mut oracles = MultilinearOracleSet::<F>::new();
+		let [a, b] = oracles.add_committed_multiple(N_VARS, F::TOWER_LEVEL);
+		let ab = oracles
+			.add_composite_mle(N_VARS, [a, b], ArithCircuit::var(0) * ArithCircuit::var(1))
+			.unwrap();
+		let a_plus_b = oracles
+			.add_composite_mle(N_VARS, [a, b], ArithCircuit::var(0) + ArithCircuit::var(1))
+			.unwrap();
+
+		let mut rng = StdRng::seed_from_u64(0);
+		let challenge = <F as Field>::random(&mut rng);
+		let combination = oracles
+			.add_zerocheck_combination(&[ab, a_plus_b], challenge)
+			.unwrap();
+		assert_eq!(oracles.n_vars(combination), N_VARS);
+
+		// Both composites vanish where a = b = 0, and only the product vanishes where just one
+		// of a and b is zero.
+		let a_evals = [0, 0, 1, 3, 0, 5, 7, 0].map(F::new);
+		let b_evals = [0, 0, 0, 3, 2, 0, 7, 0].map(F::new);
+		let mut witness = MultilinearExtensionIndex::<P>::new();
+		for (id, evals) in [(a, a_evals), (b, b_evals)] {
+			let values = evals.map(P::set_single).to_vec();
+			witness
+				.update_multilin_poly([(
+					id,
+					MultilinearExtension::from_values(values)
+						.unwrap()
+						.specialize_arc_dyn(),
+				)])
+				.unwrap();
+		}
+		for id in [ab, a_plus_b, combination] {
+			let evals = witness.materialize(&oracles[id]).unwrap();
+			witness
+				.update_multilin_poly([(id, MLEDirectAdapter::from(evals).upcast_arc_dyn())])
+				.unwrap();
+		}
+
+		let get = |id, i| {
+			witness
+				.get_multilin_poly(id)
+				.unwrap()
+				.evaluate_on_hypercube(i)
+				.unwrap()
+		};
+		for i in 0..1 << N_VARS {
+			let all_zero = get(ab, i) == F::ZERO && get(a_plus_b, i) == F::ZERO;
+			assert_eq!(get(combination, i) == F::ZERO, all_zero);
+		}
+
+		assert_matches!(
+			oracles.add_zerocheck_combination(&[ab, a], challenge),
+			Err(Error::NotCompositeOracle(id)) if id == a
+		);
+		assert_matches!(
+			oracles.add_zerocheck_combination(&[], challenge),
+			Err(Error::EmptyZerocheckCombination)
+		);
+		let [c, d] = oracles.add_committed_multiple(N_VARS + 1, F::TOWER_LEVEL);
+		let cd = oracles
+			.add_composite_mle(N_VARS + 1, [c, d], ArithCircuit::var(0) * ArithCircuit::var(1))
+			.unwrap();
+		assert_matches!(
+			oracles.add_zerocheck_combination(&[ab, cd], challenge),
+			Err(Error::IncorrectNumberOfVariables { .. })
+		);
+	}
+
+	#[test]
+	fn test_evaluate_composites_at() {
+		type F = BinaryField128b;
+		const N_VARS: usize = 3;
+
+		let mut oracles = MultilinearOracleSet::<F>::new();
+		let ids = oracles.add_committed_multiple::<3>(N_VARS, F::TOWER_LEVEL);
+		let [a, b, c] = ids;
+		let ab = oracles
+			.add_composite_mle(N_VARS, [a, b], ArithCircuit::var(0) * ArithCircuit::var(1))
+			.unwrap();
+		let bc = oracles
+			.add_composite_mle(
+				N_VARS,
+				[b, c],
+				ArithCircuit::var(0) * ArithCircuit::var(1) + ArithCircuit::var(0),
+			)
+			.unwrap();
+
+		let mut rng = StdRng::seed_from_u64(0);
+		let columns = ids.map(|_| {
+			(0..1 << N_VARS)
+				.map(|_| <F as Field>::random(&mut rng))
+				.collect::<Vec<_>>()
+		});
+		let point = (0..N_VARS)
+			.map(|_| <F as Field>::random(&mut rng))
+			.collect::<Vec<_>>();
+
+		let mut witness = MultilinearExtensionIndex::<F>::new();
+		witness
+			.update_multilin_poly(izip!(ids, &columns).map(|(id, column)| {
+				let mle = MultilinearExtension::from_values(column.clone()).unwrap();
+				(id, MLEDirectAdapter::from(mle).upcast_arc_dyn())
+			}))
+			.unwrap();
+
+		let query = MultilinearQuery::<F>::expand(&point);
+		let evaluate = |values: Vec<F>| {
+			MultilinearExtension::from_values(values)
+				.unwrap()
+				.evaluate(&query)
+				.unwrap()
+		};
+		let [a_values, b_values, c_values] = &columns;
+		let ab_eval = evaluate(izip!(a_values, b_values).map(|(&a, &b)| a * b).collect());
+		let bc_eval = evaluate(
+			izip!(b_values, c_values)
+				.map(|(&b, &c)| b * c + b)
+				.collect(),
+		);
+
+		let evals = oracles
+			.evaluate_composites_at(&[ab, bc], &point, &witness)
+			.unwrap();
+		assert_eq!(evals, [ab_eval, bc_eval]);
+		for (id, eval) in [(ab, ab_eval), (bc, bc_eval)] {
+			assert_eq!(
+				oracles
+					.evaluate_composites_at(&[id], &point, &witness)
+					.unwrap(),
+				[eval]
+			);
+		}
+
+		// Every inner oracle is looked up once, and the shared column b is read once per hypercube
+		// point rather than once per composite.
+		let mut n_lookups = 0;
+		let n_inner_evals = Cell::new(0);
+		oracles
+			.evaluate_composites_with(&[ab, bc], &point, |id| {
+				n_lookups += 1;
+				let poly = witness.get_multilin_poly(id)?;
+				let n_inner_evals = &n_inner_evals;
+				Ok(move |index| {
+					n_inner_evals.set(n_inner_evals.get() + 1);
+					Ok(poly.evaluate_on_hypercube(index)?)
+				})
+			})
+			.unwrap();
+		assert_eq!(n_lookups, 3);
+		assert_eq!(n_inner_evals.get(), 3 << N_VARS);
+
+		assert_matches!(
+			oracles.evaluate_composites_at(&[a], &point, &witness),
+			Err(Error::NotCompositeOracle(id)) if id == a
+		);
+		assert_matches!(
+			oracles.evaluate_composites_at(&[ab], &point[1..], &witness),
+			Err(Error::IncorrectNumberOfVariables { expected: 2 })
+		);
+	}
+
+	#[test]
+	fn test_add_composite_interned() {
+		type F = BinaryField128b;
+		let mut oracles = MultilinearOracleSet::<F>::new();
+		let mut cache = CompositionCache::new();
+		let [a, b, c] = oracles.add_committed_multiple(5, BinaryField1b::TOWER_LEVEL);
+
+		let product = || ArithCircuit::<F>::var(0) * ArithCircuit::var(1);
+		let composition = |oracles: &MultilinearOracleSet<F>, id| match &oracles[id].variant {
+			MultilinearPolyVariant::Composite(composite) => composite.c_arc().clone(),
+			_ => unreachable!(),
+		};
+
+		let ab = oracles
+			.add_composite_interned(&mut cache, 5, [a, b], product())
+			.unwrap();
+		let bc = oracles
+			.add_composite_interned(&mut cache, 5, [b, c], product())
+			.unwrap();
+		let sum = oracles
+			.add_composite_interned(
+				&mut cache,
+				5,
+				[a, c],
+				ArithCircuit::var(0) + ArithCircuit::var(1),
+			)
+			.unwrap();
+		let uninterned = oracles.add_composite_mle(5, [a, c], product()).unwrap();
 
-	use super::MultilinearOracleSet;
+		assert!(Arc::ptr_eq(&composition(&oracles, ab), &composition(&oracles, bc)));
+		assert!(!Arc::ptr_eq(&composition(&oracles, ab), &composition(&oracles, sum)));
+		assert!(!Arc::ptr_eq(&composition(&oracles, ab), &composition(&oracles, uninterned)));
+		assert_eq!(composition(&oracles, ab), composition(&oracles, uninterned));
+		assert_eq!(cache.len(), 2);
+
+		assert_matches!(
+			oracles.add_composite_interned(&mut cache, 5, [a], product()),
+			Err(Error::CompositionMismatch)
+		);
+	}
+
+	#[test]
+	fn test_add_linear_combination_with_offset() {
+		type F = BinaryField128b;
+		const N_VARS: usize = 4;
+
+		let mut oracles = MultilinearOracleSet::<F>::new();
+		let [a, b] = oracles.add_committed_multiple(N_VARS, BinaryField1b::TOWER_LEVEL);
+		let (two, three, five) = (F::new(2), F::new(3), F::new(5));
+		let lincom = oracles
+			.add_linear_combination_with_offset(N_VARS, five, [(a, two), (b, three)])
+			.unwrap();
+
+		// The tower level is that of the offset 5, which needs 4 bits.
+		assert_eq!(oracles.n_vars(lincom), N_VARS);
+		assert_eq!(oracles.tower_level(lincom), 2);
+
+		let mismatched = oracles.add_committed(N_VARS + 1, BinaryField1b::TOWER_LEVEL);
+		assert_matches!(
+			oracles.add_linear_combination_with_offset(
+				N_VARS,
+				five,
+				[(a, two), (mismatched, three)]
+			),
+			Err(Error::IncorrectNumberOfVariables { expected: N_VARS })
+		);
+
+		let mut rng = StdRng::seed_from_u64(0);
+		let a_values = (0..1 << N_VARS)
+			.map(|_| <F as Field>::random(&mut rng))
+			.collect::<Vec<_>>();
+		let b_values = (0..1 << N_VARS)
+			.map(|_| <F as Field>::random(&mut rng))
+			.collect::<Vec<_>>();
+		let point = (0..N_VARS)
+			.map(|_| <F as Field>::random(&mut rng))
+			.collect::<Vec<_>>();
+		let query = MultilinearQuery::<F>::expand(&point);
+		let evaluate = |values: &[F]| {
+			MultilinearExtension::from_values_slice(values)
+				.unwrap()
+				.evaluate(&query)
+				.unwrap()
+		};
+		let a_eval = evaluate(&a_values);
+		let b_eval = evaluate(&b_values);
+
+		let MultilinearPolyVariant::LinearCombination(linear_combination) =
+			&oracles[lincom].variant
+		else {
+			panic!("expected a linear combination");
+		};
+		let symbolic_eval = linear_combination.offset()
+			+ linear_combination
+				.polys()
+				.zip(linear_combination.coefficients())
+				.map(|(id, coeff)| coeff * if id == a { a_eval } else { b_eval })
+				.sum::<F>();
+
+		let lincom_values = a_values
+			.iter()
+			.zip(&b_values)
+			.map(|(&a, &b)| two * a + three * b + five)
+			.collect::<Vec<_>>();
+		assert_eq!(symbolic_eval, evaluate(&lincom_values));
+		assert_eq!(symbolic_eval, two * a_eval + three * b_eval + five);
+	}
+
+	#[test]
+	fn test_tower_level_and_n_vars_range() {
+		type F = BinaryField128b;
+		let mut oracles = MultilinearOracleSet::<F>::new();
+		assert_eq!(oracles.tower_level_range(), None);
+		assert_eq!(oracles.min_n_vars(), None);
+		assert_eq!(oracles.max_n_vars(), None);
+
+		oracles.add_committed(7, BinaryField8b::TOWER_LEVEL);
+		oracles.add_committed(3, BinaryField2b::TOWER_LEVEL);
+		oracles.add_committed(10, BinaryField32b::TOWER_LEVEL);
+		assert_eq!(oracles.tower_level_range(), Some((1, 5)));
+		assert_eq!(oracles.min_n_vars(), Some(3));
+		assert_eq!(oracles.max_n_vars(), Some(10));
+	}
+
+	#[test]
+	fn test_dedup_oracles() {
+		type F = BinaryField128b;
+		let mut oracles = MultilinearOracleSet::<F>::new();
+		let [a, b] = oracles.add_committed_multiple(4, BinaryField8b::TOWER_LEVEL);
+		let inner = [(a, F::new(2)), (b, F::new(3))];
+
+		let lincom = oracles.add_linear_combination(4, inner).unwrap();
+		let dedup = oracles
+			.add_named("lincom")
+			.dedup()
+			.linear_combination(4, inner)
+			.unwrap();
+		assert_eq!(dedup, lincom);
+		assert_ne!(oracles.add_linear_combination(4, inner).unwrap(), lincom);
+
+		let different = oracles
+			.add()
+			.dedup()
+			.linear_combination(4, [(a, F::new(2)), (b, F::new(5))])
+			.unwrap();
+		assert_ne!(different, lincom);
+
+		let c = oracles.add_committed_dedup("c", 4, BinaryField8b::TOWER_LEVEL);
+		assert_eq!(oracles.add_committed_dedup("c", 4, BinaryField8b::TOWER_LEVEL), c);
+		assert_ne!(oracles.add_committed_dedup("c", 5, BinaryField8b::TOWER_LEVEL), c);
+		assert_ne!(oracles.add_committed_dedup("d", 4, BinaryField8b::TOWER_LEVEL), c);
+		assert_ne!(
+			oracles
+				.add()
+				.dedup()
+				.committed(4, BinaryField8b::TOWER_LEVEL),
+			a
+		);
+	}
+
+	#[test]
+	fn test_structural_equality() {
+		type F = BinaryField128b;
+
+		let build = |coeff: u128| {
+			let mut oracles = MultilinearOracleSet::<F>::new();
+			let [a, b] = oracles.add_committed_multiple(4, BinaryField8b::TOWER_LEVEL);
+			let lincom = oracles
+				.add_linear_combination(4, [(a, F::new(2)), (b, F::new(coeff))])
+				.unwrap();
+			let product = oracles
+				.add_composite_mle(4, [a, b], ArithCircuit::var(0) * ArithCircuit::var(1))
+				.unwrap();
+			(oracles[lincom].clone(), oracles[product].clone())
+		};
+
+		let (lincom, product) = build(3);
+		let (lincom_again, product_again) = build(3);
+		assert_eq!(lincom, lincom_again);
+		assert_eq!(product, product_again);
+
+		let (different, _) = build(5);
+		assert_ne!(lincom, different);
+		assert!(!lincom.is_identical(&different));
+
+		let mut oracles = MultilinearOracleSet::<F>::new();
+		let [a, b] = oracles.add_committed_multiple(4, BinaryField8b::TOWER_LEVEL);
+		let first = oracles
+			.add_linear_combination(4, [(a, F::new(2)), (b, F::new(3))])
+			.unwrap();
+		let second = oracles
+			.add_named("second")
+			.linear_combination(4, [(a, F::new(2)), (b, F::new(3))])
+			.unwrap();
+		assert_ne!(oracles[first], oracles[second]);
+		assert!(oracles[first].is_identical(&oracles[second]));
+	}
+
+	#[test]
+	fn test_check_tower_level_monotone() {
+		type F = BinaryField128b;
+		let mut oracles = MultilinearOracleSet::<F>::new();
+		let a = oracles.add_committed(4, BinaryField32b::TOWER_LEVEL);
+		let b = oracles.add_committed(4, BinaryField8b::TOWER_LEVEL);
+		let product = ArithCircuit::var(0) * ArithCircuit::var(1);
+
+		let derived = oracles
+			.add_composite_mle(4, [a, b], product.clone())
+			.unwrap();
+		oracles
+			.add()
+			.composite_mle_with_tower_level(4, [b, b], product.clone(), BinaryField8b::TOWER_LEVEL)
+			.unwrap();
+		assert_eq!(oracles.tower_level(derived), BinaryField32b::TOWER_LEVEL);
+		assert_eq!(oracles.check_tower_level_monotone(), Ok(()));
+
+		let narrowing = oracles
+			.add()
+			.composite_mle_with_tower_level(4, [a, b], product, BinaryField8b::TOWER_LEVEL)
+			.unwrap();
+		assert_eq!(oracles.check_tower_level_monotone(), Err(vec![narrowing]));
+		assert_eq!(
+			oracles.check_tower_level_monotone_with(|oracle| oracle.id() == narrowing),
+			Ok(())
+		);
+	}
 
 	#[test]
 	fn add_projection_with_all_vars() {
@@ -6,7 +6,13 @@ use binius_utils::{DeserializeBytes, SerializationError, SerializationMode, Seri
 
 /// Identifier for a multilinear oracle in a [`super::MultilinearOracleSet`].
 ///
-/// This is essentially an index.
+/// This is essentially an index. It converts to and from `usize` explicitly, but deliberately
+/// supports no arithmetic, so that it cannot be mixed up with other indices:
+///
+/// ```compile_fail
+/// # use binius_core::oracle::OracleId;
+/// let id = OracleId::from(3) + 1;
+/// ```
 #[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub struct OracleId(usize);
 
@@ -32,6 +38,18 @@ impl OracleId {
 	}
 }
 
+impl From<usize> for OracleId {
+	fn from(index: usize) -> Self {
+		Self::from_index(index)
+	}
+}
+
+impl From<OracleId> for usize {
+	fn from(id: OracleId) -> Self {
+		id.index()
+	}
+}
+
 impl SerializeBytes for OracleId {
 	fn serialize(
 		&self,
@@ -62,6 +80,24 @@ impl fmt::Display for OracleId {
 	}
 }
 
+/// Serializes as the `u32` index, matching the [`SerializeBytes`] encoding, so that the
+/// serialized form does not depend on the platform.
+#[cfg(feature = "serde")]
+impl serde::Serialize for OracleId {
+	fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+		let index = u32::try_from(self.0).map_err(serde::ser::Error::custom)?;
+		serializer.serialize_u32(index)
+	}
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for OracleId {
+	fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+		let index = <u32 as serde::Deserialize>::deserialize(deserializer)?;
+		Ok(Self(index as usize))
+	}
+}
+
 // Technically, there must be no notion of a "default" oracle ID. However, there is some code that
 // requires that, so until it's fixed this is going to mean INVALID.
 impl Default for OracleId {
@@ -69,3 +105,18 @@ impl Default for OracleId {
 		Self::invalid()
 	}
 }
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn test_usize_conversions() {
+		let id = OracleId::from(7);
+		assert_eq!(id, OracleId::from_index(7));
+		assert_eq!(usize::from(id), 7);
+
+		let index: usize = OracleId::from_index(3).into();
+		assert_eq!(index, 3);
+	}
+}
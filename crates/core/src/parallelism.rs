@@ -0,0 +1,65 @@
+// Copyright 2025 Irreducible Inc.
+
+//! Runtime control over the parallelism of the prover.
+//!
+//! `binius_maybe_rayon` selects between parallel and serial execution at compile time. The switch
+//! in this module instead forces serial execution at runtime, so that a profiler or a debugging
+//! session can compare both modes without recompiling. The proofs are identical in both modes.
+
+use std::sync::{
+	OnceLock,
+	atomic::{AtomicBool, Ordering},
+};
+
+use binius_maybe_rayon::{ThreadPool, ThreadPoolBuilder};
+use tracing::Span;
+
+static SINGLE_THREADED: AtomicBool = AtomicBool::new(false);
+
+/// Forces the prover entry points to run on a single thread when `single_threaded` is true.
+///
+/// The flag is checked when entering [`constraint_system::prove`](crate::constraint_system::prove),
+/// which then runs on a dedicated pool with one thread. Changing the flag does not affect entry
+/// points that are already running. Lower-level provers that take a transcript, such as
+/// [`gkr_gpa::batch_prove`](crate::protocols::gkr_gpa::batch_prove), do not check the flag, as
+/// moving them to the pool would require the challenger to be [`Send`]; callers can run them with
+/// [`install_single_threaded`] instead.
+///
+/// The check is a single relaxed atomic load per entry point call, which is negligible for the
+/// entry points above. It is not performed in the inner parallel loops, so calling lower-level
+/// prover functions directly still uses the global thread pool.
+pub fn set_single_threaded(single_threaded: bool) {
+	SINGLE_THREADED.store(single_threaded, Ordering::Relaxed);
+}
+
+/// Returns whether the prover entry points are forced to run on a single thread.
+pub fn is_single_threaded() -> bool {
+	SINGLE_THREADED.load(Ordering::Relaxed)
+}
+
+/// Runs `op` on a single-threaded pool if [`is_single_threaded`], and in place otherwise.
+pub(crate) fn install<R: Send>(op: impl FnOnce() -> R + Send) -> R {
+	install_single_threaded(is_single_threaded(), op)
+}
+
+/// Runs `op` on a single-threaded pool if `single_threaded` is true, and in place otherwise.
+///
+/// Unlike [`set_single_threaded`], this only affects `op`, so concurrent callers can choose
+/// different modes. The current tracing span is entered on the pool thread, so that the spans of
+/// `op` keep their parent.
+pub fn install_single_threaded<R: Send>(single_threaded: bool, op: impl FnOnce() -> R + Send) -> R {
+	static SINGLE_THREADED_POOL: OnceLock<ThreadPool> = OnceLock::new();
+
+	if !single_threaded {
+		return op();
+	}
+	let span = Span::current();
+	SINGLE_THREADED_POOL
+		.get_or_init(|| {
+			ThreadPoolBuilder::new()
+				.num_threads(1)
+				.build()
+				.expect("building a single-threaded pool does not fail")
+		})
+		.install(|| span.in_scope(op))
+}
@@ -27,6 +27,10 @@ pub enum Error {
 	HalError(#[from] binius_hal::Error),
 	#[error("Math error: {0}")]
 	MathError(#[from] binius_math::Error),
+	#[error("cannot combine an empty batch of claims")]
+	EmptyClaimBatch,
+	#[error("claim {index} is at a different evaluation point than the first claim of the batch")]
+	ClaimEvalPointMismatch { index: usize },
 	#[error("Evalcheck serialization error")]
 	EvalcheckSerializationError,
 	#[error("transcript error: {0}")]
@@ -47,6 +51,8 @@ pub enum VerificationError {
 	MissingLinearCombinationEval,
 	#[error("The referenced duplicate claim is different from expected")]
 	DuplicateClaimMismatch,
+	#[error("the evaluation of the combined multilinear differs from the aggregated claim")]
+	IncorrectAggregatedEvaluation,
 }
 
 impl VerificationError {
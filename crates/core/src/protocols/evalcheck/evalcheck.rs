@@ -6,10 +6,14 @@ use std::{
 	sync::Arc,
 };
 
-use binius_field::Field;
+use binius_field::{Field, util::powers};
+use binius_utils::bail;
+#[cfg(feature = "serde")]
+use binius_utils::{SerializationError, SerializationMode};
 use bytes::{Buf, BufMut};
+use itertools::izip;
 
-use super::error::Error;
+use super::error::{Error, VerificationError};
 use crate::{
 	oracle::OracleId,
 	transcript::{TranscriptReader, TranscriptWriter},
@@ -26,6 +30,145 @@ pub struct EvalcheckMultilinearClaim<F: Field> {
 	pub eval: F,
 }
 
+/// The serde representation of an [`EvalcheckMultilinearClaim`], with the field elements encoded as
+/// their canonical tower bytes.
+#[cfg(feature = "serde")]
+#[derive(serde::Serialize, serde::Deserialize)]
+#[serde(rename = "EvalcheckMultilinearClaim")]
+struct EvalcheckMultilinearClaimRepr {
+	id: OracleId,
+	eval_point: Vec<Vec<u8>>,
+	eval: Vec<u8>,
+}
+
+#[cfg(feature = "serde")]
+fn field_to_canonical_bytes<F: Field>(value: F) -> Vec<u8> {
+	let mut buf = Vec::new();
+	value
+		.serialize(&mut buf, SerializationMode::CanonicalTower)
+		.expect("serialization into a Vec cannot run out of space");
+	buf
+}
+
+#[cfg(feature = "serde")]
+fn field_from_canonical_bytes<F: Field>(bytes: &[u8]) -> Result<F, SerializationError> {
+	let mut buf = bytes;
+	let value = F::deserialize(&mut buf, SerializationMode::CanonicalTower)?;
+	if !buf.is_empty() {
+		return Err(SerializationError::InvalidConstruction {
+			name: "EvalcheckMultilinearClaim",
+		});
+	}
+	Ok(value)
+}
+
+#[cfg(feature = "serde")]
+impl<F: Field> serde::Serialize for EvalcheckMultilinearClaim<F> {
+	fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+		let repr = EvalcheckMultilinearClaimRepr {
+			id: self.id,
+			eval_point: self
+				.eval_point
+				.iter()
+				.copied()
+				.map(field_to_canonical_bytes)
+				.collect(),
+			eval: field_to_canonical_bytes(self.eval),
+		};
+		serde::Serialize::serialize(&repr, serializer)
+	}
+}
+
+#[cfg(feature = "serde")]
+impl<'de, F: Field> serde::Deserialize<'de> for EvalcheckMultilinearClaim<F> {
+	fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+		use serde::de::Error as _;
+
+		let repr: EvalcheckMultilinearClaimRepr = serde::Deserialize::deserialize(deserializer)?;
+		let eval_point = repr
+			.eval_point
+			.iter()
+			.map(|bytes| field_from_canonical_bytes(bytes))
+			.collect::<Result<Vec<F>, _>>()
+			.map_err(D::Error::custom)?;
+		let eval = field_from_canonical_bytes(&repr.eval).map_err(D::Error::custom)?;
+		Ok(Self {
+			id: repr.id,
+			eval_point: eval_point.into(),
+			eval,
+		})
+	}
+}
+
+impl<F: Field> EvalcheckMultilinearClaim<F> {
+	/// Combines claims at a common evaluation point into a single claim on the random linear
+	/// combination of their multilinears.
+	///
+	/// The `i`-th claim is weighted by the `i`-th power of `challenge`, so that a commitment
+	/// scheme needs to open only the combined multilinear.
+	///
+	/// ## Throws
+	///
+	/// * `Error::EmptyClaimBatch` if `claims` is empty
+	/// * `Error::ClaimEvalPointMismatch` for the first claim at a point other than that of the
+	///   first claim
+	pub fn batch_into_single(claims: &[Self], challenge: F) -> Result<AggregatedClaim<F>, Error> {
+		let Some(first) = claims.first() else {
+			bail!(Error::EmptyClaimBatch);
+		};
+		if let Some(index) = claims
+			.iter()
+			.position(|claim| claim.eval_point != first.eval_point)
+		{
+			bail!(Error::ClaimEvalPointMismatch { index });
+		}
+
+		let eval = izip!(claims, powers(challenge))
+			.map(|(claim, coeff)| claim.eval * coeff)
+			.sum();
+		Ok(AggregatedClaim {
+			ids: claims.iter().map(|claim| claim.id).collect(),
+			eval_point: first.eval_point.clone(),
+			batching_challenge: challenge,
+			eval,
+		})
+	}
+}
+
+/// A claim on the random linear combination of several multilinears at a common point, as
+/// produced by [`EvalcheckMultilinearClaim::batch_into_single`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AggregatedClaim<F: Field> {
+	/// The combined multilinears, in the order of their coefficients.
+	pub ids: Vec<OracleId>,
+	/// The common evaluation point.
+	pub eval_point: EvalPoint<F>,
+	/// The challenge whose powers are the coefficients of the linear combination.
+	pub batching_challenge: F,
+	/// The claimed evaluation of the linear combination.
+	pub eval: F,
+}
+
+impl<F: Field> AggregatedClaim<F> {
+	/// The coefficient of every combined multilinear.
+	pub fn coefficients(&self) -> impl Iterator<Item = F> {
+		powers(self.batching_challenge).take(self.ids.len())
+	}
+
+	/// Checks the evaluation of the combined multilinear obtained from a commitment opening.
+	///
+	/// ## Throws
+	///
+	/// * `VerificationError::IncorrectAggregatedEvaluation` if `combined_eval` differs from the
+	///   claimed evaluation
+	pub fn verify(&self, combined_eval: F) -> Result<(), Error> {
+		if combined_eval != self.eval {
+			bail!(VerificationError::IncorrectAggregatedEvaluation);
+		}
+		Ok(())
+	}
+}
+
 #[repr(u32)]
 #[derive(Debug)]
 enum EvalcheckNumerics {
@@ -20,9 +20,11 @@ mod prove;
 pub mod subclaims;
 #[cfg(test)]
 mod tests;
+mod traversal;
 mod verify;
 
 pub use error::*;
 pub use evalcheck::*;
 pub use prove::*;
+pub(crate) use traversal::{ClaimVisitor, add_composite_claim, visit_claim};
 pub use verify::*;
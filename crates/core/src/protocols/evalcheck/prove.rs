@@ -1,6 +1,6 @@
 // Copyright 2024-2025 Irreducible Inc.
 
-use std::collections::HashSet;
+use std::{collections::HashSet, num::NonZeroUsize};
 
 use binius_field::{Field, PackedField, TowerField};
 use binius_math::MultilinearExtension;
@@ -15,9 +15,9 @@ use super::{
 	evalcheck::{EvalcheckHint, EvalcheckMultilinearClaim},
 	serialize_evalcheck_proof,
 	subclaims::{
-		MemoizedData, OracleIdPartialEval, ProjectedBivariateMeta,
-		add_composite_sumcheck_to_constraints, collect_projected_mles,
+		MemoizedData, OracleIdPartialEval, ProjectedBivariateMeta, collect_projected_mles,
 	},
+	traversal::{ClaimVisitor, add_composite_claim, visit_claim},
 };
 use crate::{
 	fiat_shamir::Challenger,
@@ -91,6 +91,9 @@ where
 
 	// Common suffixes
 	suffixes: HashSet<EvalPoint<F>>,
+
+	// Claims added with `add_claim` that have not been proven yet
+	pending_claims: Vec<EvalcheckMultilinearClaim<F>>,
 }
 
 impl<'a, 'b, F, P> EvalcheckProver<'a, 'b, F, P>
@@ -123,6 +126,8 @@ where
 
 			partial_evals: EvalPointOracleIdMap::new(),
 			suffixes: HashSet::new(),
+
+			pending_claims: Vec::new(),
 		}
 	}
 
@@ -175,12 +180,72 @@ where
 		evalcheck_claims: Vec<EvalcheckMultilinearClaim<F>>,
 		transcript: &mut ProverTranscript<Challenger_>,
 	) -> Result<(), Error> {
-		// Reset the prover state for a new round.
+		self.begin_round();
+		self.prove_batch(evalcheck_claims, transcript)?;
+		self.finish_round()
+	}
+
+	/// Prove evalcheck claims supplied by an iterator, processing them in batches of at most
+	/// `batch_size` claims.
+	///
+	/// Only a single batch of claims is held in memory at a time. Claims are deduplicated across
+	/// batches, so the proof is identical to the one produced by [`Self::prove`] on the collected
+	/// claims.
+	pub fn prove_streaming<Challenger_: Challenger>(
+		&mut self,
+		evalcheck_claims: impl IntoIterator<Item = EvalcheckMultilinearClaim<F>>,
+		batch_size: NonZeroUsize,
+		transcript: &mut ProverTranscript<Challenger_>,
+	) -> Result<(), Error> {
+		self.begin_round();
+		let mut evalcheck_claims = evalcheck_claims.into_iter();
+		loop {
+			let batch = evalcheck_claims
+				.by_ref()
+				.take(batch_size.get())
+				.collect::<Vec<_>>();
+			if batch.is_empty() {
+				break;
+			}
+			self.prove_batch(batch, transcript)?;
+		}
+		self.finish_round()
+	}
+
+	/// Add a claim to be proven by the next call to [`Self::flush`].
+	///
+	/// This lets callers hand over claims as they are produced instead of collecting them first.
+	pub fn add_claim(&mut self, evalcheck_claim: EvalcheckMultilinearClaim<F>) {
+		self.pending_claims.push(evalcheck_claim);
+	}
+
+	/// Prove the claims added with [`Self::add_claim`] since the last flush, in the order they were
+	/// added.
+	///
+	/// The proof is identical to the one produced by [`Self::prove`] on the same claims.
+	pub fn flush<Challenger_: Challenger>(
+		&mut self,
+		transcript: &mut ProverTranscript<Challenger_>,
+	) -> Result<(), Error> {
+		let evalcheck_claims = std::mem::take(&mut self.pending_claims);
+		self.prove(evalcheck_claims, transcript)
+	}
+
+	/// Reset the prover state for a new round.
+	fn begin_round(&mut self) {
 		self.round_claim_index = 0;
 		self.visited_claims.clear();
 		self.claim_to_index.clear();
 		self.evals_memoization.clear();
+	}
 
+	/// Evaluate the subclaims of a batch of claims and write the evalcheck proofs of the claims to
+	/// the transcript.
+	fn prove_batch<Challenger_: Challenger>(
+		&mut self,
+		evalcheck_claims: Vec<EvalcheckMultilinearClaim<F>>,
+		transcript: &mut ProverTranscript<Challenger_>,
+	) -> Result<(), Error> {
 		let mle_fold_full_span = tracing::debug_span!(
 			"[task] MLE Fold Full",
 			phase = "evalcheck",
@@ -272,6 +337,8 @@ where
 		for subclaim in &subclaims {
 			self.evals_memoization
 				.insert(subclaim.id, subclaim.eval_point.clone(), subclaim.eval);
+			self.memoized_data
+				.insert(subclaim.id, subclaim.eval_point.clone(), subclaim.eval);
 		}
 
 		let mut claims_without_evals = std::mem::take(&mut self.claims_without_evals)
@@ -292,6 +359,11 @@ where
 			self.prove_multilinear(claim, transcript)?;
 		}
 
+		Ok(())
+	}
+
+	/// Process the projected bivariate claims accumulated over all batches of the round.
+	fn finish_round(&mut self) -> Result<(), Error> {
 		// Step 3: Process projected_bivariate_claims
 		let dimensions_data = MLEFoldHighDimensionsData::new(self.projected_bivariate_claims.len());
 		let evalcheck_mle_fold_high_span = tracing::debug_span!(
@@ -368,7 +440,9 @@ where
 
 		match multilinear.variant {
 			MultilinearPolyVariant::Shifted(_) => {
-				if !self.evals_memoization.contains(multilinear_id, &eval_point) {
+				if !self.evals_memoization.contains(multilinear_id, &eval_point)
+					&& !self.recall_memoized_eval(multilinear_id, &eval_point)
+				{
 					self.collect_suffixes(multilinear_id, eval_point.clone());
 
 					self.claims_to_be_evaluated
@@ -457,7 +531,9 @@ where
 				self.collect_subclaims_for_memoization(id, inner_eval_point, None);
 			}
 			_ => {
-				if !self.evals_memoization.contains(multilinear_id, &eval_point) {
+				if !self.evals_memoization.contains(multilinear_id, &eval_point)
+					&& !self.recall_memoized_eval(multilinear_id, &eval_point)
+				{
 					self.claims_to_be_evaluated
 						.insert((multilinear_id, eval_point));
 				}
@@ -465,6 +541,19 @@ where
 		};
 	}
 
+	/// Copies an evaluation from [`MemoizedData`] into this round's evaluations, returning whether
+	/// it was found.
+	fn recall_memoized_eval(&mut self, oracle_id: OracleId, eval_point: &EvalPoint<F>) -> bool {
+		match self.memoized_data.get(oracle_id, eval_point) {
+			Some(eval) => {
+				self.evals_memoization
+					.insert(oracle_id, eval_point.clone(), eval);
+				true
+			}
+			None => false,
+		}
+	}
+
 	#[instrument(
 		skip_all,
 		name = "EvalcheckProverState::prove_multilinear",
@@ -475,172 +564,13 @@ where
 		evalcheck_claim: EvalcheckMultilinearClaim<F>,
 		transcript: &mut ProverTranscript<Challenger_>,
 	) -> Result<(), Error> {
-		let EvalcheckMultilinearClaim { id, eval_point, .. } = &evalcheck_claim;
-
-		let claim_id = self.claim_to_index.get(*id, eval_point);
-
-		if let Some(claim_id) = claim_id {
-			serialize_evalcheck_proof(
-				&mut transcript.message(),
-				&EvalcheckHint::DuplicateClaim(*claim_id as u32),
-			);
-			return Ok(());
-		}
-		serialize_evalcheck_proof(&mut transcript.message(), &EvalcheckHint::NewClaim);
-
-		self.prove_multilinear_skip_duplicate_check(evalcheck_claim, transcript)
-	}
-
-	fn prove_multilinear_skip_duplicate_check<Challenger_: Challenger>(
-		&mut self,
-		evalcheck_claim: EvalcheckMultilinearClaim<F>,
-		transcript: &mut ProverTranscript<Challenger_>,
-	) -> Result<(), Error> {
-		let EvalcheckMultilinearClaim {
-			id,
-			eval_point,
-			eval,
-		} = evalcheck_claim;
-
-		self.claim_to_index
-			.insert(id, eval_point.clone(), self.round_claim_index);
-
-		self.round_claim_index += 1;
-
-		let multilinear = &self.oracles[id];
-
-		match multilinear.variant {
-			MultilinearPolyVariant::Transparent { .. } | MultilinearPolyVariant::Structured(_) => {}
-			MultilinearPolyVariant::Committed => {
-				self.committed_eval_claims.push(EvalcheckMultilinearClaim {
-					id: multilinear.id,
-					eval_point,
-					eval,
-				});
-			}
-			MultilinearPolyVariant::Repeating {
-				id: inner_id,
-				log_count,
-			} => {
-				let n_vars = eval_point.len() - log_count;
-				self.prove_multilinear(
-					EvalcheckMultilinearClaim {
-						id: inner_id,
-						eval_point: eval_point.slice(0..n_vars),
-						eval,
-					},
-					transcript,
-				)?;
-			}
-			MultilinearPolyVariant::Projected(ref projected) => {
-				let new_eval_point = {
-					let (lo, hi) = eval_point.split_at(projected.start_index());
-					chain!(lo, projected.values(), hi)
-						.copied()
-						.collect::<Vec<_>>()
-				};
-
-				self.prove_multilinear(
-					EvalcheckMultilinearClaim {
-						id: projected.id(),
-						eval_point: new_eval_point.into(),
-						eval,
-					},
-					transcript,
-				)?;
-			}
-			MultilinearPolyVariant::Shifted { .. } | MultilinearPolyVariant::Packed { .. } => {
-				let claim = EvalcheckMultilinearClaim {
-					id,
-					eval_point,
-					eval,
-				};
-				self.projected_bivariate_claims.push(claim);
-			}
-			MultilinearPolyVariant::Composite(ref composite) => {
-				let position = self
-					.new_mlechecks_constraints
-					.iter()
-					.position(|(ep, _)| *ep == eval_point)
-					.unwrap_or(self.new_mlechecks_constraints.len());
-
-				transcript.message().write(&(position as u32));
-
-				add_composite_sumcheck_to_constraints(
-					position,
-					&eval_point,
-					&mut self.new_mlechecks_constraints,
-					composite,
-					eval,
-				);
-			}
-			MultilinearPolyVariant::LinearCombination(ref linear_combination) => {
-				let lincom_suboracles = linear_combination.polys().collect::<Vec<_>>();
-				for suboracle_id in lincom_suboracles {
-					if let Some(claim_index) = self.claim_to_index.get(suboracle_id, &eval_point) {
-						serialize_evalcheck_proof(
-							&mut transcript.message(),
-							&EvalcheckHint::DuplicateClaim(*claim_index as u32),
-						);
-					} else {
-						serialize_evalcheck_proof(
-							&mut transcript.message(),
-							&EvalcheckHint::NewClaim,
-						);
-
-						let eval = *self
-							.evals_memoization
-							.get(suboracle_id, &eval_point)
-							.expect("precomputed above");
-
-						transcript.message().write_scalar(eval);
-
-						self.prove_multilinear_skip_duplicate_check(
-							EvalcheckMultilinearClaim {
-								id: suboracle_id,
-								eval_point: eval_point.clone(),
-								eval,
-							},
-							transcript,
-						)?;
-					}
-				}
-			}
-			MultilinearPolyVariant::ZeroPadded(ref padded) => {
-				let inner_eval_point = chain!(
-					&eval_point[..padded.start_index()],
-					&eval_point[padded.start_index() + padded.n_pad_vars()..],
-				)
-				.copied()
-				.collect::<Vec<_>>();
-
-				let zs =
-					&eval_point[padded.start_index()..padded.start_index() + padded.n_pad_vars()];
-				let select_row = SelectRow::new(zs.len(), padded.nonzero_index())?;
-				let select_row_term = select_row
-					.evaluate(zs)
-					.expect("select_row is constructor with zs.len() variables");
-
-				if eval.is_zero() && select_row_term.is_zero() {
-					return Ok(());
-				}
-
-				let inner_eval = *self
-					.evals_memoization
-					.get(padded.id(), &inner_eval_point)
-					.expect("precomputed above");
-
-				self.prove_multilinear(
-					EvalcheckMultilinearClaim {
-						id: padded.id(),
-						eval_point: inner_eval_point.into(),
-						eval: inner_eval,
-					},
-					transcript,
-				)?;
-			}
-		}
-		Ok(())
+		visit_claim(
+			&mut ProveVisitor {
+				prover: self,
+				transcript,
+			},
+			evalcheck_claim,
+		)
 	}
 
 	pub fn collect_evals(&mut self, oracle_id: OracleId, eval_point: &EvalPoint<F>) -> F {
@@ -878,6 +808,78 @@ where
 	}
 }
 
+/// Proves claims visited by [`visit_claim`], writing hints, subclaim evaluations and the positions
+/// of mlecheck constraint sets to the transcript.
+struct ProveVisitor<'p, 'a, 'b, F, P, Challenger_>
+where
+	P: PackedField<Scalar = F>,
+	F: TowerField,
+{
+	prover: &'p mut EvalcheckProver<'a, 'b, F, P>,
+	transcript: &'p mut ProverTranscript<Challenger_>,
+}
+
+impl<F, P, Challenger_> ClaimVisitor<F> for ProveVisitor<'_, '_, '_, F, P, Challenger_>
+where
+	P: PackedField<Scalar = F>,
+	F: TowerField,
+	Challenger_: Challenger,
+{
+	fn oracles(&self) -> &MultilinearOracleSet<F> {
+		self.prover.oracles
+	}
+
+	fn claim_index(&self, id: OracleId, eval_point: &EvalPoint<F>) -> Option<usize> {
+		self.prover.claim_to_index.get(id, eval_point).copied()
+	}
+
+	fn insert_claim(&mut self, id: OracleId, eval_point: EvalPoint<F>) {
+		self.prover
+			.claim_to_index
+			.insert(id, eval_point, self.prover.round_claim_index);
+		self.prover.round_claim_index += 1;
+	}
+
+	fn write_hint(&mut self, hint: EvalcheckHint) {
+		serialize_evalcheck_proof(&mut self.transcript.message(), &hint);
+	}
+
+	fn visit_committed(&mut self, claim: EvalcheckMultilinearClaim<F>) {
+		self.prover.committed_eval_claims.push(claim);
+	}
+
+	fn visit_projected_bivariate(&mut self, claim: EvalcheckMultilinearClaim<F>) {
+		self.prover.projected_bivariate_claims.push(claim);
+	}
+
+	fn visit_composite(&mut self, claim: EvalcheckMultilinearClaim<F>) {
+		let position = add_composite_claim(
+			self.prover.oracles,
+			&mut self.prover.new_mlechecks_constraints,
+			&claim,
+		);
+		self.transcript.message().write(&(position as u32));
+	}
+
+	fn linear_combination_subclaim_eval(&mut self, id: OracleId, eval_point: &EvalPoint<F>) -> F {
+		let eval = *self
+			.prover
+			.evals_memoization
+			.get(id, eval_point)
+			.expect("precomputed above");
+		self.transcript.message().write_scalar(eval);
+		eval
+	}
+
+	fn zero_padded_subclaim_eval(&mut self, id: OracleId, eval_point: &EvalPoint<F>) -> F {
+		*self
+			.prover
+			.evals_memoization
+			.get(id, eval_point)
+			.expect("precomputed above")
+	}
+}
+
 pub struct ConstraintSetEqIndPoint<F: Field> {
 	pub eq_ind_challenges: EvalPoint<F>,
 	pub constraint_set: ConstraintSet<F>,
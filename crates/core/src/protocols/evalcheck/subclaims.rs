@@ -366,12 +366,13 @@ where
 	Ok(())
 }
 
-/// Struct for memoizing tensor expansions of evaluation points and partial evaluations of
-/// multilinears
+/// Struct for memoizing tensor expansions of evaluation points, partial evaluations of
+/// multilinears and evaluations of oracles
 #[allow(clippy::type_complexity)]
 pub struct MemoizedData<'a, P: PackedField> {
 	query: Vec<(Vec<P::Scalar>, MultilinearQuery<P>)>,
 	partial_evals: EvalPointOracleIdMap<MultilinearWitness<'a, P>, P::Scalar>,
+	evals: EvalPointOracleIdMap<P::Scalar, P::Scalar>,
 }
 
 impl<'a, P: PackedField> MemoizedData<'a, P> {
@@ -380,9 +381,24 @@ impl<'a, P: PackedField> MemoizedData<'a, P> {
 		Self {
 			query: Vec::new(),
 			partial_evals: EvalPointOracleIdMap::new(),
+			evals: EvalPointOracleIdMap::new(),
 		}
 	}
 
+	/// Returns the memoized evaluation of the oracle `id` at `eval_point`, if any.
+	pub fn get(&self, id: OracleId, eval_point: &[P::Scalar]) -> Option<P::Scalar> {
+		self.evals.get(id, eval_point).copied()
+	}
+
+	/// Memoizes the evaluation of the oracle `id` at `eval_point`.
+	///
+	/// The evalcheck prover uses a memoized evaluation instead of evaluating the witness, so the
+	/// witness of the oracle need not be present. An evaluation that is already memoized is not
+	/// replaced.
+	pub fn insert(&mut self, id: OracleId, eval_point: EvalPoint<P::Scalar>, eval: P::Scalar) {
+		self.evals.insert(id, eval_point, eval);
+	}
+
 	pub fn full_query(
 		&mut self,
 		eval_point: &[P::Scalar],
@@ -2,6 +2,7 @@
 
 use std::{array, iter::repeat_with};
 
+use assert_matches::assert_matches;
 use binius_fast_compute::arith_circuit::ArithCircuitPoly;
 use binius_field::{
 	AESTowerField128b, BinaryField1b, BinaryField128b, ByteSliced16x128x1b, ByteSlicedAES16x16x8b,
@@ -17,18 +18,18 @@ use binius_math::{
 	extrapolate_line,
 };
 use bytemuck::{Pod, cast_slice_mut};
-use itertools::Either;
+use itertools::{Either, izip};
 use rand::{SeedableRng, rngs::StdRng};
 
 use crate::{
 	fiat_shamir::HasherChallenger,
-	oracle::{MultilinearOracleSet, ShiftVariant},
+	oracle::{MultilinearOracleSet, OracleId, ShiftVariant},
 	polynomial::MultivariatePoly,
 	protocols::evalcheck::{
-		EvalcheckHint, EvalcheckMultilinearClaim, EvalcheckProver, EvalcheckVerifier,
-		deserialize_evalcheck_proof, serialize_evalcheck_proof,
+		Error, EvalcheckHint, EvalcheckMultilinearClaim, EvalcheckProver, EvalcheckVerifier,
+		VerificationError, deserialize_evalcheck_proof, serialize_evalcheck_proof,
 	},
-	transcript::ProverTranscript,
+	transcript::{ProverTranscript, VerifierTranscript},
 	transparent::select_row::SelectRow,
 	witness::MultilinearExtensionIndex,
 };
@@ -662,6 +663,165 @@ fn test_evalcheck_serialization() {
 	transcript.finalize().unwrap()
 }
 
+#[test]
+fn test_batch_into_single() {
+	let mut rng = StdRng::seed_from_u64(0);
+	let n_vars = 4;
+	let eval_point = repeat_with(|| <FExtension as Field>::random(&mut rng))
+		.take(n_vars)
+		.collect::<Vec<_>>();
+	let query = MultilinearQuery::<FExtension>::expand(&eval_point);
+
+	let multilins = repeat_with(|| {
+		let evals = repeat_with(|| <FExtension as Field>::random(&mut rng))
+			.take(1 << n_vars)
+			.collect::<Vec<_>>();
+		MultilinearExtension::from_values(evals).unwrap()
+	})
+	.take(3)
+	.collect::<Vec<_>>();
+	let claims = multilins
+		.iter()
+		.enumerate()
+		.map(|(i, multilin)| EvalcheckMultilinearClaim {
+			id: OracleId::from_index(i),
+			eval_point: eval_point.as_slice().into(),
+			eval: multilin.evaluate(&query).unwrap(),
+		})
+		.collect::<Vec<_>>();
+
+	let challenge = <FExtension as Field>::random(&mut rng);
+	let aggregated = EvalcheckMultilinearClaim::batch_into_single(&claims, challenge).unwrap();
+	assert_eq!(
+		aggregated.eval,
+		claims[0].eval + challenge * claims[1].eval + challenge.square() * claims[2].eval
+	);
+
+	// The verifier checks the aggregate against an opening of the combined multilinear.
+	let combined_evals = (0..1 << n_vars)
+		.map(|i| {
+			izip!(&multilins, aggregated.coefficients())
+				.map(|(multilin, coeff)| multilin.evals()[i] * coeff)
+				.sum::<FExtension>()
+		})
+		.collect::<Vec<_>>();
+	let combined_eval = MultilinearExtension::from_values(combined_evals)
+		.unwrap()
+		.evaluate(&query)
+		.unwrap();
+	aggregated.verify(combined_eval).unwrap();
+	assert_matches!(
+		aggregated.verify(combined_eval + FExtension::ONE),
+		Err(Error::Verification(VerificationError::IncorrectAggregatedEvaluation))
+	);
+
+	let mut mismatched = claims;
+	mismatched[2].eval_point = eval_point[1..].into();
+	assert_matches!(
+		EvalcheckMultilinearClaim::batch_into_single(&mismatched, challenge),
+		Err(Error::ClaimEvalPointMismatch { index: 2 })
+	);
+	assert_matches!(
+		EvalcheckMultilinearClaim::<FExtension>::batch_into_single(&[], challenge),
+		Err(Error::EmptyClaimBatch)
+	);
+}
+
+#[test]
+fn test_add_claim_flush_matches_prove() {
+	let n_vars = 6;
+
+	let prove = |streaming: bool| {
+		let mut rng = StdRng::seed_from_u64(0);
+		let mut oracles = MultilinearOracleSet::<FExtension>::new();
+		let [a_id, b_id] = oracles.add_committed_multiple(n_vars, FExtension::TOWER_LEVEL);
+		let lin_com_id = oracles
+			.add_linear_combination(n_vars, [(a_id, FExtension::ONE), (b_id, FExtension::new(2))])
+			.unwrap();
+
+		let mut witness_index = MultilinearExtensionIndex::<PExtension>::new();
+		let [a_witness, b_witness] = array::from_fn(|_| {
+			let evals = repeat_with(|| PExtension::random(&mut rng))
+				.take(1 << n_vars)
+				.collect::<Vec<_>>();
+			MultilinearExtension::from_values(evals).unwrap()
+		});
+		witness_index
+			.update_multilin_poly([
+				(a_id, a_witness.specialize_arc_dyn()),
+				(b_id, b_witness.specialize_arc_dyn()),
+			])
+			.unwrap();
+		let lin_com_witness = witness_index.materialize(&oracles[lin_com_id]).unwrap();
+		witness_index
+			.update_multilin_poly([(lin_com_id, lin_com_witness.specialize_arc_dyn())])
+			.unwrap();
+
+		let [point_1, point_2] = array::from_fn(|_| {
+			repeat_with(|| <FExtension as Field>::random(&mut rng))
+				.take(n_vars)
+				.collect::<Vec<_>>()
+		});
+		let claims = [(lin_com_id, &point_1), (a_id, &point_2), (b_id, &point_1)]
+			.into_iter()
+			.map(|(id, eval_point)| {
+				let query = MultilinearQuery::<PExtension>::expand(eval_point);
+				let eval = witness_index
+					.get_multilin_poly(id)
+					.unwrap()
+					.evaluate(query.to_ref())
+					.unwrap();
+				EvalcheckMultilinearClaim {
+					id,
+					eval_point: eval_point.as_slice().into(),
+					eval,
+				}
+			})
+			.collect::<Vec<_>>();
+
+		let mut transcript = ProverTranscript::<HasherChallenger<Groestl256>>::new();
+		let mut prover_state = EvalcheckProver::new(&mut oracles, &mut witness_index);
+		if streaming {
+			for claim in claims.clone() {
+				prover_state.add_claim(claim);
+			}
+			prover_state.flush(&mut transcript).unwrap();
+		} else {
+			prover_state.prove(claims.clone(), &mut transcript).unwrap();
+		}
+		let committed_eval_claims = prover_state.committed_eval_claims().clone();
+
+		let proof = transcript.finalize();
+		let mut transcript = VerifierTranscript::<HasherChallenger<Groestl256>>::new(proof.clone());
+		let mut verifier_state = EvalcheckVerifier::<FExtension>::new(&mut oracles);
+		verifier_state.verify(claims, &mut transcript).unwrap();
+		(proof, committed_eval_claims)
+	};
+
+	assert_eq!(prove(true), prove(false));
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn test_evalcheck_claim_serde_roundtrip() {
+	let mut rng = StdRng::seed_from_u64(0);
+	let claim = EvalcheckMultilinearClaim {
+		id: OracleId::from_index(7),
+		eval_point: repeat_with(|| <FExtension as Field>::random(&mut rng))
+			.take(10)
+			.collect::<Vec<_>>()
+			.into(),
+		eval: <FExtension as Field>::random(&mut rng),
+	};
+
+	let json = serde_json::to_value(&claim).unwrap();
+	assert_eq!(json["id"], 7);
+	assert_eq!(json, serde_json::to_value(claim.clone()).unwrap());
+
+	let roundtrip: EvalcheckMultilinearClaim<FExtension> = serde_json::from_value(json).unwrap();
+	assert_eq!(roundtrip, claim);
+}
+
 #[test]
 pub fn test_zero_padded_zero_vars() {
 	let mut oracles = MultilinearOracleSet::<BinaryField128b>::new();
@@ -730,3 +890,99 @@ pub fn test_zero_padded_zero_vars() {
 		.verify(vec![zero_eval_claim, non_zero_eval_claim], &mut transcript)
 		.unwrap();
 }
+
+#[test]
+fn test_memoized_evals() {
+	let n_vars = 4;
+	let mut rng = StdRng::seed_from_u64(0);
+	let backend = make_portable_backend();
+
+	let mut oracles = MultilinearOracleSet::<FExtension>::new();
+	let a_id = oracles.add_committed(n_vars, FExtension::TOWER_LEVEL);
+	let b_id = oracles.add_committed(n_vars, FExtension::TOWER_LEVEL);
+	let coeff = <FExtension as Field>::random(&mut rng);
+	let lin_com_id = oracles
+		.add_linear_combination(n_vars, [(a_id, FExtension::ONE), (b_id, coeff)])
+		.unwrap();
+
+	let [a_witness, b_witness] = array::from_fn(|_| {
+		let values = repeat_with(|| PExtension::random(&mut rng))
+			.take(1 << n_vars)
+			.collect::<Vec<_>>();
+		MultilinearExtension::from_values(values).unwrap()
+	});
+
+	let eval_point = repeat_with(|| <FExtension as Field>::random(&mut rng))
+		.take(n_vars)
+		.collect::<Vec<_>>();
+	let query: MultilinearQuery<FExtension, _> = backend.multilinear_query(&eval_point).unwrap();
+	let a_eval = a_witness.evaluate(&query).unwrap();
+	let b_eval = b_witness.evaluate(&query).unwrap();
+
+	let claim = EvalcheckMultilinearClaim {
+		id: lin_com_id,
+		eval_point: eval_point.clone().into(),
+		eval: a_eval + coeff * b_eval,
+	};
+
+	// The evaluations of the suboracles are memoized across rounds.
+	let mut witness_index = MultilinearExtensionIndex::<PExtension>::new();
+	witness_index
+		.update_multilin_poly([
+			(a_id, a_witness.specialize_arc_dyn()),
+			(b_id, b_witness.clone().specialize_arc_dyn()),
+		])
+		.unwrap();
+
+	let mut transcript = ProverTranscript::<HasherChallenger<Groestl256>>::new();
+	let mut prover_state = EvalcheckProver::new(&mut oracles, &mut witness_index);
+	prover_state
+		.prove(vec![claim.clone()], &mut transcript)
+		.unwrap();
+	assert_eq!(prover_state.memoized_data.get(a_id, &eval_point), Some(a_eval));
+	assert_eq!(prover_state.memoized_data.get(b_id, &eval_point), Some(b_eval));
+	assert_eq!(prover_state.memoized_data.get(lin_com_id, &eval_point), None);
+
+	prover_state
+		.prove(vec![claim.clone()], &mut transcript)
+		.unwrap();
+	assert_eq!(prover_state.committed_eval_claims().len(), 4);
+
+	// The second round, proven with the memoized evaluations, is accepted.
+	let mut transcript = transcript.into_verifier();
+	let mut verifier_state = EvalcheckVerifier::<FExtension>::new(&mut oracles);
+	verifier_state
+		.verify(vec![claim.clone()], &mut transcript)
+		.unwrap();
+	verifier_state
+		.verify(vec![claim.clone()], &mut transcript)
+		.unwrap();
+	assert_eq!(verifier_state.committed_eval_claims().len(), 4);
+	transcript.finalize().unwrap();
+
+	// A manually memoized evaluation is used in place of the missing witness.
+	let mut witness_index = MultilinearExtensionIndex::<PExtension>::new();
+	witness_index
+		.update_multilin_poly([(b_id, b_witness.specialize_arc_dyn())])
+		.unwrap();
+
+	let mut transcript = ProverTranscript::<HasherChallenger<Groestl256>>::new();
+	let mut prover_state = EvalcheckProver::new(&mut oracles, &mut witness_index);
+	prover_state
+		.memoized_data
+		.insert(a_id, eval_point.into(), a_eval);
+	prover_state
+		.prove(vec![claim.clone()], &mut transcript)
+		.unwrap();
+	let committed_a_claim = prover_state
+		.committed_eval_claims()
+		.iter()
+		.find(|committed| committed.id == a_id)
+		.unwrap();
+	assert_eq!(committed_a_claim.eval, a_eval);
+
+	let mut transcript = transcript.into_verifier();
+	let mut verifier_state = EvalcheckVerifier::<FExtension>::new(&mut oracles);
+	verifier_state.verify(vec![claim], &mut transcript).unwrap();
+	transcript.finalize().unwrap();
+}
@@ -0,0 +1,214 @@
+// Copyright 2025 Irreducible Inc.
+
+use binius_field::TowerField;
+use itertools::chain;
+
+use super::{
+	EvalPoint, EvalcheckHint, EvalcheckMultilinearClaim, error::Error,
+	subclaims::add_composite_sumcheck_to_constraints,
+};
+use crate::{
+	oracle::{ConstraintSetBuilder, MultilinearOracleSet, MultilinearPolyVariant, OracleId},
+	polynomial::MultivariatePoly,
+	transparent::select_row::SelectRow,
+};
+
+/// The per-claim work of a traversal of evalcheck claims with [`visit_claim`].
+///
+/// The traversal visits claims in the order [`EvalcheckProver`](super::EvalcheckProver) proves
+/// them, stepping into subclaims depth first and deduplicating claims within a round.
+pub(crate) trait ClaimVisitor<F: TowerField> {
+	fn oracles(&self) -> &MultilinearOracleSet<F>;
+
+	/// The index in this round of a claim that has already been visited.
+	fn claim_index(&self, id: OracleId, eval_point: &EvalPoint<F>) -> Option<usize>;
+
+	/// Assigns the next index of this round to a newly visited claim.
+	fn insert_claim(&mut self, id: OracleId, eval_point: EvalPoint<F>);
+
+	fn write_hint(&mut self, hint: EvalcheckHint);
+
+	fn visit_committed(&mut self, claim: EvalcheckMultilinearClaim<F>);
+
+	/// Visits a claim on a shifted or packed oracle, which is reduced to a bivariate sumcheck at
+	/// the end of the round.
+	fn visit_projected_bivariate(&mut self, claim: EvalcheckMultilinearClaim<F>);
+
+	/// Visits a claim on a composite oracle, see [`add_composite_claim`].
+	fn visit_composite(&mut self, claim: EvalcheckMultilinearClaim<F>);
+
+	/// The evaluation of a new subclaim of a linear combination.
+	fn linear_combination_subclaim_eval(&mut self, id: OracleId, eval_point: &EvalPoint<F>) -> F;
+
+	/// The evaluation of the subclaim of a zero-padded oracle.
+	fn zero_padded_subclaim_eval(&mut self, id: OracleId, eval_point: &EvalPoint<F>) -> F;
+}
+
+/// Visits a claim, writing a hint of whether it duplicates a claim of this round.
+pub(crate) fn visit_claim<F, V>(
+	visitor: &mut V,
+	claim: EvalcheckMultilinearClaim<F>,
+) -> Result<(), Error>
+where
+	F: TowerField,
+	V: ClaimVisitor<F>,
+{
+	if let Some(index) = visitor.claim_index(claim.id, &claim.eval_point) {
+		visitor.write_hint(EvalcheckHint::DuplicateClaim(index as u32));
+		return Ok(());
+	}
+	visitor.write_hint(EvalcheckHint::NewClaim);
+
+	visit_claim_skip_duplicate_check(visitor, claim)
+}
+
+fn visit_claim_skip_duplicate_check<F, V>(
+	visitor: &mut V,
+	claim: EvalcheckMultilinearClaim<F>,
+) -> Result<(), Error>
+where
+	F: TowerField,
+	V: ClaimVisitor<F>,
+{
+	visitor.insert_claim(claim.id, claim.eval_point.clone());
+
+	let EvalcheckMultilinearClaim {
+		id,
+		ref eval_point,
+		eval,
+	} = claim;
+
+	let step = match &visitor.oracles()[id].variant {
+		MultilinearPolyVariant::Transparent { .. } | MultilinearPolyVariant::Structured(_) => {
+			return Ok(());
+		}
+		MultilinearPolyVariant::Committed => Step::Committed,
+		&MultilinearPolyVariant::Repeating {
+			id: inner_id,
+			log_count,
+		} => {
+			let n_vars = eval_point.len() - log_count;
+			Step::Subclaim(inner_id, eval_point.slice(0..n_vars))
+		}
+		MultilinearPolyVariant::Projected(projected) => {
+			let new_eval_point = {
+				let (lo, hi) = eval_point.split_at(projected.start_index());
+				chain!(lo, projected.values(), hi)
+					.copied()
+					.collect::<Vec<_>>()
+			};
+			Step::Subclaim(projected.id(), new_eval_point.into())
+		}
+		MultilinearPolyVariant::Shifted(_) | MultilinearPolyVariant::Packed(_) => {
+			Step::ProjectedBivariate
+		}
+		MultilinearPolyVariant::Composite(_) => Step::Composite,
+		MultilinearPolyVariant::LinearCombination(linear_combination) => {
+			Step::LinearCombination(linear_combination.polys().collect())
+		}
+		MultilinearPolyVariant::ZeroPadded(padded) => {
+			let inner_eval_point = chain!(
+				&eval_point[..padded.start_index()],
+				&eval_point[padded.start_index() + padded.n_pad_vars()..],
+			)
+			.copied()
+			.collect::<Vec<_>>();
+
+			let zs = &eval_point[padded.start_index()..padded.start_index() + padded.n_pad_vars()];
+			let select_row = SelectRow::new(zs.len(), padded.nonzero_index())?;
+			let select_row_term = select_row
+				.evaluate(zs)
+				.expect("select_row is constructor with zs.len() variables");
+
+			if eval.is_zero() && select_row_term.is_zero() {
+				return Ok(());
+			}
+
+			Step::ZeroPadded(padded.id(), inner_eval_point.into())
+		}
+	};
+
+	match step {
+		Step::Committed => visitor.visit_committed(claim),
+		Step::ProjectedBivariate => visitor.visit_projected_bivariate(claim),
+		Step::Composite => visitor.visit_composite(claim),
+		Step::Subclaim(id, eval_point) => {
+			visit_claim(
+				visitor,
+				EvalcheckMultilinearClaim {
+					id,
+					eval_point,
+					eval,
+				},
+			)?;
+		}
+		Step::LinearCombination(suboracle_ids) => {
+			for suboracle_id in suboracle_ids {
+				if let Some(index) = visitor.claim_index(suboracle_id, &claim.eval_point) {
+					visitor.write_hint(EvalcheckHint::DuplicateClaim(index as u32));
+				} else {
+					visitor.write_hint(EvalcheckHint::NewClaim);
+
+					let eval =
+						visitor.linear_combination_subclaim_eval(suboracle_id, &claim.eval_point);
+					visit_claim_skip_duplicate_check(
+						visitor,
+						EvalcheckMultilinearClaim {
+							id: suboracle_id,
+							eval_point: claim.eval_point.clone(),
+							eval,
+						},
+					)?;
+				}
+			}
+		}
+		Step::ZeroPadded(id, eval_point) => {
+			let eval = visitor.zero_padded_subclaim_eval(id, &eval_point);
+			visit_claim(
+				visitor,
+				EvalcheckMultilinearClaim {
+					id,
+					eval_point,
+					eval,
+				},
+			)?;
+		}
+	}
+	Ok(())
+}
+
+/// What remains to be done for a claim after its oracle has been looked up.
+enum Step<F: TowerField> {
+	Committed,
+	ProjectedBivariate,
+	Composite,
+	Subclaim(OracleId, EvalPoint<F>),
+	LinearCombination(Vec<OracleId>),
+	ZeroPadded(OracleId, EvalPoint<F>),
+}
+
+/// Adds a claim on a composite oracle to the mlecheck constraint set of its evaluation point,
+/// returning the position of the constraint set.
+pub(crate) fn add_composite_claim<F: TowerField>(
+	oracles: &MultilinearOracleSet<F>,
+	constraint_builders: &mut Vec<(EvalPoint<F>, ConstraintSetBuilder<F>)>,
+	claim: &EvalcheckMultilinearClaim<F>,
+) -> usize {
+	let MultilinearPolyVariant::Composite(composite) = &oracles[claim.id].variant else {
+		unreachable!("visit_composite is only called on composite oracles");
+	};
+
+	let position = constraint_builders
+		.iter()
+		.position(|(ep, _)| *ep == claim.eval_point)
+		.unwrap_or(constraint_builders.len());
+
+	add_composite_sumcheck_to_constraints(
+		position,
+		&claim.eval_point,
+		constraint_builders,
+		composite,
+		claim.eval,
+	);
+	position
+}
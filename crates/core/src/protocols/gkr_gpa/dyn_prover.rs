@@ -0,0 +1,88 @@
+// Copyright 2025 Irreducible Inc.
+
+use std::marker::PhantomData;
+
+use binius_field::{BinaryField, Field, PackedExtension, PackedField, TowerField};
+use binius_hal::ComputationBackend;
+use binius_math::{DefaultEvaluationDomainFactory, EvaluationOrder};
+
+use super::{
+	Error, GrandProductBatchProveOutput, GrandProductClaim, GrandProductWitness, batch_prove,
+};
+use crate::{fiat_shamir::Challenger, transcript::ProverTranscript};
+
+/// A grand product prover with the packed field of the witnesses and the domain field erased.
+///
+/// Binaries supporting several field configurations can select a `Box<dyn GpaProver<F, _>>` at
+/// runtime rather than instantiating [`batch_prove`] for every configuration at every call site.
+/// The input layers are passed as scalars and packed by the prover, so the dynamic path costs a
+/// copy of the inputs and a virtual call per batch on top of [`batch_prove`].
+pub trait GpaProver<F: TowerField, Challenger_> {
+	/// Proves the grand product claims with the `n_vars`-variate input layers `inputs`, as
+	/// [`batch_prove`] does.
+	///
+	/// As for [`GrandProductWitness::new`], an input layer may be shorter than the hypercube, in
+	/// which case the missing evaluations are one.
+	fn batch_prove(
+		&self,
+		evaluation_order: EvaluationOrder,
+		inputs: Vec<(usize, Vec<F>)>,
+		claims: &[GrandProductClaim<F>],
+		transcript: &mut ProverTranscript<Challenger_>,
+	) -> Result<GrandProductBatchProveOutput<F>, Error>;
+}
+
+/// The [`GpaProver`] for the packed field `P` with evaluation domains over `FDomain`.
+#[derive(Debug)]
+pub struct PackedGpaProver<P, FDomain, Backend> {
+	backend: Backend,
+	_marker: PhantomData<fn() -> (P, FDomain)>,
+}
+
+impl<P, FDomain, Backend> PackedGpaProver<P, FDomain, Backend> {
+	pub const fn new(backend: Backend) -> Self {
+		Self {
+			backend,
+			_marker: PhantomData,
+		}
+	}
+}
+
+impl<F, P, FDomain, Challenger_, Backend> GpaProver<F, Challenger_>
+	for PackedGpaProver<P, FDomain, Backend>
+where
+	F: TowerField,
+	P: PackedField<Scalar = F> + PackedExtension<FDomain>,
+	FDomain: BinaryField,
+	Challenger_: Challenger,
+	Backend: ComputationBackend,
+{
+	fn batch_prove(
+		&self,
+		evaluation_order: EvaluationOrder,
+		inputs: Vec<(usize, Vec<F>)>,
+		claims: &[GrandProductClaim<F>],
+		transcript: &mut ProverTranscript<Challenger_>,
+	) -> Result<GrandProductBatchProveOutput<F>, Error> {
+		let witnesses = inputs
+			.into_iter()
+			.map(|(n_vars, input_layer)| {
+				// Padding with ones keeps the implicit trailing evaluations of a short input layer.
+				let packed = input_layer
+					.chunks(P::WIDTH)
+					.map(|chunk| P::from_fn(|i| chunk.get(i).copied().unwrap_or(<F as Field>::ONE)))
+					.collect();
+				GrandProductWitness::new(n_vars, packed)
+			})
+			.collect::<Result<Vec<_>, _>>()?;
+
+		batch_prove::<_, _, FDomain, _, _>(
+			evaluation_order,
+			witnesses,
+			claims,
+			DefaultEvaluationDomainFactory::<FDomain>::default(),
+			transcript,
+			&self.backend,
+		)
+	}
+}
@@ -35,6 +35,26 @@ pub enum Error {
 	MetasProductsMismatch,
 	#[error("first layer advice multilinears do not match provided n_vars")]
 	IncorrectFirstLayerAdviceLength,
+	#[error("the product of witness {index} does not match its public product")]
+	PublicProductMismatch { index: usize },
+	#[error("number of public products does not match the number of claims")]
+	MismatchedPublicProductsLength,
+	#[error("the witness has {values} values but {exponents} exponents")]
+	MismatchedExponentsLength { values: usize, exponents: usize },
+	#[error("claim {index} is out of range or assigned to more than one partition")]
+	InvalidPartitionAssignment { index: usize },
+	#[error("input {index} is in the identity prefix but is not one")]
+	IdentityPrefixMismatch { index: usize },
+	#[error(
+		"the proof of layer {layer} does not verify for a single claim, it may batch several claims"
+	)]
+	SingleClaimProofMismatch { layer: usize },
+	#[error("proof {index} of the batch is invalid: {error}")]
+	BatchProofInvalid {
+		index: usize,
+		#[source]
+		error: Box<Error>,
+	},
 	#[error("polynomial error: {0}")]
 	Polynomial(#[from] PolynomialError),
 	#[error("sumcheck failure: {0}")]
@@ -45,4 +65,6 @@ pub enum Error {
 	HalError(#[from] binius_hal::Error),
 	#[error("Math error: {0}")]
 	MathError(#[from] binius_math::Error),
+	#[error("transcript error: {0}")]
+	TranscriptError(#[from] crate::transcript::Error),
 }
@@ -1,21 +1,37 @@
 // Copyright 2024-2025 Irreducible Inc.
 
-use binius_field::{Field, PackedField, packed::get_packed_slice};
+use std::iter;
+
+use binius_field::{
+	Field, PackedField, PackedFieldIndexable,
+	packed::{get_packed_slice, pack_slice, par_prefix_product},
+};
+use binius_macros::{DeserializeBytes, SerializeBytes};
 use binius_maybe_rayon::prelude::*;
 use binius_utils::bail;
 use bytemuck::zeroed_vec;
+use itertools::izip;
 use tracing::{debug_span, instrument};
 
 use super::Error;
 use crate::protocols::sumcheck::Error as SumcheckError;
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, SerializeBytes, DeserializeBytes)]
 pub struct GrandProductClaim<F: Field> {
 	pub n_vars: usize,
 	pub product: F,
 }
 
 impl<F: Field> GrandProductClaim<F> {
+	/// The number of GKR layers, each reduced by one layer sumcheck, in the proof of the claim.
+	///
+	/// The layer sumchecks of a batch are run together, so a batch proof has as many layers as
+	/// its claim with the most variables. Verifiers can use this to bound the work of a proof
+	/// before reading it.
+	pub const fn n_gkr_layers(&self) -> usize {
+		self.n_vars
+	}
+
 	pub fn isomorphic<FI: Field + From<F>>(self) -> GrandProductClaim<FI> {
 		GrandProductClaim {
 			n_vars: self.n_vars,
@@ -24,6 +40,24 @@ impl<F: Field> GrandProductClaim<F> {
 	}
 }
 
+/// Configuration of the construction of a [`GrandProductWitness`].
+#[derive(Debug, Clone, Copy, Default)]
+pub struct GpaWitnessConfig {
+	/// Base-two logarithm of the minimum number of packed elements computed by one parallel task.
+	///
+	/// Layers with fewer packed elements are computed serially, which avoids the task overhead of
+	/// the small layers near the root of the circuit. The default of zero computes every layer in
+	/// parallel.
+	pub par_chunk_log_size: usize,
+}
+
+impl GpaWitnessConfig {
+	/// The configuration computing every layer serially.
+	const SERIAL: Self = Self {
+		par_chunk_log_size: usize::BITS as usize - 1,
+	};
+}
+
 #[derive(Debug, Clone)]
 pub struct GrandProductWitness<P: PackedField> {
 	circuit_layers: Vec<Vec<P>>,
@@ -36,8 +70,133 @@ pub struct GrandProductWitness<P: PackedField> {
 /// assumed to be `P::Scalar::ONE`. There is a total on `n_vars + 1` layers, ordered
 /// by decreasing size, with last layer containing a single grand product scalar.
 impl<P: PackedField> GrandProductWitness<P> {
-	#[instrument(skip_all, level = "debug", name = "GrandProductWitness::new")]
 	pub fn new(n_vars: usize, input_layer: Vec<P>) -> Result<Self, Error> {
+		Self::new_with_config(n_vars, input_layer, GpaWitnessConfig::default())
+	}
+
+	/// Constructs the witness of the weighted grand product $\prod_i x_i^{e_i}$.
+	///
+	/// The `i`-th scalar of `values` is raised to the `i`-th of `exponents`, and the powers form
+	/// the input layer, so the grand product evaluation is the weighted product. As in
+	/// [`Self::new`], `values` may be shorter than `2^n_vars` scalars, with the absent inputs
+	/// being one.
+	///
+	/// ## Throws
+	///
+	/// * `Error::MismatchedExponentsLength` if `values` and `exponents` differ in scalar count
+	pub fn new_weighted(n_vars: usize, values: Vec<P>, exponents: Vec<u32>) -> Result<Self, Error> {
+		let n_values = values.len() << P::LOG_WIDTH;
+		if n_values != exponents.len() {
+			bail!(Error::MismatchedExponentsLength {
+				values: n_values,
+				exponents: exponents.len(),
+			});
+		}
+
+		let input_layer = izip!(values, exponents.chunks_exact(P::WIDTH))
+			.map(|(packed, exponents)| P::from_fn(|i| packed.get(i).pow(u64::from(exponents[i]))))
+			.collect();
+		Self::new(n_vars, input_layer)
+	}
+
+	/// Constructs the witnesses of a batch of grand products, given as pairs of `n_vars` and the
+	/// input layer as in [`Self::new`].
+	///
+	/// Constructing the witnesses in a parallel iterator over the batch nests the parallelism,
+	/// because every construction also splits each of its layers into parallel tasks. The nested
+	/// tasks do not add threads, but all witnesses are interleaved on all threads, and the many
+	/// small tasks of the layers near the root cost more to schedule than to compute. Instead, the
+	/// batch is split by work: a witness whose input layer is longer than a thread's share of the
+	/// total input length is constructed with parallel layers, one after another, and the other
+	/// witnesses are constructed serially, one per task, in a parallel iterator.
+	#[instrument(skip_all, level = "debug", name = "GrandProductWitness::new_batch")]
+	pub fn new_batch(inputs: Vec<(usize, Vec<P>)>) -> Result<Vec<Self>, Error> {
+		Self::new_batch_with_threads(inputs, binius_maybe_rayon::current_num_threads())
+	}
+
+	/// Constructs a batch as [`Self::new_batch`] does, splitting the work for `n_threads` threads.
+	pub(super) fn new_batch_with_threads(
+		inputs: Vec<(usize, Vec<P>)>,
+		n_threads: usize,
+	) -> Result<Vec<Self>, Error> {
+		let total_len = inputs
+			.iter()
+			.map(|(_, input_layer)| input_layer.len())
+			.sum::<usize>();
+		let thread_share = total_len / n_threads.max(1);
+
+		let mut witnesses = iter::repeat_with(|| None)
+			.take(inputs.len())
+			.collect::<Vec<_>>();
+		let (large_inputs, small_inputs): (Vec<_>, Vec<_>) = inputs
+			.into_iter()
+			.enumerate()
+			.partition(|(_, (_, input_layer))| input_layer.len() > thread_share);
+
+		for (index, (n_vars, input_layer)) in large_inputs {
+			witnesses[index] = Some(Self::new(n_vars, input_layer)?);
+		}
+		let small_witnesses = small_inputs
+			.into_par_iter()
+			.map(|(index, (n_vars, input_layer))| {
+				let witness = Self::new_with_config(n_vars, input_layer, GpaWitnessConfig::SERIAL)?;
+				Ok((index, witness))
+			})
+			.collect::<Result<Vec<_>, Error>>()?;
+		for (index, witness) in small_witnesses {
+			witnesses[index] = Some(witness);
+		}
+
+		Ok(witnesses
+			.into_iter()
+			.map(|witness| witness.expect("every input is constructed exactly once"))
+			.collect())
+	}
+
+	#[instrument(skip_all, level = "debug", name = "GrandProductWitness::new")]
+	pub fn new_with_config(
+		n_vars: usize,
+		input_layer: Vec<P>,
+		config: GpaWitnessConfig,
+	) -> Result<Self, Error> {
+		Self::construct(n_vars, input_layer, config, 0)
+	}
+
+	/// Constructs the witness of an input layer whose first `identity_count` scalars are one, as
+	/// in the padding of permutation arguments.
+	///
+	/// The product of the halves of a layer equals the second half wherever the first half is
+	/// one, so these multiplications are replaced by copies. The prefix of ones in the next layer
+	/// is shorter by the length of a half, so that only the layers close to the input benefit.
+	/// The witness is identical to the one constructed by [`Self::new`].
+	///
+	/// ## Throws
+	///
+	/// * `Error::IdentityPrefixMismatch` if one of the first `identity_count` input scalars is not
+	///   one
+	pub fn new_with_identity_prefix(
+		n_vars: usize,
+		input_layer: Vec<P>,
+		identity_count: usize,
+	) -> Result<Self, Error> {
+		if let Some(index) = P::iter_slice(&input_layer)
+			.take(identity_count)
+			.position(|value| value != P::Scalar::ONE)
+		{
+			bail!(Error::IdentityPrefixMismatch { index });
+		}
+		Self::construct(n_vars, input_layer, GpaWitnessConfig::default(), identity_count)
+	}
+
+	/// Constructs the layers, copying instead of multiplying by the first `identity_count` input
+	/// scalars, which must be one.
+	fn construct(
+		n_vars: usize,
+		input_layer: Vec<P>,
+		config: GpaWitnessConfig,
+		mut identity_count: usize,
+	) -> Result<Self, Error> {
+		let min_par_len = 1 << config.par_chunk_log_size;
 		if input_layer.len() > 1 << n_vars.saturating_sub(P::LOG_WIDTH) {
 			bail!(SumcheckError::NumberOfVariablesMismatch);
 		}
@@ -60,11 +219,28 @@ impl<P: PackedField> GrandProductWitness<P> {
 
 					if pivot > 0 {
 						let (evals_0, evals_1) = prev_layer.split_at(packed_len);
-						(layer.as_mut_slice(), evals_0, evals_1)
-							.into_par_iter()
-							.for_each(|(product, &eval_0, &eval_1)| {
+
+						// The products with fully packed ones of the first half are copies.
+						let n_identity = (identity_count >> P::LOG_WIDTH).min(pivot);
+						let (identity_layer, layer) = layer.split_at_mut(n_identity);
+						let (identity_evals_1, evals_1) = evals_1.split_at(n_identity);
+						identity_layer.copy_from_slice(identity_evals_1);
+						let evals_0 = &evals_0[n_identity..];
+
+						if pivot - n_identity < min_par_len {
+							for (product, &eval_0, &eval_1) in
+								izip!(layer.iter_mut(), evals_0, evals_1)
+							{
 								*product = eval_0 * eval_1;
-							});
+							}
+						} else {
+							(layer, evals_0, evals_1)
+								.into_par_iter()
+								.with_min_len(min_par_len)
+								.for_each(|(product, &eval_0, &eval_1)| {
+									*product = eval_0 * eval_1;
+								});
+						}
 					}
 
 					// In case of truncated witness, some of the scalars may stay unaltered
@@ -82,6 +258,7 @@ impl<P: PackedField> GrandProductWitness<P> {
 					}
 				}
 
+				identity_count = identity_count.saturating_sub(1 << layer_n_vars);
 				circuit_layers.push(layer);
 			}
 		});
@@ -89,6 +266,36 @@ impl<P: PackedField> GrandProductWitness<P> {
 		Ok(Self { circuit_layers })
 	}
 
+	/// Reconstructs a witness from its circuit layers, as returned by
+	/// [`Self::into_circuit_layers`].
+	///
+	/// Only the shape of the layers is checked, not that each layer is the product of the halves
+	/// of the previous one. A witness in `n_vars` variables has `n_vars + 1` layers, and every
+	/// layer after the input layer is as long as the previous one, truncated to the length its
+	/// number of variables permits.
+	///
+	/// ## Throws
+	///
+	/// * `Error::InvalidCircuitEvals` if the number of layers is not `n_vars + 1` or a layer does
+	///   not have the length of the layer constructed from the previous one
+	pub fn from_circuit_layers(n_vars: usize, circuit_layers: Vec<Vec<P>>) -> Result<Self, Error> {
+		if circuit_layers.len() != n_vars + 1 {
+			bail!(Error::InvalidCircuitEvals);
+		}
+		let mut expected_len = usize::MAX;
+		for (layer_index, layer) in circuit_layers.iter().enumerate() {
+			let max_layer_len = 1 << (n_vars - layer_index).saturating_sub(P::LOG_WIDTH);
+			if layer_index == 0 {
+				expected_len = layer.len();
+			}
+			expected_len = expected_len.min(max_layer_len);
+			if layer.len() != expected_len {
+				bail!(Error::InvalidCircuitEvals);
+			}
+		}
+		Ok(Self { circuit_layers })
+	}
+
 	/// Base-two logarithm of the number of inputs to the GKR grand product circuit
 	pub fn n_vars(&self) -> usize {
 		self.circuit_layers.len() - 1
@@ -101,6 +308,38 @@ impl<P: PackedField> GrandProductWitness<P> {
 		first_packed.get(0)
 	}
 
+	/// Returns the prefix products of the input layer as a multilinear in `n_vars` variables.
+	///
+	/// The value at index `i` is the product of the inputs `0..=i`, where absent inputs of a
+	/// truncated witness are one, so the value at the last index is the grand product.
+	pub fn prefix_products(&self) -> Vec<P>
+	where
+		P: PackedFieldIndexable,
+	{
+		let n_vars = self.n_vars();
+		let mut prefix_products = par_prefix_product(&self.circuit_layers[0]);
+
+		// The absent inputs are one, so the prefix products past the input layer are all the
+		// grand product.
+		let grand_product = prefix_products.last().copied().unwrap_or(P::Scalar::ONE);
+		prefix_products.resize(1 << n_vars, grand_product);
+		pack_slice(&prefix_products)
+	}
+
+	/// The numerator multilinear, the input layer of the circuit.
+	///
+	/// The input layer is kept alongside the other layers, so this is the layer passed to the
+	/// constructor, or the weighted powers for [`Self::new_weighted`]. It may be truncated, in
+	/// which case the absent inputs are one.
+	pub fn numerator(&self) -> &[P] {
+		&self.circuit_layers[0]
+	}
+
+	/// The layer multilinears in non-ascending length order.
+	pub fn circuit_layers(&self) -> &[Vec<P>] {
+		&self.circuit_layers
+	}
+
 	/// Consume the witness, returning the vector of layer multilinears in non-ascending length
 	/// order.
 	pub fn into_circuit_layers(self) -> Vec<Vec<P>> {
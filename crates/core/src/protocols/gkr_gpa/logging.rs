@@ -0,0 +1,23 @@
+// Copyright 2025 Irreducible Inc.
+
+use binius_utils::impl_debug_with_json;
+use serde::Serialize;
+
+#[derive(Serialize)]
+pub(super) struct GkrLayerDimensionsData {
+	layer: usize,
+	n_claims: usize,
+	layer_n_vars: usize,
+}
+
+impl GkrLayerDimensionsData {
+	pub(super) fn new(layer: usize, n_claims: usize, layer_n_vars: usize) -> Self {
+		Self {
+			layer,
+			n_claims,
+			layer_n_vars,
+		}
+	}
+}
+
+impl_debug_with_json!(GkrLayerDimensionsData);
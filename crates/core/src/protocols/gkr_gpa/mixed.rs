@@ -0,0 +1,121 @@
+// Copyright 2025 Irreducible Inc.
+
+use std::fmt;
+
+use binius_field::{Field, PackedExtension, PackedField, TowerField};
+use binius_hal::ComputationBackend;
+use binius_math::{EvaluationDomainFactory, EvaluationOrder};
+use binius_maybe_rayon::prelude::*;
+use binius_utils::bail;
+use tracing::instrument;
+
+use super::{
+	Error, GrandProductBatchProveOutput, GrandProductClaim, GrandProductWitness, batch_prove,
+};
+use crate::{fiat_shamir::Challenger, transcript::ProverTranscript};
+
+type Promotion<P> = Box<dyn FnOnce() -> Vec<P> + Send>;
+
+/// The input layer of a grand product witness whose evaluations are in a source field other than
+/// the field of the proof.
+///
+/// The source field converts into the field of the proof, either because it is a subfield of it
+/// or through an isomorphism. The input layer is promoted to the field of the proof by
+/// [`batch_prove_mixed`].
+pub struct MixedWitness<P: PackedField> {
+	n_vars: usize,
+	source_tower_level: usize,
+	promote: Promotion<P>,
+}
+
+impl<P: PackedField> MixedWitness<P> {
+	/// Creates the witness of a grand product over the `n_vars`-variate input layer `input_layer`
+	/// with evaluations in `PS::Scalar`.
+	///
+	/// As for [`GrandProductWitness::new`], the input layer may be shorter than the hypercube, in
+	/// which case the missing evaluations are one.
+	pub fn new<PS>(n_vars: usize, input_layer: Vec<PS>) -> Self
+	where
+		PS: PackedField<Scalar: TowerField>,
+		P::Scalar: From<PS::Scalar>,
+	{
+		let promote = Box::new(move || {
+			let n_scalars = (input_layer.len() << PS::LOG_WIDTH).min(1 << n_vars);
+			let scalars = PS::iter_slice(&input_layer)
+				.take(n_scalars)
+				.map(P::Scalar::from)
+				.collect::<Vec<_>>();
+			// Padding with ones keeps the implicit trailing evaluations of a short input layer.
+			scalars
+				.chunks(P::WIDTH)
+				.map(|chunk| {
+					P::from_fn(|i| chunk.get(i).copied().unwrap_or(<P::Scalar as Field>::ONE))
+				})
+				.collect()
+		});
+		Self {
+			n_vars,
+			source_tower_level: PS::Scalar::TOWER_LEVEL,
+			promote,
+		}
+	}
+
+	/// The number of variables of the input layer.
+	pub const fn n_vars(&self) -> usize {
+		self.n_vars
+	}
+
+	/// The tower level of the source field of the input layer.
+	pub const fn source_tower_level(&self) -> usize {
+		self.source_tower_level
+	}
+}
+
+impl<P: PackedField> fmt::Debug for MixedWitness<P> {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		f.debug_struct("MixedWitness")
+			.field("n_vars", &self.n_vars)
+			.field("source_tower_level", &self.source_tower_level)
+			.finish_non_exhaustive()
+	}
+}
+
+/// Proves a batch of grand product claims whose witnesses are in different source fields.
+///
+/// Every input is promoted to the field `F` of the proof, after which the batch is proven by
+/// [`batch_prove`]. The claims are over `F`, so a claim computed in a source field is converted
+/// with [`GrandProductClaim::isomorphic`], and the proof is verified with
+/// [`batch_verify`](super::batch_verify).
+///
+/// ## Throws
+///
+/// * `Error::MismatchedWitnessClaimLength` if `inputs` and `claims` have different lengths
+/// * the errors of [`GrandProductWitness::new`] for a malformed input layer
+#[instrument(skip_all, name = "gkr_gpa::batch_prove_mixed", level = "debug")]
+pub fn batch_prove_mixed<F, P, FDomain, Challenger_, Backend>(
+	evaluation_order: EvaluationOrder,
+	inputs: Vec<MixedWitness<P>>,
+	claims: &[GrandProductClaim<F>],
+	evaluation_domain_factory: impl EvaluationDomainFactory<FDomain>,
+	transcript: &mut ProverTranscript<Challenger_>,
+	backend: &Backend,
+) -> Result<GrandProductBatchProveOutput<F>, Error>
+where
+	F: TowerField,
+	P: PackedField<Scalar = F> + PackedExtension<FDomain>,
+	FDomain: Field,
+	Challenger_: Challenger,
+	Backend: ComputationBackend,
+{
+	if inputs.len() != claims.len() {
+		bail!(Error::MismatchedWitnessClaimLength);
+	}
+
+	let promoted = inputs
+		.into_par_iter()
+		.map(|input| (input.n_vars, (input.promote)()))
+		.collect::<Vec<_>>();
+	let witnesses = GrandProductWitness::new_batch(promoted)?;
+
+	batch_prove(evaluation_order, witnesses, claims, evaluation_domain_factory, transcript, backend)
+}
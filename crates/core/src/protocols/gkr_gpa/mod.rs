@@ -18,24 +18,43 @@
 //! 3) Layer Challenge $r_{k+1} := (r_k, \mu_k)$ $k+1$ variate, materialized as a combination of the
 //!    above two, used in `LayerClaim`
 //!
+//! The evaluation point of the final layer claims is always sampled from the transcript, and it
+//! cannot be fixed in advance by an outer protocol. Each layer challenge must be sampled after the
+//! prover has sent the round polynomials of that layer, so a prover knowing the point beforehand
+//! could prove a false product. To compose the GPA into a larger protocol, pass the final
+//! `LayerClaim`s on as evalcheck claims, which can be batched with the claims of the outer
+//! protocol.
+//!
 //! See [Thaler13] Section 5.3.1 for further background on the GKR polynomial identities for a
 //! binary tree circuit.
 //!
 //! [Thaler13]: <https://eprint.iacr.org/2013/351>
 
+mod dyn_prover;
 mod error;
 #[allow(clippy::module_inception)]
 mod gkr_gpa;
+mod logging;
+mod mixed;
 mod oracles;
+mod parse;
+mod partition;
 mod prove;
+mod session;
 #[cfg(test)]
 mod tests;
 mod verify;
 
+pub use dyn_prover::*;
 pub use error::*;
 pub use gkr_gpa::{
-	GrandProductBatchProveOutput, GrandProductClaim, GrandProductWitness, LayerClaim,
+	GpaWitnessConfig, GrandProductBatchProveOutput, GrandProductClaim, GrandProductWitness,
+	LayerClaim,
 };
+pub use mixed::*;
 pub use oracles::*;
+pub use parse::*;
+pub use partition::*;
 pub use prove::*;
+pub use session::*;
 pub use verify::*;
@@ -0,0 +1,302 @@
+// Copyright 2025 Irreducible Inc.
+
+use std::{cmp::Reverse, iter, mem};
+
+use binius_field::{Field, TowerField};
+use binius_utils::SerializationMode;
+
+use super::Error;
+use crate::transcript::{
+	Error as TranscriptError, ProofSchema, TRANSCRIPT_FORMAT_VERSION, TranscriptField,
+	TranscriptOpKind,
+};
+
+/// The degree of the layer sumcheck composition, the product of the two halves of a layer with
+/// the equality indicator.
+const LAYER_SUMCHECK_DEGREE: usize = 3;
+
+/// The structure of a grand product batch proof, as read by [`try_parse_proof`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ProofStructure<F: Field> {
+	/// The proofs of the layer reductions, from the output layer towards the input layers.
+	pub layers: Vec<LayerProofStructure<F>>,
+}
+
+/// The proof of the reduction of one layer of a batch of grand product circuits.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LayerProofStructure<F: Field> {
+	/// The round messages of the layer sumcheck, one per variable of the layer.
+	pub round_coeffs: Vec<Vec<F>>,
+	/// The evaluations of the two halves of the layer of every claim still being reduced,
+	/// followed by the evaluation of the equality indicator.
+	pub multilinear_evals: Vec<F>,
+}
+
+/// Parses the proof of [`batch_prove`](super::batch_prove) on claims with `claim_n_vars`
+/// variables, without verifying it.
+///
+/// The proof is not self-describing, so the number of variables of the claims, which is public,
+/// determines its structure. The parser checks the format version, that the proof has exactly the
+/// length of a proof for the claims, and that every field element deserializes. A proof that
+/// parses may still fail [`batch_verify`](super::batch_verify), which checks the cryptographic
+/// relations; a proof that does not parse always fails it.
+///
+/// ## Invariant
+///
+/// The parser never panics, on any `claim_n_vars` and `bytes`, and its work is linear in the
+/// length of `bytes` and the number of claims. It can therefore be applied to untrusted input
+/// before any verification work. To keep this invariant under the `bail_panic` feature, errors
+/// are returned without `bail!`.
+///
+/// ## Throws
+///
+/// * `Error::TranscriptError` if the proof has the wrong version, is too short, has trailing bytes,
+///   or contains an invalid field element
+pub fn try_parse_proof<F: TowerField>(
+	claim_n_vars: &[usize],
+	bytes: &[u8],
+) -> Result<ProofStructure<F>, Error> {
+	let Some((&version, mut bytes)) = bytes.split_first() else {
+		return Err(TranscriptError::ProofUnexpectedlyShort {
+			needed: Some(1),
+			available: 0,
+		}
+		.into());
+	};
+	if version != TRANSCRIPT_FORMAT_VERSION {
+		return Err(TranscriptError::TranscriptVersionMismatch {
+			found: version,
+			expected: TRANSCRIPT_FORMAT_VERSION,
+		}
+		.into());
+	}
+
+	let empty_layer = || LayerProofStructure {
+		round_coeffs: Vec::new(),
+		multilinear_evals: Vec::new(),
+	};
+	let mut layers = Vec::new();
+	let mut layer = empty_layer();
+	// The schema is generated lazily and every layer reads at least one element, so the loop ends
+	// once the proof is exhausted.
+	for entry in GpaProofSchema::new(claim_n_vars).entries() {
+		match entry {
+			SchemaEntry::RoundCoeffs { .. } => {
+				layer
+					.round_coeffs
+					.push(read_scalars(&mut bytes, entry.n_scalars())?);
+			}
+			SchemaEntry::ClaimEvals { .. } | SchemaEntry::EqIndEval { .. } => {
+				layer
+					.multilinear_evals
+					.extend(read_scalars::<F>(&mut bytes, entry.n_scalars())?);
+			}
+			SchemaEntry::LayerChallenge { .. } => {
+				layers.push(mem::replace(&mut layer, empty_layer()));
+			}
+			SchemaEntry::BatchCoeff { .. } | SchemaEntry::RoundChallenge { .. } => {}
+		}
+	}
+
+	if !bytes.is_empty() {
+		return Err(TranscriptError::TranscriptNotEmpty {
+			remaining: bytes.len(),
+		}
+		.into());
+	}
+	Ok(ProofStructure { layers })
+}
+
+/// Returns the layout of the proof of [`batch_prove`](super::batch_prove) on claims with
+/// `claim_n_vars` variables, the same layout that [`try_parse_proof`] reads.
+///
+/// Every field is labelled with its layer and sumcheck round, or with the index of the claim whose
+/// layer evaluations it holds, so that [`diff`](crate::transcript::diff) locates the first
+/// difference between two proofs in terms of the protocol.
+///
+/// ## Throws
+///
+/// * `Error::TranscriptError` if the field does not serialize
+pub fn proof_schema<F: TowerField>(claim_n_vars: &[usize]) -> Result<ProofSchema, Error> {
+	let mut zero = Vec::new();
+	F::ZERO
+		.serialize(&mut zero, SerializationMode::CanonicalTower)
+		.map_err(TranscriptError::from)?;
+	let scalar_len = zero.len();
+
+	let mut schema = ProofSchema::new();
+	for entry in GpaProofSchema::new(claim_n_vars).entries() {
+		if entry.kind() == TranscriptOpKind::Message {
+			schema.push(entry.label(), entry.n_scalars() * scalar_len);
+		}
+	}
+	Ok(schema)
+}
+
+/// Returns every value of the transcript of [`batch_prove`](super::batch_prove) on claims with
+/// `claim_n_vars` variables, in the order in which the prover writes and samples them.
+///
+/// Unlike [`proof_schema`], which only describes the bytes of the proof, this lists each field
+/// element on its own together with its field, and includes the challenges, so that a verifier
+/// written outside of this crate can parse the proof and keep its Fiat-Shamir state in step with
+/// the prover. The format version byte heading the proof is not listed. Every layer reduction,
+/// from the output layer towards the input layers, consists of
+///
+/// 1. the batching coefficient of the layer sumcheck, sampled;
+/// 2. for each sumcheck round, the three coefficients of the truncated round polynomial, written,
+///    and the round challenge, sampled;
+/// 3. the evaluations of the two halves of the layer of every claim still being reduced, in the
+///    order of [`proof_schema`], and the evaluation of the equality indicator, written;
+/// 4. the challenge extrapolating the two halves to the next layer, sampled.
+pub fn transcript_schema<F: TowerField>(claim_n_vars: &[usize]) -> Vec<TranscriptField> {
+	GpaProofSchema::new(claim_n_vars)
+		.entries()
+		.flat_map(|entry| {
+			(0..entry.n_scalars()).map(move |scalar| {
+				TranscriptField::scalar::<F>(entry.kind(), entry.scalar_name(scalar))
+			})
+		})
+		.collect()
+}
+
+/// The schema of a grand product batch proof on claims with given numbers of variables.
+///
+/// This is the single description of the proof from which [`try_parse_proof`], [`proof_schema`]
+/// and [`transcript_schema`] are derived.
+struct GpaProofSchema<'a> {
+	claim_n_vars: &'a [usize],
+	/// The indices of the claims by descending number of variables, keeping the relative order of
+	/// claims with the same number of variables, which is the order the prover reduces them in.
+	sorted_indices: Vec<usize>,
+}
+
+impl<'a> GpaProofSchema<'a> {
+	fn new(claim_n_vars: &'a [usize]) -> Self {
+		let mut sorted_indices = (0..claim_n_vars.len()).collect::<Vec<_>>();
+		sorted_indices.sort_by_key(|&index| Reverse(claim_n_vars[index]));
+		Self {
+			claim_n_vars,
+			sorted_indices,
+		}
+	}
+
+	/// Returns the entries of the transcript in the order in which the prover writes and samples
+	/// them.
+	///
+	/// The entries are generated lazily, so that a parser stopping at the end of the proof does
+	/// work linear in its length even when the claims have a huge number of variables.
+	fn entries(&self) -> impl Iterator<Item = SchemaEntry> + '_ {
+		let max_n_vars = self.claim_n_vars.iter().copied().max().unwrap_or(0);
+		(0..max_n_vars).flat_map(move |layer_no| {
+			let rounds = (0..layer_no).flat_map(move |round| {
+				[
+					SchemaEntry::RoundCoeffs { layer_no, round },
+					SchemaEntry::RoundChallenge { layer_no, round },
+				]
+			});
+			// The claims still being reduced are a prefix of the sorted claims.
+			let evals = self
+				.sorted_indices
+				.iter()
+				.take_while(move |&&index| self.claim_n_vars[index] > layer_no)
+				.map(move |&index| SchemaEntry::ClaimEvals { layer_no, index });
+			iter::once(SchemaEntry::BatchCoeff { layer_no })
+				.chain(rounds)
+				.chain(evals)
+				.chain([
+					SchemaEntry::EqIndEval { layer_no },
+					SchemaEntry::LayerChallenge { layer_no },
+				])
+		})
+	}
+}
+
+/// A group of field elements of the transcript of a grand product batch proof.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum SchemaEntry {
+	/// The batching coefficient of the layer sumcheck.
+	BatchCoeff { layer_no: usize },
+	/// The coefficients of the truncated round polynomial of a layer sumcheck round.
+	RoundCoeffs { layer_no: usize, round: usize },
+	/// The challenge of a layer sumcheck round.
+	RoundChallenge { layer_no: usize, round: usize },
+	/// The evaluations of the two halves of the layer of a claim.
+	ClaimEvals { layer_no: usize, index: usize },
+	/// The evaluation of the equality indicator of the layer sumcheck.
+	EqIndEval { layer_no: usize },
+	/// The challenge extrapolating the two halves of the layer to the next layer.
+	LayerChallenge { layer_no: usize },
+}
+
+impl SchemaEntry {
+	const fn kind(self) -> TranscriptOpKind {
+		match self {
+			Self::BatchCoeff { .. } | Self::RoundChallenge { .. } | Self::LayerChallenge { .. } => {
+				TranscriptOpKind::Sample
+			}
+			Self::RoundCoeffs { .. } | Self::ClaimEvals { .. } | Self::EqIndEval { .. } => {
+				TranscriptOpKind::Message
+			}
+		}
+	}
+
+	const fn n_scalars(self) -> usize {
+		match self {
+			Self::RoundCoeffs { .. } => LAYER_SUMCHECK_DEGREE,
+			Self::ClaimEvals { .. } => 2,
+			_ => 1,
+		}
+	}
+
+	/// The label of the whole entry, as used by [`proof_schema`].
+	fn label(self) -> String {
+		match self {
+			Self::BatchCoeff { layer_no } => format!("gkr_gpa layer {layer_no} batch coeff"),
+			Self::RoundCoeffs { layer_no, round } => {
+				format!("gkr_gpa layer {layer_no} sumcheck round {round}")
+			}
+			Self::RoundChallenge { layer_no, round } => {
+				format!("gkr_gpa layer {layer_no} sumcheck round {round} challenge")
+			}
+			Self::ClaimEvals { layer_no, index } => {
+				format!("gkr_gpa layer {layer_no} evals of claim {index}")
+			}
+			Self::EqIndEval { layer_no } => format!("gkr_gpa layer {layer_no} eq indicator eval"),
+			Self::LayerChallenge { layer_no } => format!("gkr_gpa layer {layer_no} challenge"),
+		}
+	}
+
+	/// The name of the `scalar`-th field element of the entry, as used by [`transcript_schema`].
+	fn scalar_name(self, scalar: usize) -> String {
+		match self {
+			Self::RoundCoeffs { .. } => format!("{} coeff {scalar}", self.label()),
+			Self::ClaimEvals { layer_no, index } => {
+				let half = if scalar == 0 { "lo" } else { "hi" };
+				format!("gkr_gpa layer {layer_no} eval {half} of claim {index}")
+			}
+			_ => self.label(),
+		}
+	}
+}
+
+/// Reads `n` field elements, checking the length of the proof before reading any of them.
+fn read_scalars<F: TowerField>(bytes: &mut &[u8], n: usize) -> Result<Vec<F>, Error> {
+	let mode = SerializationMode::CanonicalTower;
+	let mut zero = Vec::new();
+	F::ZERO
+		.serialize(&mut zero, mode)
+		.map_err(TranscriptError::from)?;
+
+	let available = bytes.len();
+	match n.checked_mul(zero.len()) {
+		Some(needed) if needed <= available => {}
+		needed => {
+			return Err(TranscriptError::ProofUnexpectedlyShort { needed, available }.into());
+		}
+	}
+	iter::repeat_with(|| {
+		F::deserialize(&mut *bytes, mode).map_err(|err| TranscriptError::from(err).into())
+	})
+	.take(n)
+	.collect()
+}
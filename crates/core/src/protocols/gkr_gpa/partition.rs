@@ -0,0 +1,129 @@
+// Copyright 2025 Irreducible Inc.
+
+use std::iter;
+
+use binius_field::{Field, PackedExtension, PackedField, TowerField};
+use binius_hal::ComputationBackend;
+use binius_macros::{DeserializeBytes, SerializeBytes};
+use binius_math::{EvaluationDomainFactory, EvaluationOrder};
+use binius_maybe_rayon::prelude::*;
+use binius_utils::bail;
+use itertools::izip;
+use tracing::instrument;
+
+use super::{
+	Error, GrandProductBatchProveOutput, GrandProductClaim, GrandProductWitness, batch_prove,
+};
+use crate::{fiat_shamir::Challenger, transcript::ProverTranscript};
+
+/// The part of a grand product batch computed by one worker of a partitioned proof.
+///
+/// A worker constructs the witnesses of the claims assigned to it, evaluating every layer of their
+/// product circuits, and sends the claims together with the circuit layers back to the
+/// coordinator, which only runs the layer sumchecks of the whole batch. The intermediate is
+/// serializable, so that workers may run on other machines.
+#[derive(Debug, Clone, SerializeBytes, DeserializeBytes)]
+pub struct GrandProductPartition<F: Field, P: PackedField<Scalar = F>> {
+	/// The claims of the partition, in the order of the assigned claim indices.
+	pub claims: Vec<GrandProductClaim<F>>,
+	/// The circuit layers of every claim, as returned by
+	/// [`GrandProductWitness::into_circuit_layers`].
+	pub circuit_layers: Vec<Vec<Vec<P>>>,
+}
+
+impl<F: Field, P: PackedField<Scalar = F>> GrandProductPartition<F, P> {
+	/// Creates the partition proving `claims` with the corresponding `witnesses`.
+	pub fn new(
+		claims: Vec<GrandProductClaim<F>>,
+		witnesses: impl IntoIterator<Item = GrandProductWitness<P>>,
+	) -> Self {
+		Self {
+			claims,
+			circuit_layers: witnesses
+				.into_iter()
+				.map(GrandProductWitness::into_circuit_layers)
+				.collect(),
+		}
+	}
+}
+
+/// Proves a batch of grand product claims whose witnesses are computed by several workers.
+///
+/// `partitions` assigns the claims to workers, with the `i`-th partition listing the indices of
+/// the claims computed by the `i`-th worker. Every index in `0..n_claims` must be assigned to
+/// exactly one partition. The coordinator gathers the partitions by calling
+/// `gather_partition(i, &partitions[i])` for every partition, in parallel, and then runs the layer
+/// sumchecks of the reunited batch in a single transcript, without evaluating any circuit layer
+/// itself.
+///
+/// The proof and the final layer claims are identical to those of [`batch_prove`] on the claims in
+/// index order, so the proof is verified with [`batch_verify`](super::batch_verify).
+///
+/// ## Throws
+///
+/// * `Error::InvalidPartitionAssignment` if the partitions do not assign every claim exactly once
+/// * `Error::MismatchedWitnessClaimLength` if a gathered partition does not match its assignment
+/// * `Error::InvalidCircuitEvals` if the circuit layers of a gathered partition do not have the
+///   shape of a witness for the number of variables of its claim
+#[instrument(skip_all, name = "gkr_gpa::batch_prove_partitioned", level = "debug")]
+pub fn batch_prove_partitioned<F, P, FDomain, Challenger_, Backend>(
+	evaluation_order: EvaluationOrder,
+	partitions: &[Vec<usize>],
+	gather_partition: impl Fn(usize, &[usize]) -> Result<GrandProductPartition<F, P>, Error> + Sync,
+	evaluation_domain_factory: impl EvaluationDomainFactory<FDomain>,
+	transcript: &mut ProverTranscript<Challenger_>,
+	backend: &Backend,
+) -> Result<GrandProductBatchProveOutput<F>, Error>
+where
+	F: TowerField,
+	P: PackedField<Scalar = F> + PackedExtension<FDomain>,
+	FDomain: Field,
+	Challenger_: Challenger,
+	Backend: ComputationBackend,
+{
+	let n_claims = partitions.iter().map(Vec::len).sum::<usize>();
+	let mut assigned = vec![false; n_claims];
+	for &index in partitions.iter().flatten() {
+		if index >= n_claims || assigned[index] {
+			bail!(Error::InvalidPartitionAssignment { index });
+		}
+		assigned[index] = true;
+	}
+
+	let gathered = partitions
+		.par_iter()
+		.enumerate()
+		.map(|(i, indices)| gather_partition(i, indices))
+		.collect::<Result<Vec<_>, _>>()?;
+
+	let mut claims_witnesses = iter::repeat_with(|| None)
+		.take(n_claims)
+		.collect::<Vec<_>>();
+	for (indices, partition) in partitions.iter().zip(gathered) {
+		let GrandProductPartition {
+			claims,
+			circuit_layers,
+		} = partition;
+		if claims.len() != indices.len() || circuit_layers.len() != indices.len() {
+			bail!(Error::MismatchedWitnessClaimLength);
+		}
+		for (&index, claim, circuit_layers) in izip!(indices, claims, circuit_layers) {
+			let witness = GrandProductWitness::from_circuit_layers(claim.n_vars, circuit_layers)?;
+			claims_witnesses[index] = Some((claim, witness));
+		}
+	}
+
+	let (claims, witnesses): (Vec<_>, Vec<_>) = claims_witnesses
+		.into_iter()
+		.map(|claim_witness| claim_witness.expect("every claim is assigned exactly once"))
+		.unzip();
+
+	batch_prove(
+		evaluation_order,
+		witnesses,
+		&claims,
+		evaluation_domain_factory,
+		transcript,
+		backend,
+	)
+}
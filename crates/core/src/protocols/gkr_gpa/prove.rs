@@ -1,31 +1,80 @@
 // Copyright 2024-2025 Irreducible Inc.
 
+use std::{borrow::Cow, slice};
+
 use binius_field::{Field, PackedExtension, PackedField, TowerField};
 use binius_hal::ComputationBackend;
-use binius_math::{EvaluationDomainFactory, EvaluationOrder, extrapolate_line_scalar};
+use binius_math::{
+	CompositionPoly, EvaluationDomainFactory, EvaluationOrder, MultilinearPoly, RowsBatchRef,
+	extrapolate_line_scalar,
+};
+use binius_maybe_rayon::prelude::*;
 use binius_utils::{
 	bail,
 	sorting::{stable_sort, unsort},
 };
+use bytemuck::zeroed_vec;
 use itertools::izip;
 use tracing::instrument;
 
 use super::{
 	Error, GrandProductClaim, GrandProductWitness,
 	gkr_gpa::{GrandProductBatchProveOutput, LayerClaim},
+	logging::GkrLayerDimensionsData,
 };
 use crate::{
 	composition::{BivariateProduct, IndexComposition},
 	fiat_shamir::{CanSample, Challenger},
+	parallelism,
+	polynomial::MultilinearComposite,
 	protocols::sumcheck::{
 		BatchSumcheckOutput, CompositeSumClaim,
 		prove::{SumcheckProver, eq_ind::EqIndSumcheckProverBuilder, front_loaded},
 	},
 	transcript::ProverTranscript,
+	witness::MultilinearWitness,
 };
 
 /// Proves batch reduction turning each GrandProductClaim into LayerClaim on original multilinear.
 ///
+/// An empty batch yields no final layer claims and leaves the transcript untouched.
+///
+/// The evaluation domains of the layer sumchecks are defined over `FDomain`, which must be a
+/// subfield of `F`. This is enforced by the `PackedExtension<FDomain>` bound, so that pairing `F`
+/// with a field it does not extend fails to compile:
+///
+/// ```compile_fail
+/// # use binius_core::{
+/// # 	fiat_shamir::HasherChallenger,
+/// # 	protocols::gkr_gpa::{GrandProductClaim, GrandProductWitness, batch_prove},
+/// # 	transcript::ProverTranscript,
+/// # };
+/// # use binius_field::{
+/// # 	BinaryField32b, BinaryField128bPolyval, Field, PackedBinaryPolyval1x128b, PackedField,
+/// # };
+/// # use binius_hal::make_portable_backend;
+/// # use binius_hash::groestl::Groestl256;
+/// # use binius_math::{EvaluationOrder, IsomorphicEvaluationDomainFactory};
+/// let witness = GrandProductWitness::new(0, vec![PackedBinaryPolyval1x128b::one()]).unwrap();
+/// let claim = GrandProductClaim {
+/// 	n_vars: 0,
+/// 	product: BinaryField128bPolyval::ONE,
+/// };
+/// let mut transcript = ProverTranscript::<HasherChallenger<Groestl256>>::new();
+/// // BinaryField32b is not a subfield of BinaryField128bPolyval.
+/// batch_prove::<_, _, BinaryField32b, _, _>(
+/// 	EvaluationOrder::HighToLow,
+/// 	[witness],
+/// 	&[claim],
+/// 	IsomorphicEvaluationDomainFactory::<BinaryField32b>::default(),
+/// 	&mut transcript,
+/// 	&make_portable_backend(),
+/// );
+/// ```
+///
+/// Any subfield is large enough, because the degree 2 layer sumchecks need only two finite
+/// evaluation points besides the point at infinity.
+///
 /// REQUIRES:
 /// * witnesses and claims are of the same length
 /// * The ith witness corresponds to the ith claim
@@ -58,6 +107,317 @@ where
 		.map(|(witness, claim)| GrandProductProverState::new(claim, witness))
 		.collect::<Result<Vec<_>, _>>()?;
 
+	prove_states(
+		evaluation_order,
+		prover_states,
+		claims,
+		evaluation_domain_factory,
+		transcript,
+		backend,
+	)
+}
+
+/// Proves batch reduction turning each GrandProductClaim into LayerClaim on original multilinear,
+/// without consuming the witnesses.
+///
+/// The proof is identical to the one produced by [`batch_prove`]. Because the witnesses are only
+/// borrowed, the layers of the product circuits cannot be freed as the layer reductions proceed.
+/// Instead, each layer is copied right before it is reduced, so that the witnesses stay allocated
+/// for the whole duration of proving, in addition to the copy of the layer being reduced. Prefer
+/// [`batch_prove`] unless the witnesses need to be inspected after proving.
+///
+/// REQUIRES:
+/// * witnesses and claims are of the same length
+/// * The ith witness corresponds to the ith claim
+#[instrument(skip_all, name = "gkr_gpa::batch_prove_ref", level = "debug")]
+pub fn batch_prove_ref<F, P, FDomain, Challenger_, Backend>(
+	evaluation_order: EvaluationOrder,
+	witnesses: &[GrandProductWitness<P>],
+	claims: &[GrandProductClaim<F>],
+	evaluation_domain_factory: impl EvaluationDomainFactory<FDomain>,
+	transcript: &mut ProverTranscript<Challenger_>,
+	backend: &Backend,
+) -> Result<GrandProductBatchProveOutput<F>, Error>
+where
+	F: TowerField,
+	P: PackedField<Scalar = F> + PackedExtension<FDomain>,
+	FDomain: Field,
+	Challenger_: Challenger,
+	Backend: ComputationBackend,
+{
+	if witnesses.len() != claims.len() {
+		bail!(Error::MismatchedWitnessClaimLength);
+	}
+
+	let prover_states = izip!(witnesses, claims)
+		.map(|(witness, claim)| GrandProductProverState::new_borrowed(claim, witness))
+		.collect::<Result<Vec<_>, _>>()?;
+
+	prove_states(
+		evaluation_order,
+		prover_states,
+		claims,
+		evaluation_domain_factory,
+		transcript,
+		backend,
+	)
+}
+
+/// Proves the grand product of the column `composition(inner)` over the `n_vars`-variate
+/// hypercube, evaluating the composition directly into the input layer of the product circuit.
+///
+/// The proof is identical to the one produced by [`batch_prove`] for the single witness built from
+/// the materialized composite column. The input layer cannot be avoided, as the sumcheck of the
+/// last layer reduction reads it, but it is the only copy of the column, see
+/// [`composite_input_layer`].
+///
+/// ## Throws
+///
+/// * `Error::Polynomial` if the composition does not have one variable per inner witness, or an
+///   inner witness does not have `n_vars` variables
+/// * `Error::ProverClaimWitnessMismatch` if the claim is not over `n_vars` variables
+#[instrument(skip_all, name = "gkr_gpa::prove_composite_product", level = "debug")]
+#[allow(clippy::too_many_arguments)]
+pub fn prove_composite_product<'a, F, P, FDomain, Composition, Challenger_, Backend>(
+	evaluation_order: EvaluationOrder,
+	n_vars: usize,
+	composition: Composition,
+	inner: Vec<MultilinearWitness<'a, P>>,
+	claim: &GrandProductClaim<F>,
+	evaluation_domain_factory: impl EvaluationDomainFactory<FDomain>,
+	transcript: &mut ProverTranscript<Challenger_>,
+	backend: &Backend,
+) -> Result<GrandProductBatchProveOutput<F>, Error>
+where
+	F: TowerField,
+	P: PackedField<Scalar = F> + PackedExtension<FDomain>,
+	FDomain: Field,
+	Composition: CompositionPoly<P>,
+	Challenger_: Challenger,
+	Backend: ComputationBackend,
+{
+	let input_layer = composite_input_layer(n_vars, composition, inner)?;
+	let witness = GrandProductWitness::new(n_vars, input_layer)?;
+	batch_prove(
+		evaluation_order,
+		[witness],
+		slice::from_ref(claim),
+		evaluation_domain_factory,
+		transcript,
+		backend,
+	)
+}
+
+/// Evaluates the column `composition(inner)` over the `n_vars`-variate hypercube into the input
+/// layer of a product circuit.
+///
+/// The composition is evaluated packed, with [`CompositionPoly::batch_evaluate`], on chunks of
+/// the packed evaluations of the inner witnesses, which are borrowed where the witnesses hold
+/// them with one scalar per lane. Other witnesses are copied, one chunk at a time. The lanes of
+/// the input layer beyond the hypercube, present when `n_vars` is less than `P::LOG_WIDTH`, are
+/// one, so that they do not change the product.
+///
+/// ## Throws
+///
+/// * `Error::Polynomial` if the composition does not have one variable per inner witness, or an
+///   inner witness does not have `n_vars` variables
+pub(super) fn composite_input_layer<'a, P, Composition>(
+	n_vars: usize,
+	composition: Composition,
+	inner: Vec<MultilinearWitness<'a, P>>,
+) -> Result<Vec<P>, Error>
+where
+	P: PackedField,
+	Composition: CompositionPoly<P>,
+{
+	const CHUNK_LOG_LEN: usize = 10;
+
+	let composite = MultilinearComposite::new(n_vars, composition, inner)?;
+	let log_packed_len = n_vars.saturating_sub(P::LOG_WIDTH);
+	let log_chunk_len = log_packed_len.min(CHUNK_LOG_LEN);
+	let chunk_vars = n_vars.min(log_chunk_len + P::LOG_WIDTH);
+
+	let mut input_layer = zeroed_vec::<P>(1 << log_packed_len);
+	parallelism::install(|| {
+		input_layer
+			.par_chunks_mut(1 << log_chunk_len)
+			.enumerate()
+			.try_for_each(|(chunk_index, evals)| -> Result<(), Error> {
+				let chunk_range = chunk_index * evals.len()..(chunk_index + 1) * evals.len();
+				let inner_evals = composite
+					.multilinears
+					.iter()
+					.map(|poly| match poly.packed_evals() {
+						// The packed evaluations of a subfield witness hold several of its
+						// scalars per lane, so those are copied instead.
+						Some(packed_evals)
+							if poly.log_extension_degree() == 0
+								&& packed_evals.len() >= chunk_range.end =>
+						{
+							Ok(Cow::Borrowed(&packed_evals[chunk_range.clone()]))
+						}
+						_ => {
+							let mut chunk = zeroed_vec(evals.len());
+							poly.subcube_evals(chunk_vars, chunk_index, 0, &mut chunk)?;
+							Ok(Cow::Owned(chunk))
+						}
+					})
+					.collect::<Result<Vec<_>, Error>>()?;
+				let rows = inner_evals.iter().map(Cow::as_ref).collect::<Vec<_>>();
+				composite
+					.composition
+					.batch_evaluate(&RowsBatchRef::new(&rows, evals.len()), evals)?;
+				Ok(())
+			})
+	})?;
+
+	if n_vars < P::LOG_WIDTH {
+		for lane in 1 << n_vars..P::WIDTH {
+			input_layer[0].set(lane, P::Scalar::ONE);
+		}
+	}
+	Ok(input_layer)
+}
+
+/// Proves grand product claims whose products are public constants known to the verifier, such as
+/// the product of one in a permutation check.
+///
+/// The products are observed by the challenger, so that the challenges depend on them, but they
+/// are not written to the proof. The proof is thereby one field element per claim shorter than
+/// that of [`batch_prove_sent_products`], which sends products only known to the prover. The
+/// claims are verified with [`batch_verify_public_products`](super::batch_verify_public_products).
+///
+/// ## Throws
+///
+/// * `Error::MismatchedWitnessClaimLength` if `expected` and `witnesses` differ in length
+/// * `Error::PublicProductMismatch` if the product of a witness differs from its expected product
+#[instrument(
+	skip_all,
+	name = "gkr_gpa::batch_prove_public_products",
+	level = "debug"
+)]
+pub fn batch_prove_public_products<F, P, FDomain, Challenger_, Backend>(
+	evaluation_order: EvaluationOrder,
+	expected: &[F],
+	witnesses: impl IntoIterator<Item = GrandProductWitness<P>>,
+	evaluation_domain_factory: impl EvaluationDomainFactory<FDomain>,
+	transcript: &mut ProverTranscript<Challenger_>,
+	backend: &Backend,
+) -> Result<GrandProductBatchProveOutput<F>, Error>
+where
+	F: TowerField,
+	P: PackedField<Scalar = F> + PackedExtension<FDomain>,
+	FDomain: Field,
+	Challenger_: Challenger,
+	Backend: ComputationBackend,
+{
+	let witnesses = witnesses.into_iter().collect::<Vec<_>>();
+	if witnesses.len() != expected.len() {
+		bail!(Error::MismatchedWitnessClaimLength);
+	}
+
+	let claims = izip!(&witnesses, expected)
+		.enumerate()
+		.map(|(index, (witness, &product))| {
+			if witness.grand_product_evaluation() != product {
+				bail!(Error::PublicProductMismatch { index });
+			}
+			Ok(GrandProductClaim {
+				n_vars: witness.n_vars(),
+				product,
+			})
+		})
+		.collect::<Result<Vec<_>, Error>>()?;
+
+	transcript.observe().write_scalar_slice(expected);
+	batch_prove(
+		evaluation_order,
+		witnesses,
+		&claims,
+		evaluation_domain_factory,
+		transcript,
+		backend,
+	)
+}
+
+/// Proves grand product claims whose products are only known to the prover.
+///
+/// The products of the witnesses are written to the proof before proving, and are returned to
+/// the verifier by [`batch_verify_sent_products`](super::batch_verify_sent_products).
+#[instrument(skip_all, name = "gkr_gpa::batch_prove_sent_products", level = "debug")]
+pub fn batch_prove_sent_products<F, P, FDomain, Challenger_, Backend>(
+	evaluation_order: EvaluationOrder,
+	witnesses: impl IntoIterator<Item = GrandProductWitness<P>>,
+	evaluation_domain_factory: impl EvaluationDomainFactory<FDomain>,
+	transcript: &mut ProverTranscript<Challenger_>,
+	backend: &Backend,
+) -> Result<GrandProductBatchProveOutput<F>, Error>
+where
+	F: TowerField,
+	P: PackedField<Scalar = F> + PackedExtension<FDomain>,
+	FDomain: Field,
+	Challenger_: Challenger,
+	Backend: ComputationBackend,
+{
+	let witnesses = witnesses.into_iter().collect::<Vec<_>>();
+	let claims = witnesses
+		.iter()
+		.map(|witness| GrandProductClaim {
+			n_vars: witness.n_vars(),
+			product: witness.grand_product_evaluation(),
+		})
+		.collect::<Vec<_>>();
+
+	transcript
+		.message()
+		.write_scalar_iter(claims.iter().map(|claim| claim.product));
+	batch_prove(
+		evaluation_order,
+		witnesses,
+		&claims,
+		evaluation_domain_factory,
+		transcript,
+		backend,
+	)
+}
+
+fn prove_states<F, P, FDomain, Challenger_, Backend>(
+	evaluation_order: EvaluationOrder,
+	mut prover_states: Vec<GrandProductProverState<P>>,
+	claims: &[GrandProductClaim<F>],
+	evaluation_domain_factory: impl EvaluationDomainFactory<FDomain>,
+	transcript: &mut ProverTranscript<Challenger_>,
+	backend: &Backend,
+) -> Result<GrandProductBatchProveOutput<F>, Error>
+where
+	F: TowerField,
+	P: PackedField<Scalar = F> + PackedExtension<FDomain>,
+	FDomain: Field,
+	Challenger_: Challenger,
+	Backend: ComputationBackend,
+{
+	if prover_states.is_empty() {
+		return Ok(GrandProductBatchProveOutput {
+			final_layer_claims: vec![],
+		});
+	}
+
+	// A single claim needs neither sorting nor tracking of finished provers. The layer proofs are
+	// the same as in the general case, so the proof is accepted by `batch_verify`.
+	if prover_states.len() == 1 {
+		let state = prover_states.pop().expect("prover_states.len() == 1");
+		let final_layer_claim = prove_single_state(
+			evaluation_order,
+			state,
+			evaluation_domain_factory,
+			transcript,
+			backend,
+		)?;
+		return Ok(GrandProductBatchProveOutput {
+			final_layer_claims: vec![final_layer_claim],
+		});
+	}
+
 	let (original_indices, mut sorted_prover_states) =
 		stable_sort(prover_states, |state| state.remaining_layers.len(), true);
 
@@ -77,53 +437,14 @@ where
 		}
 
 		// Now we must create the batch layer proof for the kth to k+1th layer reduction
-
-		// Step 2: Create sumcheck batch proof
-		let BatchSumcheckOutput {
-			challenges,
-			multilinear_evals,
-		} = {
-			let _layer_span = tracing::info_span!(
-				"[task] GKR GPA Layer Sumcheck",
-				phase = "exp",
-				perfetto_category = "task.main"
-			)
-			.entered();
-
-			let eq_ind_sumcheck_prover = GrandProductProverState::stage_sumcheck_provers(
-				evaluation_order,
-				&mut sorted_prover_states,
-				evaluation_domain_factory.clone(),
-				&eval_point,
-				backend,
-			)?;
-
-			let batch_sumcheck_prover =
-				front_loaded::BatchProver::new(vec![eq_ind_sumcheck_prover], transcript)?;
-
-			let mut batch_output = batch_sumcheck_prover.run(transcript)?;
-
-			if evaluation_order == EvaluationOrder::HighToLow {
-				batch_output.challenges.reverse();
-			}
-
-			batch_output
-		};
-
-		// Step 3: Sample a challenge for the next layer
-		let gpa_challenge = transcript.sample();
-
-		eval_point.copy_from_slice(&challenges);
-		eval_point.push(gpa_challenge);
-
-		// Step 4: Finalize each prover to update its internal current_layer_claim
-		debug_assert_eq!(multilinear_evals.len(), 1);
-		let multilinear_evals = multilinear_evals
-			.first()
-			.expect("exactly one prover in a batch");
-		for (state, evals) in izip!(&mut sorted_prover_states, multilinear_evals.chunks_exact(2)) {
-			state.update_layer_eval(evals[0], evals[1], gpa_challenge);
-		}
+		prove_layer(
+			evaluation_order,
+			&mut sorted_prover_states,
+			evaluation_domain_factory.clone(),
+			&mut eval_point,
+			transcript,
+			backend,
+		)?;
 	}
 	process_finished_provers(
 		&mut sorted_prover_states,
@@ -141,8 +462,126 @@ where
 	Ok(GrandProductBatchProveOutput { final_layer_claims })
 }
 
+fn prove_single_state<F, P, FDomain, Challenger_, Backend>(
+	evaluation_order: EvaluationOrder,
+	mut state: GrandProductProverState<P>,
+	evaluation_domain_factory: impl EvaluationDomainFactory<FDomain>,
+	transcript: &mut ProverTranscript<Challenger_>,
+	backend: &Backend,
+) -> Result<LayerClaim<F>, Error>
+where
+	F: TowerField,
+	P: PackedField<Scalar = F> + PackedExtension<FDomain>,
+	FDomain: Field,
+	Challenger_: Challenger,
+	Backend: ComputationBackend,
+{
+	let mut eval_point = Vec::with_capacity(state.remaining_layers.len());
+	while !state.remaining_layers.is_empty() {
+		prove_layer(
+			evaluation_order,
+			slice::from_mut(&mut state),
+			evaluation_domain_factory.clone(),
+			&mut eval_point,
+			transcript,
+			backend,
+		)?;
+	}
+	state.finalize(&eval_point)
+}
+
+/// Proves the reduction from the current layer claims of `states` to claims on their next
+/// layers, extending `eval_point` by one coordinate.
+///
+/// All states must have at least one remaining layer.
+fn prove_layer<F, P, FDomain, Challenger_, Backend>(
+	evaluation_order: EvaluationOrder,
+	states: &mut [GrandProductProverState<P>],
+	evaluation_domain_factory: impl EvaluationDomainFactory<FDomain>,
+	eval_point: &mut Vec<F>,
+	transcript: &mut ProverTranscript<Challenger_>,
+	backend: &Backend,
+) -> Result<(), Error>
+where
+	F: TowerField,
+	P: PackedField<Scalar = F> + PackedExtension<FDomain>,
+	FDomain: Field,
+	Challenger_: Challenger,
+	Backend: ComputationBackend,
+{
+	// The layer sumcheck has one variable per reduced layer, and yields claims on the next layer.
+	let layer = eval_point.len();
+	let dimensions_data = GkrLayerDimensionsData::new(layer, states.len(), layer + 1);
+	let _layer_span = tracing::debug_span!(
+		"[step] GKR GPA Layer",
+		phase = "exp",
+		layer = layer,
+		perfetto_category = "phase.sub",
+		?dimensions_data,
+	)
+	.entered();
+
+	// Step 2: Create sumcheck batch proof
+	let BatchSumcheckOutput {
+		challenges,
+		multilinear_evals,
+	} = {
+		let _sumcheck_span = tracing::info_span!(
+			"[task] GKR GPA Layer Sumcheck",
+			phase = "exp",
+			layer = layer,
+			perfetto_category = "task.main",
+			?dimensions_data,
+		)
+		.entered();
+
+		let eq_ind_sumcheck_prover = GrandProductProverState::stage_sumcheck_provers(
+			evaluation_order,
+			states,
+			evaluation_domain_factory,
+			eval_point,
+			backend,
+		)?;
+
+		let batch_sumcheck_prover =
+			front_loaded::BatchProver::new(vec![eq_ind_sumcheck_prover], transcript)?;
+
+		let mut batch_output = batch_sumcheck_prover.run(transcript)?;
+
+		if evaluation_order == EvaluationOrder::HighToLow {
+			batch_output.challenges.reverse();
+		}
+
+		batch_output
+	};
+
+	// Step 3: Sample a challenge for the next layer
+	let gpa_challenge = transcript.sample();
+
+	eval_point.copy_from_slice(&challenges);
+	eval_point.push(gpa_challenge);
+
+	// Step 4: Finalize each prover to update its internal current_layer_claim
+	let _finalize_span = tracing::debug_span!(
+		"[task] GKR GPA Layer Finalize",
+		phase = "exp",
+		layer = layer,
+		perfetto_category = "task.main",
+	)
+	.entered();
+	debug_assert_eq!(multilinear_evals.len(), 1);
+	let multilinear_evals = multilinear_evals
+		.first()
+		.expect("exactly one prover in a batch");
+	for (state, evals) in izip!(states, multilinear_evals.chunks_exact(2)) {
+		state.update_layer_eval(evals[0], evals[1], gpa_challenge);
+	}
+
+	Ok(())
+}
+
 fn process_finished_provers<F, P>(
-	sorted_prover_states: &mut Vec<GrandProductProverState<P>>,
+	sorted_prover_states: &mut Vec<GrandProductProverState<'_, P>>,
 	reverse_sorted_final_layer_claims: &mut Vec<LayerClaim<F>>,
 	eval_point: &[F],
 ) -> Result<(), Error>
@@ -165,29 +604,48 @@ where
 /// Coordinates the proving of a grand product claim before and after
 /// the sumcheck-based layer reductions.
 #[derive(Debug)]
-struct GrandProductProverState<P>
+struct GrandProductProverState<'a, P>
 where
 	P: PackedField<Scalar: TowerField>,
 {
 	// Remaining layers of the product circuit, ordered from largest to smallest.
-	// Each step removes the last layer.
-	remaining_layers: Vec<Vec<P>>,
+	// Each step removes the last layer. Borrowed layers are copied when they are removed.
+	remaining_layers: Vec<Cow<'a, [P]>>,
 	// The current eval claim (on a shared eval point).
 	layer_eval: P::Scalar,
 }
 
-impl<F, P> GrandProductProverState<P>
+impl<'a, F, P> GrandProductProverState<'a, P>
 where
 	F: TowerField,
 	P: PackedField<Scalar = F>,
 {
 	/// Create a new GrandProductProverState
 	fn new(claim: &GrandProductClaim<F>, witness: GrandProductWitness<P>) -> Result<Self, Error> {
-		if claim.n_vars != witness.n_vars() || witness.grand_product_evaluation() != claim.product {
-			bail!(Error::ProverClaimWitnessMismatch);
-		}
+		check_claim_witness(claim, &witness)?;
+		let remaining_layers = witness
+			.into_circuit_layers()
+			.into_iter()
+			.map(Cow::Owned)
+			.collect();
+		Ok(Self::with_layers(claim, remaining_layers))
+	}
+
+	/// Create a new GrandProductProverState borrowing the witness layers
+	fn new_borrowed(
+		claim: &GrandProductClaim<F>,
+		witness: &'a GrandProductWitness<P>,
+	) -> Result<Self, Error> {
+		check_claim_witness(claim, witness)?;
+		let remaining_layers = witness
+			.circuit_layers()
+			.iter()
+			.map(|layer| Cow::Borrowed(layer.as_slice()))
+			.collect();
+		Ok(Self::with_layers(claim, remaining_layers))
+	}
 
-		let mut remaining_layers = witness.into_circuit_layers();
+	fn with_layers(claim: &GrandProductClaim<F>, mut remaining_layers: Vec<Cow<'a, [P]>>) -> Self {
 		debug_assert_eq!(remaining_layers.len(), claim.n_vars + 1);
 		let _ = remaining_layers
 			.pop()
@@ -197,21 +655,21 @@ where
 		let layer_eval = claim.product;
 
 		// Return new GrandProductProver and the common product
-		Ok(Self {
+		Self {
 			remaining_layers,
 			layer_eval,
-		})
+		}
 	}
 
 	#[allow(clippy::type_complexity)]
 	#[instrument(skip_all, level = "debug")]
-	fn stage_sumcheck_provers<'a, FDomain, Backend>(
+	fn stage_sumcheck_provers<'b, FDomain, Backend>(
 		evaluation_order: EvaluationOrder,
 		states: &mut [Self],
 		evaluation_domain_factory: impl EvaluationDomainFactory<FDomain>,
 		eq_ind_challenges: &[P::Scalar],
-		backend: &'a Backend,
-	) -> Result<impl SumcheckProver<P::Scalar> + 'a, Error>
+		backend: &'b Backend,
+	) -> Result<impl SumcheckProver<P::Scalar> + 'b, Error>
 	where
 		FDomain: Field,
 		P: PackedExtension<FDomain>,
@@ -238,7 +696,8 @@ where
 			let layer = state
 				.remaining_layers
 				.pop()
-				.expect("not staging more than n_vars times");
+				.expect("not staging more than n_vars times")
+				.into_owned();
 
 			let multilinear_pair =
 				if n_vars >= P::LOG_WIDTH && layer.len() < 1 << (n_vars - P::LOG_WIDTH) {
@@ -294,3 +753,17 @@ where
 		})
 	}
 }
+
+fn check_claim_witness<F, P>(
+	claim: &GrandProductClaim<F>,
+	witness: &GrandProductWitness<P>,
+) -> Result<(), Error>
+where
+	F: TowerField,
+	P: PackedField<Scalar = F>,
+{
+	if claim.n_vars != witness.n_vars() || witness.grand_product_evaluation() != claim.product {
+		bail!(Error::ProverClaimWitnessMismatch);
+	}
+	Ok(())
+}
@@ -0,0 +1,136 @@
+// Copyright 2025 Irreducible Inc.
+
+use std::marker::PhantomData;
+
+use binius_field::{Field, PackedExtension, PackedField, TowerField};
+use binius_hal::ComputationBackend;
+use binius_math::{EvaluationDomainFactory, EvaluationOrder};
+use bytes::BufMut;
+
+use super::{
+	Error, GrandProductBatchProveOutput, GrandProductClaim, GrandProductWitness, LayerClaim,
+	batch_prove, batch_verify,
+};
+use crate::{
+	fiat_shamir::Challenger,
+	transcript::{ProverTranscript, TranscriptWriter, VerifierTranscript, write_u64},
+};
+
+/// Observes the batch index and the claims of a batch, separating it from the batches before it.
+fn observe_batch<F: TowerField, B: BufMut>(
+	mut observer: TranscriptWriter<B>,
+	batch_index: usize,
+	claims: &[GrandProductClaim<F>],
+) {
+	write_u64(&mut observer, batch_index as u64);
+	write_u64(&mut observer, claims.len() as u64);
+	for claim in claims {
+		write_u64(&mut observer, claim.n_vars as u64);
+		observer.write_scalar(claim.product);
+	}
+}
+
+/// Proves several independent batches of grand product claims into a single transcript.
+///
+/// Each batch is proven with [`batch_prove`] after observing its index and claims, so the
+/// challenges of a batch depend on every batch proven before it, and one proof covers them all.
+/// The batches must be verified in the same order with a [`ProductVerifierSession`].
+#[derive(Debug)]
+pub struct ProductSession<'a, Challenger_, FDomain, DomainFactory, Backend>
+where
+	FDomain: Field,
+	DomainFactory: EvaluationDomainFactory<FDomain>,
+{
+	transcript: &'a mut ProverTranscript<Challenger_>,
+	evaluation_domain_factory: DomainFactory,
+	backend: &'a Backend,
+	n_batches: usize,
+	_marker: PhantomData<FDomain>,
+}
+
+impl<'a, Challenger_, FDomain, DomainFactory, Backend>
+	ProductSession<'a, Challenger_, FDomain, DomainFactory, Backend>
+where
+	Challenger_: Challenger,
+	FDomain: Field,
+	DomainFactory: EvaluationDomainFactory<FDomain>,
+	Backend: ComputationBackend,
+{
+	pub fn new(
+		transcript: &'a mut ProverTranscript<Challenger_>,
+		evaluation_domain_factory: DomainFactory,
+		backend: &'a Backend,
+	) -> Self {
+		Self {
+			transcript,
+			evaluation_domain_factory,
+			backend,
+			n_batches: 0,
+			_marker: PhantomData,
+		}
+	}
+
+	/// The number of batches proven so far.
+	pub const fn n_batches(&self) -> usize {
+		self.n_batches
+	}
+
+	/// Proves the next batch of grand product claims.
+	///
+	/// The requirements on `witnesses` and `claims` are those of [`batch_prove`].
+	pub fn add_batch<F, P>(
+		&mut self,
+		witnesses: impl IntoIterator<Item = GrandProductWitness<P>>,
+		claims: &[GrandProductClaim<F>],
+		evaluation_order: EvaluationOrder,
+	) -> Result<GrandProductBatchProveOutput<F>, Error>
+	where
+		F: TowerField,
+		P: PackedField<Scalar = F> + PackedExtension<FDomain>,
+	{
+		observe_batch(self.transcript.observe(), self.n_batches, claims);
+		let output = batch_prove(
+			evaluation_order,
+			witnesses,
+			claims,
+			self.evaluation_domain_factory.clone(),
+			self.transcript,
+			self.backend,
+		)?;
+		self.n_batches += 1;
+		Ok(output)
+	}
+}
+
+/// Verifies batches of grand product claims proven with a [`ProductSession`].
+#[derive(Debug)]
+pub struct ProductVerifierSession<'a, Challenger_> {
+	transcript: &'a mut VerifierTranscript<Challenger_>,
+	n_batches: usize,
+}
+
+impl<'a, Challenger_: Challenger> ProductVerifierSession<'a, Challenger_> {
+	pub const fn new(transcript: &'a mut VerifierTranscript<Challenger_>) -> Self {
+		Self {
+			transcript,
+			n_batches: 0,
+		}
+	}
+
+	/// The number of batches verified so far.
+	pub const fn n_batches(&self) -> usize {
+		self.n_batches
+	}
+
+	/// Verifies the next batch of grand product claims.
+	pub fn add_batch<F: TowerField>(
+		&mut self,
+		claims: &[GrandProductClaim<F>],
+		evaluation_order: EvaluationOrder,
+	) -> Result<Vec<LayerClaim<F>>, Error> {
+		observe_batch(self.transcript.observe(), self.n_batches, claims);
+		let layer_claims = batch_verify(evaluation_order, claims.to_vec(), self.transcript)?;
+		self.n_batches += 1;
+		Ok(layer_claims)
+	}
+}
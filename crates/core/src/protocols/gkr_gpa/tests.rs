@@ -1,26 +1,54 @@
 // Copyright 2024-2025 Irreducible Inc.
 
-use std::iter::repeat_with;
+use std::{
+	io::{self, Read},
+	iter::repeat_with,
+	slice,
+	sync::{Arc, Mutex},
+};
 
 use binius_field::{
-	BinaryField32b, BinaryField128b, Field, PackedExtension, PackedField, PackedFieldIndexable,
-	RepackedExtension, TowerField,
+	BinaryField, BinaryField1b, BinaryField8b, BinaryField32b, BinaryField64b, BinaryField128b,
+	Field, PackedExtension, PackedField, PackedFieldIndexable, RepackedExtension, TowerField,
 	arch::{OptimalUnderlier256b, OptimalUnderlier512b},
 	as_packed_field::{PackScalar, PackedType},
-	packed::set_packed_slice,
+	packed::{get_packed_slice, len_packed_slice, set_packed_slice},
 	underlier::{UnderlierType, WithUnderlier},
 };
 use binius_hash::groestl::Groestl256;
-use binius_math::{EvaluationOrder, IsomorphicEvaluationDomainFactory, MultilinearExtension};
+use binius_macros::composition;
+use binius_math::{
+	CompositionPoly, DefaultEvaluationDomainFactory, EvaluationDomain, EvaluationDomainFactory,
+	EvaluationOrder, IsomorphicEvaluationDomainFactory, MultilinearExtension, MultilinearQuery,
+};
+use binius_utils::{DeserializeBytes, SerializationMode, SerializeBytes};
 use bytemuck::zeroed_vec;
-use rand::{SeedableRng, rngs::StdRng};
+use itertools::{Itertools, izip};
+use rand::{Rng, SeedableRng, rngs::StdRng};
 
-use super::{GrandProductClaim, GrandProductWitness};
+use super::{
+	Error, GpaProver, GpaWitnessConfig, GrandProductClaim, GrandProductPartition,
+	GrandProductWitness, LayerClaim, MixedWitness, PackedGpaProver, ProductSession,
+	ProductVerifierSession, batch_prove_mixed, batch_prove_partitioned, proof_schema,
+	prove_composite_product, transcript_schema, try_parse_proof, verify_single,
+};
 use crate::{
 	fiat_shamir::HasherChallenger,
 	oracle::MultilinearOracleSet,
-	protocols::gkr_gpa::{GrandProductBatchProveOutput, batch_prove, batch_verify},
-	transcript::ProverTranscript,
+	parallelism,
+	protocols::{
+		gkr_gpa::{
+			GrandProductBatchProveOutput, batch_prove, batch_prove_public_products,
+			batch_prove_ref, batch_prove_sent_products, batch_verify, batch_verify_many,
+			batch_verify_public_products, batch_verify_sent_products,
+		},
+		sumcheck::{Error as SumcheckError, VerificationError},
+	},
+	test_utils::ProofSizeGuard,
+	transcript::{
+		Error as TranscriptError, ProverTranscript, ReadTape, TRANSCRIPT_FORMAT_VERSION,
+		TranscriptOpKind, VerifierTranscript, diff,
+	},
 	witness::MultilinearExtensionIndex,
 };
 
@@ -130,10 +158,10 @@ where
 	let witness_index = MultilinearExtensionIndex::<PackedType<U, F>>::new();
 	let mut claims = Vec::new();
 	let mut witnesses = Vec::new();
-	let domain_factory = IsomorphicEvaluationDomainFactory::<FS>::default();
-	let backend = binius_hal::make_portable_backend();
 
-	// Setup
+	// Setup. The claims with 4 variables come before the larger claims with 7 variables, so the
+	// prover has to reorder the claims by size and join the smaller ones into the batched layer
+	// sumchecks only for their last layers.
 	let (n_vars, n_multilins) = (5, 2);
 	let CreateClaimsWitnessesOutput {
 		new_claims,
@@ -176,31 +204,76 @@ where
 	// Prove and Verify
 	let _ = (oracle_set, witness_index, rng);
 
+	prove_and_verify::<P, FS>(evaluation_order, &claims, witnesses);
+}
+
+/// Proves the claims on a fresh transcript, verifies the proof, and returns it.
+///
+/// Checks that the prover and the verifier agree on the final layer claims, and that each final
+/// layer claim is the evaluation of the input layer of its witness.
+fn prove_and_verify<P, FS>(
+	evaluation_order: EvaluationOrder,
+	claims: &[GrandProductClaim<P::Scalar>],
+	witnesses: Vec<GrandProductWitness<P>>,
+) -> Vec<u8>
+where
+	P: PackedExtension<FS, Scalar: TowerField>,
+	FS: TowerField,
+{
+	prove_and_verify_with_domain_factory(
+		evaluation_order,
+		claims,
+		witnesses,
+		IsomorphicEvaluationDomainFactory::<FS>::default(),
+	)
+}
+
+fn prove_and_verify_with_domain_factory<P, FS>(
+	evaluation_order: EvaluationOrder,
+	claims: &[GrandProductClaim<P::Scalar>],
+	witnesses: Vec<GrandProductWitness<P>>,
+	domain_factory: impl EvaluationDomainFactory<FS>,
+) -> Vec<u8>
+where
+	P: PackedExtension<FS, Scalar: TowerField>,
+	FS: TowerField,
+{
+	let inputs = izip!(claims, &witnesses)
+		.map(|(claim, witness)| {
+			MultilinearExtension::new(claim.n_vars, witness.circuit_layers()[0].clone()).unwrap()
+		})
+		.collect::<Vec<_>>();
+
 	let mut prover_transcript = ProverTranscript::<HasherChallenger<Groestl256>>::new();
 	let GrandProductBatchProveOutput { final_layer_claims } = batch_prove::<_, _, FS, _, _>(
 		evaluation_order,
 		witnesses,
-		&claims,
+		claims,
 		domain_factory,
 		&mut prover_transcript,
-		&backend,
+		&binius_hal::make_portable_backend(),
 	)
 	.unwrap();
+	let proof = prover_transcript.finalize();
 
-	let mut verify_transcript = prover_transcript.into_verifier();
-	let verified_evalcheck_multilinear_claims =
-		batch_verify(evaluation_order, claims.clone(), &mut verify_transcript).unwrap();
+	let mut verifier_transcript =
+		VerifierTranscript::<HasherChallenger<Groestl256>>::try_new(proof.clone()).unwrap();
+	let verified_claims =
+		batch_verify(evaluation_order, claims.to_vec(), &mut verifier_transcript).unwrap();
+	verifier_transcript.finalize().unwrap();
 
-	assert_eq!(final_layer_claims.len(), verified_evalcheck_multilinear_claims.len());
-	for (proved_eval_claim, verified_layer_claim) in final_layer_claims
-		.iter()
-		.zip(verified_evalcheck_multilinear_claims.iter())
+	assert_eq!(final_layer_claims.len(), claims.len());
+	assert_eq!(verified_claims.len(), claims.len());
+	for (claim, proved, verified, input) in
+		izip!(claims, &final_layer_claims, &verified_claims, &inputs)
 	{
-		// Evaluations match
-		assert_eq!(proved_eval_claim.eval, verified_layer_claim.eval);
-		// Evaluation Points match
-		assert_eq!(proved_eval_claim.eval_point, verified_layer_claim.eval_point);
+		assert_eq!(proved.eval, verified.eval);
+		assert_eq!(proved.eval_point, verified.eval_point);
+		assert_eq!(verified.eval_point.len(), claim.n_vars);
+		let query = MultilinearQuery::<P::Scalar>::expand(&verified.eval_point);
+		assert_eq!(input.evaluate(&query).unwrap(), verified.eval);
 	}
+	proof
 }
 
 #[test]
@@ -232,3 +305,1537 @@ fn test_prove_verify_batch_512b() {
 
 	run_prove_verify_batch_test::<U, F, FS, P>();
 }
+
+#[test]
+fn test_prove_verify_batch_64b() {
+	type F = BinaryField64b;
+	type U = OptimalUnderlier256b;
+	type P = PackedType<U, F>;
+	type FS = BinaryField8b;
+
+	run_prove_verify_batch_test::<U, F, FS, P>();
+}
+
+fn generate_batch<P, F>(
+	rng: &mut StdRng,
+	n_vars: usize,
+	n_multilins: usize,
+) -> (Vec<GrandProductClaim<F>>, Vec<GrandProductWitness<P>>)
+where
+	P: PackedExtension<F, Scalar = F>,
+	F: Field,
+{
+	generate_poly_helper::<P, F>(rng, n_vars, n_multilins)
+		.into_iter()
+		.map(|(mle, product)| {
+			let claim = GrandProductClaim { n_vars, product };
+			let witness = GrandProductWitness::new(n_vars, mle.into_evals()).unwrap();
+			(claim, witness)
+		})
+		.unzip()
+}
+
+fn verify_session(
+	transcript: &mut VerifierTranscript<HasherChallenger<Groestl256>>,
+	batches: &[Vec<GrandProductClaim<BinaryField128b>>],
+	evaluation_order: EvaluationOrder,
+) -> Result<Vec<Vec<LayerClaim<BinaryField128b>>>, Error> {
+	let mut session = ProductVerifierSession::new(transcript);
+	batches
+		.iter()
+		.map(|claims| session.add_batch(claims, evaluation_order))
+		.collect()
+}
+
+#[test]
+fn test_product_session_two_batches() {
+	type F = BinaryField128b;
+	type P = PackedType<OptimalUnderlier256b, F>;
+	type FS = BinaryField32b;
+
+	let evaluation_order = EvaluationOrder::HighToLow;
+	let mut rng = StdRng::seed_from_u64(0);
+	let domain_factory = IsomorphicEvaluationDomainFactory::<FS>::default();
+	let backend = binius_hal::make_portable_backend();
+
+	let (claims_1, witnesses_1) = generate_batch::<P, F>(&mut rng, 5, 2);
+	let (claims_2, witnesses_2) = generate_batch::<P, F>(&mut rng, 4, 3);
+
+	let mut prover_transcript = ProverTranscript::<HasherChallenger<Groestl256>>::new();
+	let mut session =
+		ProductSession::<_, FS, _, _>::new(&mut prover_transcript, domain_factory, &backend);
+	let output_1 = session
+		.add_batch(witnesses_1, &claims_1, evaluation_order)
+		.unwrap();
+	let output_2 = session
+		.add_batch(witnesses_2, &claims_2, evaluation_order)
+		.unwrap();
+	assert_eq!(session.n_batches(), 2);
+
+	let batches = [claims_1, claims_2];
+	let proof = prover_transcript.finalize();
+
+	let mut verifier_transcript = VerifierTranscript::new(proof.clone());
+	let verified = verify_session(&mut verifier_transcript, &batches, evaluation_order).unwrap();
+	verifier_transcript.finalize().unwrap();
+
+	for (proved, verified) in [output_1, output_2].iter().zip(&verified) {
+		assert_eq!(proved.final_layer_claims.len(), verified.len());
+		for (proved_claim, verified_claim) in proved.final_layer_claims.iter().zip(verified) {
+			assert_eq!(proved_claim.eval, verified_claim.eval);
+			assert_eq!(proved_claim.eval_point, verified_claim.eval_point);
+		}
+	}
+
+	// After the version header, the proof starts with the layer evaluations of the first batch,
+	// which the verifier checks against the claimed products.
+	let mut tampered_proof = proof;
+	tampered_proof[1] ^= 1;
+	let mut verifier_transcript = VerifierTranscript::new(tampered_proof);
+	assert!(matches!(
+		verify_session(&mut verifier_transcript, &batches, evaluation_order),
+		Err(Error::SumcheckError(SumcheckError::Verification(
+			VerificationError::IncorrectBatchEvaluation
+		)))
+	));
+}
+
+#[test]
+fn test_batch_prove_ref_matches_batch_prove() {
+	type F = BinaryField128b;
+	type P = PackedType<OptimalUnderlier256b, F>;
+	type FS = BinaryField32b;
+
+	let mut rng = StdRng::seed_from_u64(0);
+	let domain_factory = IsomorphicEvaluationDomainFactory::<FS>::default();
+	let backend = binius_hal::make_portable_backend();
+
+	let (mut claims, mut witnesses) = generate_batch::<P, F>(&mut rng, 6, 2);
+	let (more_claims, more_witnesses) = generate_batch::<P, F>(&mut rng, 1, 3);
+	claims.extend(more_claims);
+	witnesses.extend(more_witnesses);
+
+	for evaluation_order in [EvaluationOrder::LowToHigh, EvaluationOrder::HighToLow] {
+		let mut ref_transcript = ProverTranscript::<HasherChallenger<Groestl256>>::new();
+		let ref_output = batch_prove_ref::<_, _, FS, _, _>(
+			evaluation_order,
+			&witnesses,
+			&claims,
+			domain_factory.clone(),
+			&mut ref_transcript,
+			&backend,
+		)
+		.unwrap();
+
+		let mut transcript = ProverTranscript::<HasherChallenger<Groestl256>>::new();
+		let output = batch_prove::<_, _, FS, _, _>(
+			evaluation_order,
+			witnesses.clone(),
+			&claims,
+			domain_factory.clone(),
+			&mut transcript,
+			&backend,
+		)
+		.unwrap();
+
+		assert_eq!(ref_transcript.finalize(), transcript.finalize());
+		for (ref_claim, claim) in ref_output
+			.final_layer_claims
+			.iter()
+			.zip(&output.final_layer_claims)
+		{
+			assert_eq!(ref_claim.eval, claim.eval);
+			assert_eq!(ref_claim.eval_point, claim.eval_point);
+		}
+	}
+}
+
+#[test]
+fn test_partitioned_proof_matches_monolithic_proof() {
+	type F = BinaryField128b;
+	type P = PackedType<OptimalUnderlier256b, F>;
+	type FS = BinaryField32b;
+
+	let mut rng = StdRng::seed_from_u64(0);
+	let domain_factory = IsomorphicEvaluationDomainFactory::<FS>::default();
+	let backend = binius_hal::make_portable_backend();
+
+	let (mut claims, mut witnesses) = generate_batch::<P, F>(&mut rng, 6, 4);
+	let (more_claims, more_witnesses) = generate_batch::<P, F>(&mut rng, 3, 4);
+	claims.extend(more_claims);
+	witnesses.extend(more_witnesses);
+
+	let mut transcript = ProverTranscript::<HasherChallenger<Groestl256>>::new();
+	let output = batch_prove::<_, _, FS, _, _>(
+		EvaluationOrder::HighToLow,
+		witnesses.clone(),
+		&claims,
+		domain_factory.clone(),
+		&mut transcript,
+		&backend,
+	)
+	.unwrap();
+
+	// Each worker serializes its partition, as if sending it to the coordinator over the network.
+	let partitions = vec![vec![0, 4], vec![5, 1], vec![2, 6], vec![7, 3]];
+	let gather_partition = |_, indices: &[usize]| {
+		let partition = GrandProductPartition::new(
+			indices.iter().map(|&i| claims[i].clone()).collect(),
+			indices.iter().map(|&i| witnesses[i].clone()),
+		);
+		let mut bytes = Vec::new();
+		partition
+			.serialize(&mut bytes, SerializationMode::CanonicalTower)
+			.unwrap();
+		Ok(GrandProductPartition::deserialize(bytes.as_slice(), SerializationMode::CanonicalTower)
+			.unwrap())
+	};
+
+	let mut partitioned_transcript = ProverTranscript::<HasherChallenger<Groestl256>>::new();
+	let partitioned_output = batch_prove_partitioned::<_, P, FS, _, _>(
+		EvaluationOrder::HighToLow,
+		&partitions,
+		gather_partition,
+		domain_factory.clone(),
+		&mut partitioned_transcript,
+		&backend,
+	)
+	.unwrap();
+
+	assert_eq!(partitioned_transcript.finalize(), transcript.finalize());
+	for (partitioned_claim, claim) in
+		izip!(&partitioned_output.final_layer_claims, &output.final_layer_claims)
+	{
+		assert_eq!(partitioned_claim.eval, claim.eval);
+		assert_eq!(partitioned_claim.eval_point, claim.eval_point);
+	}
+
+	let mut transcript = ProverTranscript::<HasherChallenger<Groestl256>>::new();
+	let result = batch_prove_partitioned::<_, P, FS, _, _>(
+		EvaluationOrder::HighToLow,
+		&[vec![0, 1], vec![1, 2]],
+		gather_partition,
+		domain_factory.clone(),
+		&mut transcript,
+		&backend,
+	);
+	assert!(matches!(result, Err(Error::InvalidPartitionAssignment { index: 1 })));
+
+	// A worker sending circuit layers of the wrong shape is rejected before proving.
+	let gather_truncated_partition = |i, indices: &[usize]| {
+		let mut partition = gather_partition(i, indices)?;
+		partition.circuit_layers[0].pop();
+		Ok(partition)
+	};
+	let mut transcript = ProverTranscript::<HasherChallenger<Groestl256>>::new();
+	let result = batch_prove_partitioned::<_, P, FS, _, _>(
+		EvaluationOrder::HighToLow,
+		&partitions,
+		gather_truncated_partition,
+		domain_factory,
+		&mut transcript,
+		&backend,
+	);
+	assert!(matches!(result, Err(Error::InvalidCircuitEvals)));
+}
+
+#[test]
+fn test_batch_prove_mixed_source_fields() {
+	type F = BinaryField128b;
+	type P = PackedType<OptimalUnderlier256b, F>;
+	type FDomain = BinaryField32b;
+
+	fn mixed_input<FS: TowerField>(
+		rng: &mut StdRng,
+		n_vars: usize,
+	) -> (MixedWitness<P>, GrandProductClaim<F>)
+	where
+		OptimalUnderlier256b: PackScalar<FS>,
+		F: From<FS>,
+	{
+		let values = repeat_with(|| FS::random(&mut *rng))
+			.take(1 << n_vars)
+			.collect::<Vec<_>>();
+		let claim = GrandProductClaim {
+			n_vars,
+			product: values.iter().product::<FS>(),
+		};
+		let input_layer = values
+			.chunks(PackedType::<OptimalUnderlier256b, FS>::WIDTH)
+			.map(|chunk| {
+				PackedType::<OptimalUnderlier256b, FS>::from_scalars(chunk.iter().copied())
+			})
+			.collect();
+		(MixedWitness::new(n_vars, input_layer), claim.isomorphic())
+	}
+
+	let mut rng = StdRng::seed_from_u64(0);
+	let (inputs, claims): (Vec<_>, Vec<_>) = [
+		mixed_input::<BinaryField8b>(&mut rng, 6),
+		mixed_input::<BinaryField32b>(&mut rng, 6),
+		mixed_input::<BinaryField8b>(&mut rng, 3),
+		mixed_input::<BinaryField32b>(&mut rng, 4),
+	]
+	.into_iter()
+	.unzip();
+	assert_eq!(
+		inputs
+			.iter()
+			.map(MixedWitness::source_tower_level)
+			.collect::<Vec<_>>(),
+		[3, 5, 3, 5]
+	);
+
+	let backend = binius_hal::make_portable_backend();
+	let mut prover_transcript = ProverTranscript::<HasherChallenger<Groestl256>>::new();
+	let GrandProductBatchProveOutput { final_layer_claims } =
+		batch_prove_mixed::<_, _, FDomain, _, _>(
+			EvaluationOrder::HighToLow,
+			inputs,
+			&claims,
+			IsomorphicEvaluationDomainFactory::<FDomain>::default(),
+			&mut prover_transcript,
+			&backend,
+		)
+		.unwrap();
+
+	let mut verifier_transcript = prover_transcript.into_verifier();
+	let verified_claims =
+		batch_verify(EvaluationOrder::HighToLow, claims, &mut verifier_transcript).unwrap();
+	verifier_transcript.finalize().unwrap();
+	assert_eq!(final_layer_claims.len(), verified_claims.len());
+	for (proved_claim, verified_claim) in izip!(&final_layer_claims, &verified_claims) {
+		assert_eq!(proved_claim.eval, verified_claim.eval);
+		assert_eq!(proved_claim.eval_point, verified_claim.eval_point);
+	}
+}
+
+#[test]
+fn test_n_gkr_layers_matches_proof() {
+	type F = BinaryField128b;
+	type P = PackedType<OptimalUnderlier256b, F>;
+	type FDomain = BinaryField32b;
+
+	let mut rng = StdRng::seed_from_u64(0);
+	let backend = binius_hal::make_portable_backend();
+	for n_vars in [0, 1, 3, 6] {
+		let (claims, witnesses) = generate_batch::<P, F>(&mut rng, n_vars, 1);
+		let n_gkr_layers = claims[0].n_gkr_layers();
+		assert_eq!(witnesses[0].circuit_layers().len(), n_gkr_layers + 1);
+
+		let mut transcript = ProverTranscript::<HasherChallenger<Groestl256>>::new();
+		let GrandProductBatchProveOutput { final_layer_claims } =
+			batch_prove::<_, _, FDomain, _, _>(
+				EvaluationOrder::HighToLow,
+				witnesses,
+				&claims,
+				IsomorphicEvaluationDomainFactory::<FDomain>::default(),
+				&mut transcript,
+				&backend,
+			)
+			.unwrap();
+
+		// Every layer reduction appends one coordinate to the evaluation point.
+		assert_eq!(final_layer_claims[0].eval_point.len(), n_gkr_layers);
+	}
+}
+
+#[test]
+fn test_try_parse_proof() {
+	type F = BinaryField128b;
+	type P = PackedType<OptimalUnderlier256b, F>;
+	type FDomain = BinaryField32b;
+
+	let mut rng = StdRng::seed_from_u64(0);
+	let (mut claims, mut witnesses) = generate_batch::<P, F>(&mut rng, 4, 2);
+	let (more_claims, more_witnesses) = generate_batch::<P, F>(&mut rng, 2, 1);
+	claims.extend(more_claims);
+	witnesses.extend(more_witnesses);
+	let claim_n_vars = claims.iter().map(|claim| claim.n_vars).collect::<Vec<_>>();
+	let proof = prove_and_verify::<P, FDomain>(EvaluationOrder::HighToLow, &claims, witnesses);
+
+	let structure = try_parse_proof::<F>(&claim_n_vars, &proof).unwrap();
+	assert_eq!(structure.layers.len(), 4);
+	for (layer_no, layer) in structure.layers.iter().enumerate() {
+		assert_eq!(layer.round_coeffs.len(), layer_no);
+		let n_active_claims = if layer_no < 2 { 3 } else { 2 };
+		assert_eq!(layer.multilinear_evals.len(), 2 * n_active_claims + 1);
+	}
+
+	assert!(try_parse_proof::<F>(&claim_n_vars, &proof[..proof.len() - 1]).is_err());
+	let mut long_proof = proof.clone();
+	long_proof.push(0);
+	assert!(try_parse_proof::<F>(&claim_n_vars, &long_proof).is_err());
+	assert!(try_parse_proof::<F>(&[], &proof[..1]).is_ok());
+	assert!(try_parse_proof::<F>(&[], &[]).is_err());
+}
+
+#[test]
+fn test_proof_schema_diff() {
+	type F = BinaryField128b;
+	type P = PackedType<OptimalUnderlier256b, F>;
+	type FDomain = BinaryField32b;
+
+	let mut rng = StdRng::seed_from_u64(0);
+	let (mut claims, mut witnesses) = generate_batch::<P, F>(&mut rng, 2, 1);
+	let (more_claims, more_witnesses) = generate_batch::<P, F>(&mut rng, 3, 2);
+	claims.extend(more_claims);
+	witnesses.extend(more_witnesses);
+	let claim_n_vars = claims.iter().map(|claim| claim.n_vars).collect::<Vec<_>>();
+	let proof = prove_and_verify::<P, FDomain>(EvaluationOrder::HighToLow, &claims, witnesses);
+
+	let schema = proof_schema::<F>(&claim_n_vars).unwrap();
+	assert_eq!(schema.proof_len(), proof.len());
+	assert_eq!(diff(&proof, &proof, &schema), None);
+
+	// The claim with the fewest variables comes first in the batch but last in every layer.
+	let labels = schema
+		.fields()
+		.iter()
+		.map(|field| field.label.as_str())
+		.collect::<Vec<_>>();
+	let layer_1_evals = labels
+		.iter()
+		.position(|&label| label == "gkr_gpa layer 1 evals of claim 1")
+		.unwrap();
+	assert_eq!(
+		labels[layer_1_evals..layer_1_evals + 4],
+		[
+			"gkr_gpa layer 1 evals of claim 1",
+			"gkr_gpa layer 1 evals of claim 2",
+			"gkr_gpa layer 1 evals of claim 0",
+			"gkr_gpa layer 1 eq indicator eval",
+		]
+	);
+
+	let field_start = schema.fields()[..layer_1_evals + 2]
+		.iter()
+		.map(|field| field.len)
+		.sum::<usize>();
+	let mut corrupted = proof.clone();
+	corrupted[field_start + 3] ^= 1;
+	let difference = diff(&proof, &corrupted, &schema).unwrap();
+	assert_eq!(difference.byte_offset, field_start + 3);
+	assert_eq!(difference.label, "gkr_gpa layer 1 evals of claim 0");
+}
+
+#[test]
+fn test_transcript_schema_matches_proof() {
+	type F = BinaryField128b;
+	type P = PackedType<OptimalUnderlier256b, F>;
+	type FDomain = BinaryField32b;
+
+	let mut rng = StdRng::seed_from_u64(0);
+	let (mut claims, mut witnesses) = generate_batch::<P, F>(&mut rng, 2, 1);
+	let (more_claims, more_witnesses) = generate_batch::<P, F>(&mut rng, 3, 2);
+	claims.extend(more_claims);
+	witnesses.extend(more_witnesses);
+	let claim_n_vars = claims.iter().map(|claim| claim.n_vars).collect::<Vec<_>>();
+
+	let mut transcript =
+		ProverTranscript::<HasherChallenger<Groestl256>>::new().with_operation_log();
+	batch_prove::<_, _, FDomain, _, _>(
+		EvaluationOrder::HighToLow,
+		witnesses,
+		&claims,
+		IsomorphicEvaluationDomainFactory::<FDomain>::default(),
+		&mut transcript,
+		&binius_hal::make_portable_backend(),
+	)
+	.unwrap();
+	// The operation log is only recorded in builds with `debug_assertions`.
+	#[cfg(debug_assertions)]
+	let ops = transcript
+		.operation_log()
+		.iter()
+		.map(|op| (op.kind, op.bytes.len()))
+		.collect::<Vec<_>>();
+	let proof = transcript.finalize();
+
+	let schema = transcript_schema::<F>(&claim_n_vars);
+	assert!(
+		schema
+			.iter()
+			.all(|field| field.tower_level == F::TOWER_LEVEL)
+	);
+	#[cfg(debug_assertions)]
+	assert_eq!(
+		schema
+			.iter()
+			.map(|field| (field.kind, field.n_bytes))
+			.collect::<Vec<_>>(),
+		ops
+	);
+
+	// Parsing the proof after the version byte with the schema consumes it exactly.
+	let mut bytes = &proof[1..];
+	for field in &schema {
+		if field.kind == TranscriptOpKind::Message {
+			let (value, rest) = bytes.split_at(field.n_bytes);
+			F::deserialize(value, SerializationMode::CanonicalTower).unwrap();
+			bytes = rest;
+		}
+	}
+	assert!(bytes.is_empty());
+
+	assert!(transcript_schema::<F>(&[]).is_empty());
+}
+
+#[test]
+fn test_try_parse_proof_never_panics() {
+	let mut rng = StdRng::seed_from_u64(0);
+	for _ in 0..1000 {
+		let n_claims = rng.gen_range(0..5);
+		let claim_n_vars = repeat_with(|| match rng.gen_range(0..10) {
+			0 => usize::MAX,
+			n_vars => n_vars,
+		})
+		.take(n_claims)
+		.collect::<Vec<_>>();
+		let n_bytes = rng.gen_range(0..1024);
+		let mut bytes = repeat_with(|| rng.r#gen::<u8>())
+			.take(n_bytes)
+			.collect::<Vec<_>>();
+		// Most proofs get past the version header.
+		if let Some(version) = bytes.first_mut().filter(|_| rng.gen_bool(0.9)) {
+			*version = TRANSCRIPT_FORMAT_VERSION;
+		}
+
+		// Returning at all, with either result, is the property under test.
+		let _ = try_parse_proof::<BinaryField128b>(&claim_n_vars, &bytes);
+		let _ = try_parse_proof::<BinaryField8b>(&claim_n_vars, &bytes);
+	}
+}
+
+/// An evaluation domain factory that records the size of every domain it creates.
+#[derive(Clone, Default)]
+struct LoggingEvaluationDomainFactory<F: BinaryField> {
+	inner: DefaultEvaluationDomainFactory<F>,
+	created_sizes: Arc<Mutex<Vec<usize>>>,
+}
+
+impl<F: BinaryField> EvaluationDomainFactory<F> for LoggingEvaluationDomainFactory<F> {
+	fn create(&self, size: usize) -> Result<EvaluationDomain<F>, binius_math::Error> {
+		self.created_sizes.lock().unwrap().push(size);
+		self.inner.create(size)
+	}
+}
+
+#[test]
+fn test_batch_prove_is_generic_over_domain_factory() {
+	type F = BinaryField128b;
+	type P = PackedType<OptimalUnderlier256b, F>;
+	type FS = BinaryField32b;
+
+	fn prove(
+		claims: &[GrandProductClaim<F>],
+		witnesses: &[GrandProductWitness<P>],
+		domain_factory: impl EvaluationDomainFactory<FS>,
+	) -> Vec<u8> {
+		let mut transcript = ProverTranscript::<HasherChallenger<Groestl256>>::new();
+		batch_prove::<_, _, FS, _, _>(
+			EvaluationOrder::HighToLow,
+			witnesses.to_vec(),
+			claims,
+			domain_factory,
+			&mut transcript,
+			&binius_hal::make_portable_backend(),
+		)
+		.unwrap();
+		transcript.finalize()
+	}
+
+	let mut rng = StdRng::seed_from_u64(0);
+	let (claims, witnesses) = generate_batch::<P, F>(&mut rng, 5, 3);
+
+	let isomorphic_proof =
+		prove(&claims, &witnesses, IsomorphicEvaluationDomainFactory::<FS>::default());
+	let default_proof = prove(&claims, &witnesses, DefaultEvaluationDomainFactory::<FS>::default());
+	let logging_factory = LoggingEvaluationDomainFactory::<FS>::default();
+	let logging_proof = prove(&claims, &witnesses, logging_factory.clone());
+
+	assert!(!logging_factory.created_sizes.lock().unwrap().is_empty());
+	assert_eq!(isomorphic_proof, default_proof);
+	assert_eq!(isomorphic_proof, logging_proof);
+}
+
+#[test]
+fn test_dyn_prover_matches_batch_prove() {
+	type F = BinaryField128b;
+	type P = PackedType<OptimalUnderlier256b, F>;
+	type FS = BinaryField32b;
+	type Challenger = HasherChallenger<Groestl256>;
+
+	fn dyn_prover(log_width: usize) -> Box<dyn GpaProver<F, Challenger>> {
+		let backend = binius_hal::make_portable_backend();
+		match log_width {
+			1 => Box::new(PackedGpaProver::<P, FS, _>::new(backend)),
+			_ => Box::new(PackedGpaProver::<PackedType<OptimalUnderlier512b, F>, FS, _>::new(
+				backend,
+			)),
+		}
+	}
+
+	let mut rng = StdRng::seed_from_u64(0);
+	let (claims, witnesses) = generate_batch::<P, F>(&mut rng, 5, 3);
+	let inputs = witnesses
+		.iter()
+		.map(|witness| {
+			let input = P::iter_slice(witness.numerator()).collect::<Vec<_>>();
+			(witness.n_vars(), input)
+		})
+		.collect::<Vec<_>>();
+
+	let mut transcript = ProverTranscript::<Challenger>::new();
+	batch_prove::<_, _, FS, _, _>(
+		EvaluationOrder::HighToLow,
+		witnesses,
+		&claims,
+		DefaultEvaluationDomainFactory::<FS>::default(),
+		&mut transcript,
+		&binius_hal::make_portable_backend(),
+	)
+	.unwrap();
+	let expected_proof = transcript.finalize();
+
+	for log_width in [1, 2] {
+		let prover = dyn_prover(log_width);
+		let mut transcript = ProverTranscript::<Challenger>::new();
+		prover
+			.batch_prove(EvaluationOrder::HighToLow, inputs.clone(), &claims, &mut transcript)
+			.unwrap();
+		assert_eq!(transcript.finalize(), expected_proof);
+	}
+}
+
+#[test]
+fn test_prove_composite_product_matches_materialized() {
+	type F = BinaryField128b;
+	type P = PackedType<OptimalUnderlier256b, F>;
+	type FS = BinaryField8b;
+
+	let composition = composition!(v0 * v1 + v2 * 125);
+	let mut rng = StdRng::seed_from_u64(0);
+	for n_vars in [0, 5] {
+		let mut oracles = MultilinearOracleSet::<F>::new();
+		let inner_ids = oracles.add_committed_multiple::<3>(n_vars, F::TOWER_LEVEL);
+		let composite_id = oracles
+			.add_composite_mle(n_vars, inner_ids, CompositionPoly::<F>::expression(&composition))
+			.unwrap();
+
+		let mut witness_index = MultilinearExtensionIndex::<P>::new();
+		let inner = inner_ids.map(|_| {
+			let evals = repeat_with(|| P::random(&mut rng))
+				.take(1 << n_vars.saturating_sub(P::LOG_WIDTH))
+				.collect::<Vec<_>>();
+			MultilinearExtension::new(n_vars, evals)
+				.unwrap()
+				.specialize_arc_dyn()
+		});
+		witness_index
+			.update_multilin_poly(izip!(inner_ids, inner.clone()))
+			.unwrap();
+
+		let column = witness_index.materialize(&oracles[composite_id]).unwrap();
+		let product = P::iter_slice(column.evals())
+			.take(1 << n_vars)
+			.product::<F>();
+		let claim = GrandProductClaim { n_vars, product };
+
+		let mut transcript = ProverTranscript::<HasherChallenger<Groestl256>>::new();
+		batch_prove::<_, _, FS, _, _>(
+			EvaluationOrder::HighToLow,
+			[GrandProductWitness::new(n_vars, column.into_evals()).unwrap()],
+			slice::from_ref(&claim),
+			DefaultEvaluationDomainFactory::<FS>::default(),
+			&mut transcript,
+			&binius_hal::make_portable_backend(),
+		)
+		.unwrap();
+		let expected_proof = transcript.finalize();
+
+		let mut transcript = ProverTranscript::<HasherChallenger<Groestl256>>::new();
+		prove_composite_product::<_, _, FS, _, _, _>(
+			EvaluationOrder::HighToLow,
+			n_vars,
+			composition,
+			inner.to_vec(),
+			&claim,
+			DefaultEvaluationDomainFactory::<FS>::default(),
+			&mut transcript,
+			&binius_hal::make_portable_backend(),
+		)
+		.unwrap();
+		let proof = transcript.finalize();
+		assert_eq!(proof, expected_proof);
+
+		let mut transcript = VerifierTranscript::<HasherChallenger<Groestl256>>::new(proof);
+		batch_verify(EvaluationOrder::HighToLow, [claim], &mut transcript).unwrap();
+		transcript.finalize().unwrap();
+	}
+}
+
+#[test]
+fn test_composite_input_layer_pads_with_one() {
+	type F = BinaryField128b;
+	type P = PackedType<OptimalUnderlier512b, F>;
+	type PS = PackedType<OptimalUnderlier512b, BinaryField8b>;
+
+	let composition = composition!(v0 * v1 + v2 * 125);
+	let mut rng = StdRng::seed_from_u64(0);
+	// The packed type has four lanes, so the smaller circuits leave lanes beyond the hypercube.
+	for n_vars in [0usize, 1, 2, 13] {
+		let packed_inner = repeat_with(|| {
+			let evals = repeat_with(|| P::random(&mut rng))
+				.take(1 << n_vars.saturating_sub(P::LOG_WIDTH))
+				.collect::<Vec<_>>();
+			MultilinearExtension::new(n_vars, evals)
+				.unwrap()
+				.specialize_arc_dyn::<P>()
+		})
+		.take(2)
+		.collect::<Vec<_>>();
+		// A subfield witness has no packed evaluations, so its evaluations are copied by chunk.
+		let subfield_evals = repeat_with(|| PS::random(&mut rng))
+			.take(1 << n_vars.saturating_sub(PS::LOG_WIDTH))
+			.collect::<Vec<_>>();
+		let subfield_inner = MultilinearExtension::new(n_vars, subfield_evals)
+			.unwrap()
+			.specialize_arc_dyn::<P>();
+		let inner = packed_inner
+			.into_iter()
+			.chain([subfield_inner])
+			.collect::<Vec<_>>();
+
+		let input_layer =
+			super::prove::composite_input_layer(n_vars, composition, inner.clone()).unwrap();
+		assert_eq!(input_layer.len(), 1 << n_vars.saturating_sub(P::LOG_WIDTH));
+		for (index, value) in P::iter_slice(&input_layer).enumerate() {
+			let expected = if index < 1 << n_vars {
+				let query = inner
+					.iter()
+					.map(|poly| poly.evaluate_on_hypercube(index).unwrap())
+					.collect::<Vec<_>>();
+				CompositionPoly::<F>::evaluate(&composition, &query).unwrap()
+			} else {
+				F::ONE
+			};
+			assert_eq!(value, expected);
+		}
+	}
+}
+
+#[test]
+fn test_layer_domain_size() {
+	type F = BinaryField128b;
+	type P = PackedType<OptimalUnderlier256b, F>;
+	type FS = BinaryField32b;
+
+	let mut rng = StdRng::seed_from_u64(0);
+	let (claims, witnesses) = generate_batch::<P, F>(&mut rng, 5, 3);
+
+	let domain_factory = LoggingEvaluationDomainFactory::<FS>::default();
+	prove_and_verify_with_domain_factory::<P, FS>(
+		EvaluationOrder::HighToLow,
+		&claims,
+		witnesses,
+		domain_factory.clone(),
+	);
+
+	// The layer sumchecks multiply the bivariate product with the equality indicator. The verifier
+	// reads the eq-ind round degree, 3 coefficients, per round, which pins the interpolation
+	// domain without reading its size from the proof.
+	let created_sizes = domain_factory.created_sizes.lock().unwrap().clone();
+	assert!(!created_sizes.is_empty());
+	assert!(created_sizes.iter().all(|&size| size == 3));
+}
+
+#[test]
+fn test_batch_verify_wrong_round_degree() {
+	type F = BinaryField128b;
+	type P = PackedType<OptimalUnderlier256b, F>;
+	type FS = BinaryField32b;
+	type Challenger = HasherChallenger<Groestl256>;
+
+	let mut rng = StdRng::seed_from_u64(0);
+	let (claims, witnesses) = generate_batch::<P, F>(&mut rng, 5, 3);
+	let claim_n_vars = claims.iter().map(|claim| claim.n_vars).collect::<Vec<_>>();
+	let proof = prove_and_verify::<P, FS>(EvaluationOrder::HighToLow, &claims, witnesses);
+	let structure = try_parse_proof::<F>(&claim_n_vars, &proof).unwrap();
+
+	// Re-encode the proof with one coefficient too few or too many in every layer round, as a
+	// prover interpolating over a domain of the wrong size would write it.
+	let reencode = |resize_round: &dyn Fn(&mut Vec<F>)| {
+		let mut bytes = proof[..1].to_vec();
+		for layer in &structure.layers {
+			for coeffs in &layer.round_coeffs {
+				let mut coeffs = coeffs.clone();
+				resize_round(&mut coeffs);
+				for coeff in coeffs {
+					coeff
+						.serialize(&mut bytes, SerializationMode::CanonicalTower)
+						.unwrap();
+				}
+			}
+			for eval in &layer.multilinear_evals {
+				eval.serialize(&mut bytes, SerializationMode::CanonicalTower)
+					.unwrap();
+			}
+		}
+		bytes
+	};
+
+	let mut verifier_transcript = VerifierTranscript::<Challenger>::new(reencode(&|_| {}));
+	batch_verify(EvaluationOrder::HighToLow, claims.clone(), &mut verifier_transcript).unwrap();
+	verifier_transcript.finalize().unwrap();
+
+	for bad_proof in [
+		reencode(&|coeffs| {
+			coeffs.pop();
+		}),
+		reencode(&|coeffs| coeffs.push(F::ZERO)),
+	] {
+		let mut verifier_transcript = VerifierTranscript::<Challenger>::new(bad_proof);
+		assert!(
+			batch_verify(EvaluationOrder::HighToLow, claims.clone(), &mut verifier_transcript)
+				.is_err()
+		);
+	}
+}
+
+#[test]
+fn test_prove_verify_single_claim() {
+	type F = BinaryField128b;
+	type P = PackedType<OptimalUnderlier256b, F>;
+	type FS = BinaryField32b;
+
+	let mut rng = StdRng::seed_from_u64(0);
+	for n_vars in [0, 1, 7] {
+		for evaluation_order in [EvaluationOrder::LowToHigh, EvaluationOrder::HighToLow] {
+			let (claims, witnesses) = generate_batch::<P, F>(&mut rng, n_vars, 1);
+			prove_and_verify::<P, FS>(evaluation_order, &claims, witnesses);
+		}
+	}
+}
+
+#[test]
+fn test_prove_verify_empty_batch() {
+	type F = BinaryField128b;
+	type P = PackedType<OptimalUnderlier256b, F>;
+	type FS = BinaryField32b;
+
+	let domain_factory = IsomorphicEvaluationDomainFactory::<FS>::default();
+	let backend = binius_hal::make_portable_backend();
+
+	for evaluation_order in [EvaluationOrder::LowToHigh, EvaluationOrder::HighToLow] {
+		let mut prover_transcript = ProverTranscript::<HasherChallenger<Groestl256>>::new();
+		let mark = prover_transcript.position();
+		let GrandProductBatchProveOutput { final_layer_claims } = batch_prove::<F, P, FS, _, _>(
+			evaluation_order,
+			[],
+			&[],
+			domain_factory.clone(),
+			&mut prover_transcript,
+			&backend,
+		)
+		.unwrap();
+		assert!(final_layer_claims.is_empty());
+		assert_eq!(prover_transcript.position(), mark);
+
+		let mut verifier_transcript = prover_transcript.into_verifier();
+		let verified_claims =
+			batch_verify::<F, _>(evaluation_order, [], &mut verifier_transcript).unwrap();
+		assert!(verified_claims.is_empty());
+		verifier_transcript.finalize().unwrap();
+	}
+}
+
+#[test]
+fn test_proof_size_16_vars() {
+	type F = BinaryField128b;
+	type P = PackedType<OptimalUnderlier256b, F>;
+	type FS = BinaryField32b;
+
+	// The version byte followed by 408 field elements of 16 bytes.
+	const PROOF_SIZE_GUARD: ProofSizeGuard = ProofSizeGuard::new("gkr_gpa 16 vars", 6529);
+
+	let mut rng = StdRng::seed_from_u64(0);
+	let (claims, witnesses) = generate_batch::<P, F>(&mut rng, 16, 1);
+	let proof = prove_and_verify::<P, FS>(EvaluationOrder::HighToLow, &claims, witnesses);
+
+	PROOF_SIZE_GUARD.assert(proof.len());
+}
+
+#[test]
+fn test_prove_verify_smallest_domain_field() {
+	type F = BinaryField128b;
+	type P = PackedType<OptimalUnderlier256b, F>;
+	type FS = BinaryField1b;
+
+	let mut rng = StdRng::seed_from_u64(0);
+	let (claims, witnesses) = generate_batch::<P, F>(&mut rng, 4, 2);
+	prove_and_verify::<P, FS>(EvaluationOrder::HighToLow, &claims, witnesses);
+}
+
+#[test]
+fn test_single_threaded_proof_is_identical() {
+	type F = BinaryField128b;
+	type P = PackedType<OptimalUnderlier256b, F>;
+	type FS = BinaryField32b;
+
+	let mut rng = StdRng::seed_from_u64(0);
+	let (claims, witnesses) = generate_batch::<P, F>(&mut rng, 10, 4);
+
+	let prove = |single_threaded| {
+		parallelism::install_single_threaded(single_threaded, || {
+			prove_and_verify::<P, FS>(EvaluationOrder::HighToLow, &claims, witnesses.clone())
+		})
+	};
+
+	assert_eq!(prove(true), prove(false));
+}
+
+/// A reader returning at most 64 bytes per read.
+struct ChunkedReader<'a>(&'a [u8]);
+
+impl Read for ChunkedReader<'_> {
+	fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+		let n = buf.len().min(self.0.len()).min(64);
+		buf[..n].copy_from_slice(&self.0[..n]);
+		self.0 = &self.0[n..];
+		Ok(n)
+	}
+}
+
+#[test]
+fn test_batch_verify_streamed_proof() {
+	type F = BinaryField128b;
+	type P = PackedType<OptimalUnderlier256b, F>;
+	type FS = BinaryField32b;
+	type Challenger = HasherChallenger<Groestl256>;
+
+	let mut rng = StdRng::seed_from_u64(0);
+	let evaluation_order = EvaluationOrder::HighToLow;
+
+	let (claims, witnesses) = generate_batch::<P, F>(&mut rng, 8, 3);
+	let proof = prove_and_verify::<P, FS>(evaluation_order, &claims, witnesses);
+
+	let mut buffered_transcript = VerifierTranscript::<Challenger>::new(proof.clone());
+	let buffered_claims =
+		batch_verify(evaluation_order, claims.clone(), &mut buffered_transcript).unwrap();
+	buffered_transcript.finalize().unwrap();
+
+	// Stream the proof both through the default window and through a window much smaller than
+	// the proof, which is refilled many times.
+	for tape in [
+		ReadTape::new(ChunkedReader(&proof), proof.len()),
+		ReadTape::with_window(ChunkedReader(&proof), proof.len(), 128),
+	] {
+		let mut streamed_transcript =
+			VerifierTranscript::<Challenger, _>::from_read_tape(tape).unwrap();
+		let streamed_claims =
+			batch_verify(evaluation_order, claims.clone(), &mut streamed_transcript).unwrap();
+		streamed_transcript.finalize().unwrap();
+
+		for (buffered, streamed) in izip!(&buffered_claims, &streamed_claims) {
+			assert_eq!(buffered.eval, streamed.eval);
+			assert_eq!(buffered.eval_point, streamed.eval_point);
+		}
+	}
+
+	let truncated = &proof[..proof.len() - 1];
+	let mut truncated_transcript =
+		VerifierTranscript::<Challenger, _>::from_reader(ChunkedReader(truncated), proof.len())
+			.unwrap();
+	let result = batch_verify(evaluation_order, claims, &mut truncated_transcript);
+	assert!(matches!(
+		result,
+		Err(Error::SumcheckError(SumcheckError::TranscriptError(
+			TranscriptError::ProofUnexpectedlyShort {
+				needed: Some(16),
+				available: 15
+			}
+		)))
+	));
+
+	assert!(matches!(
+		VerifierTranscript::<Challenger, _>::from_reader(ChunkedReader(&[]), 0),
+		Err(TranscriptError::ProofUnexpectedlyShort {
+			needed: Some(1),
+			available: 0
+		})
+	));
+}
+
+#[test]
+fn test_batch_verify_many() {
+	type F = BinaryField128b;
+	type P = PackedType<OptimalUnderlier256b, F>;
+	type FS = BinaryField32b;
+	type Challenger = HasherChallenger<Groestl256>;
+
+	const N_PROOFS: usize = 3;
+
+	let evaluation_order = EvaluationOrder::HighToLow;
+	let mut rng = StdRng::seed_from_u64(0);
+
+	let (claims, proofs): (Vec<_>, Vec<_>) = (0..N_PROOFS)
+		.map(|i| {
+			let (claims, witnesses) = generate_batch::<P, F>(&mut rng, 3 + i, 2);
+			let proof = prove_and_verify::<P, FS>(evaluation_order, &claims, witnesses);
+			(claims, proof)
+		})
+		.unzip();
+
+	let verify = |proofs: Vec<Vec<u8>>| {
+		let proofs = izip!(claims.clone(), proofs)
+			.map(|(claims, proof)| (claims, VerifierTranscript::<Challenger>::new(proof)));
+		batch_verify_many(evaluation_order, proofs)
+	};
+
+	let final_layer_claims = verify(proofs.clone()).unwrap();
+	for (claims, proof, final_layer_claims) in izip!(&claims, &proofs, final_layer_claims) {
+		let mut transcript = VerifierTranscript::<Challenger>::new(proof.clone());
+		let expected = batch_verify(evaluation_order, claims.clone(), &mut transcript).unwrap();
+		assert_eq!(final_layer_claims.len(), expected.len());
+		for (claim, expected) in izip!(final_layer_claims, expected) {
+			assert_eq!(claim.eval_point, expected.eval_point);
+			assert_eq!(claim.eval, expected.eval);
+		}
+	}
+
+	for i in 0..N_PROOFS {
+		let mut corrupted = proofs.clone();
+		let last = corrupted[i].len() - 1;
+		corrupted[i][last] ^= 1;
+		assert!(matches!(
+			verify(corrupted),
+			Err(Error::BatchProofInvalid { index, .. }) if index == i
+		));
+	}
+}
+
+#[test]
+fn test_batch_verify_truncated_proof() {
+	type F = BinaryField128b;
+	type P = PackedType<OptimalUnderlier256b, F>;
+	type FS = BinaryField32b;
+	type Challenger = HasherChallenger<Groestl256>;
+
+	let mut rng = StdRng::seed_from_u64(0);
+	let evaluation_order = EvaluationOrder::HighToLow;
+	let (claims, witnesses) = generate_batch::<P, F>(&mut rng, 4, 2);
+	let proof = prove_and_verify::<P, FS>(evaluation_order, &claims, witnesses);
+
+	// The proof ends with the evaluations of the last layer sumcheck, so each truncation fails on
+	// the read of the last evaluation, which is a 16-byte scalar. A streamed proof that is shorter
+	// than its declared length fails the same way.
+	for (truncated_len, available) in [(5, 11), (16, 0)] {
+		let truncated = &proof[..proof.len() - truncated_len];
+		let mut verifier_transcript = VerifierTranscript::<Challenger>::new(truncated.to_vec());
+		let result = batch_verify(evaluation_order, claims.clone(), &mut verifier_transcript);
+		assert!(matches!(
+			result,
+			Err(Error::SumcheckError(SumcheckError::TranscriptError(
+				TranscriptError::ProofUnexpectedlyShort { needed: Some(16), available: actual }
+			))) if actual == available
+		));
+
+		let mut streamed_transcript =
+			VerifierTranscript::<Challenger, _>::from_reader(truncated, proof.len()).unwrap();
+		let result = batch_verify(evaluation_order, claims.clone(), &mut streamed_transcript);
+		assert!(matches!(
+			result,
+			Err(Error::SumcheckError(SumcheckError::TranscriptError(
+				TranscriptError::ProofUnexpectedlyShort { needed: Some(16), available: actual }
+			))) if actual == available
+		));
+	}
+}
+
+#[test]
+fn test_weighted_grand_product() {
+	type F = BinaryField128b;
+	type P = PackedType<OptimalUnderlier256b, F>;
+
+	let mut rng = StdRng::seed_from_u64(0);
+	for (n_vars, input_len) in [(5, 1 << 4), (5, 3), (0, 1)] {
+		let values = repeat_with(|| P::random(&mut rng))
+			.take(input_len)
+			.collect::<Vec<_>>();
+		let exponents = (0..input_len * P::WIDTH)
+			.map(|i| (i % 7) as u32)
+			.collect::<Vec<_>>();
+
+		let witness =
+			GrandProductWitness::new_weighted(n_vars, values.clone(), exponents.clone()).unwrap();
+
+		let mut expected = F::ONE;
+		for (value, &exponent) in izip!(P::iter_slice(&values), &exponents).take(1 << n_vars) {
+			for _ in 0..exponent {
+				expected *= value;
+			}
+		}
+		assert_eq!(witness.grand_product_evaluation(), expected);
+	}
+
+	assert!(matches!(
+		GrandProductWitness::new_weighted(2, vec![P::one()], vec![1; P::WIDTH + 1]),
+		Err(Error::MismatchedExponentsLength { .. })
+	));
+}
+
+#[test]
+fn test_numerator() {
+	type F = BinaryField128b;
+	type P = PackedType<OptimalUnderlier256b, F>;
+
+	let mut rng = StdRng::seed_from_u64(0);
+	for (n_vars, input_len) in [(5, 1 << 4), (5, 3), (0, 1)] {
+		let input_layer = repeat_with(|| P::random(&mut rng))
+			.take(input_len)
+			.collect::<Vec<_>>();
+		let witness = GrandProductWitness::new(n_vars, input_layer.clone()).unwrap();
+		assert_eq!(witness.numerator(), input_layer.as_slice());
+		assert_eq!(witness.numerator(), witness.circuit_layers()[0].as_slice());
+	}
+
+	let values = repeat_with(|| P::random(&mut rng))
+		.take(2)
+		.collect::<Vec<_>>();
+	let exponents = (0..2 * P::WIDTH)
+		.map(|i| (i % 3) as u32)
+		.collect::<Vec<_>>();
+	let witness = GrandProductWitness::new_weighted(4, values.clone(), exponents.clone()).unwrap();
+	for (i, (value, &exponent)) in izip!(P::iter_slice(&values), &exponents).enumerate() {
+		let mut power = F::ONE;
+		for _ in 0..exponent {
+			power *= value;
+		}
+		assert_eq!(get_packed_slice(witness.numerator(), i), power);
+	}
+}
+
+#[test]
+fn test_prefix_products() {
+	type F = BinaryField128b;
+	type P = PackedType<OptimalUnderlier256b, F>;
+
+	let mut rng = StdRng::seed_from_u64(0);
+	for (n_vars, input_len) in [(6, 1 << 5), (6, 7), (0, 1), (1, 1), (13, 3000)] {
+		let input_layer = repeat_with(|| P::random(&mut rng))
+			.take(input_len)
+			.collect::<Vec<_>>();
+		let witness = GrandProductWitness::new(n_vars, input_layer.clone()).unwrap();
+
+		let prefix_products = witness.prefix_products();
+		assert_eq!(prefix_products.len(), 1 << n_vars.saturating_sub(P::LOG_WIDTH));
+		assert_eq!(
+			get_packed_slice(&prefix_products, (1 << n_vars) - 1),
+			witness.grand_product_evaluation()
+		);
+
+		let input = |i| {
+			if i < len_packed_slice(&input_layer) {
+				get_packed_slice(&input_layer, i)
+			} else {
+				F::ONE
+			}
+		};
+		assert_eq!(get_packed_slice(&prefix_products, 0), input(0));
+		for i in 0..(1 << n_vars) - 1 {
+			assert_eq!(
+				get_packed_slice(&prefix_products, i) * input(i + 1),
+				get_packed_slice(&prefix_products, i + 1)
+			);
+		}
+	}
+}
+
+#[test]
+fn test_new_batch_matches_new() {
+	type F = BinaryField128b;
+	type P = PackedType<OptimalUnderlier256b, F>;
+
+	let mut rng = StdRng::seed_from_u64(0);
+	let inputs = [(6, 20), (1, 1), (10, 1 << 8), (4, 0)]
+		.map(|(n_vars, len)| {
+			let input_layer = repeat_with(|| P::random(&mut rng))
+				.take(len)
+				.collect::<Vec<_>>();
+			(n_vars, input_layer)
+		})
+		.to_vec();
+
+	let witnesses = GrandProductWitness::new_batch(inputs.clone()).unwrap();
+	assert_eq!(witnesses.len(), inputs.len());
+	for (witness, (n_vars, input_layer)) in izip!(witnesses, inputs) {
+		let expected = GrandProductWitness::new(n_vars, input_layer).unwrap();
+		assert_eq!(witness.circuit_layers(), expected.circuit_layers());
+	}
+
+	assert!(GrandProductWitness::<P>::new_batch(vec![(0, vec![P::one(); 2])]).is_err());
+}
+
+#[test]
+fn test_new_batch_splits_by_work() {
+	type F = BinaryField128b;
+	type P = PackedType<OptimalUnderlier256b, F>;
+
+	let mut rng = StdRng::seed_from_u64(0);
+	let inputs = [(12, 1 << 11), (3, 4), (5, 16), (4, 0), (2, 2)]
+		.map(|(n_vars, len)| {
+			let input_layer = repeat_with(|| P::random(&mut rng))
+				.take(len)
+				.collect::<Vec<_>>();
+			(n_vars, input_layer)
+		})
+		.to_vec();
+	let expected = inputs
+		.iter()
+		.map(|(n_vars, input_layer)| {
+			GrandProductWitness::new(*n_vars, input_layer.clone()).unwrap()
+		})
+		.collect::<Vec<_>>();
+
+	// A single thread has the whole input as its share, so every witness is constructed
+	// serially in the parallel iterator. With more threads than input elements, the share is
+	// empty and every non-empty witness is constructed with parallel layers. With four threads,
+	// only the first witness exceeds the share.
+	for n_threads in [1, usize::MAX, 4] {
+		let witnesses =
+			GrandProductWitness::new_batch_with_threads(inputs.clone(), n_threads).unwrap();
+		assert_eq!(witnesses.len(), expected.len());
+		for (witness, expected) in izip!(&witnesses, &expected) {
+			assert_eq!(witness.circuit_layers(), expected.circuit_layers());
+		}
+	}
+
+	for n_threads in [1, usize::MAX] {
+		assert!(
+			GrandProductWitness::<P>::new_batch_with_threads(
+				vec![(0, vec![P::one(); 2])],
+				n_threads
+			)
+			.is_err()
+		);
+	}
+}
+
+#[test]
+fn test_from_circuit_layers_validates_shape() {
+	type F = BinaryField128b;
+	type P = PackedType<OptimalUnderlier256b, F>;
+
+	let mut rng = StdRng::seed_from_u64(0);
+	let n_vars = 6;
+	let input_layer = repeat_with(|| P::random(&mut rng))
+		.take(20)
+		.collect::<Vec<_>>();
+	let witness = GrandProductWitness::new(n_vars, input_layer).unwrap();
+	let layers = witness.circuit_layers().to_vec();
+
+	let rebuilt = GrandProductWitness::from_circuit_layers(n_vars, layers.clone()).unwrap();
+	assert_eq!(rebuilt.circuit_layers(), witness.circuit_layers());
+
+	let mut missing_layer = layers.clone();
+	missing_layer.pop();
+	assert!(matches!(
+		GrandProductWitness::from_circuit_layers(n_vars, missing_layer),
+		Err(Error::InvalidCircuitEvals)
+	));
+	assert!(matches!(
+		GrandProductWitness::<P>::from_circuit_layers(n_vars, Vec::new()),
+		Err(Error::InvalidCircuitEvals)
+	));
+
+	let mut short_layer = layers.clone();
+	short_layer[1].pop();
+	assert!(matches!(
+		GrandProductWitness::from_circuit_layers(n_vars, short_layer),
+		Err(Error::InvalidCircuitEvals)
+	));
+
+	let mut long_input = layers;
+	long_input[0].resize(1 << (n_vars - P::LOG_WIDTH + 1), P::one());
+	assert!(matches!(
+		GrandProductWitness::from_circuit_layers(n_vars, long_input),
+		Err(Error::InvalidCircuitEvals)
+	));
+}
+
+#[test]
+fn test_identity_prefix_witness() {
+	type F = BinaryField128b;
+	type P = PackedType<OptimalUnderlier256b, F>;
+
+	let mut rng = StdRng::seed_from_u64(0);
+	for (n_vars, input_len, identity_count) in [
+		(10, 1 << 9, 1 << 9),
+		(10, 1 << 9, 301),
+		(10, 300, 600),
+		(3, 4, 3),
+		(1, 1, 2),
+	] {
+		let input_layer = (0..input_len << P::LOG_WIDTH)
+			.map(|i| {
+				if i < identity_count {
+					F::ONE
+				} else {
+					<F as Field>::random(&mut rng)
+				}
+			})
+			.chunks(P::WIDTH)
+			.into_iter()
+			.map(P::from_scalars)
+			.collect::<Vec<_>>();
+
+		let witness = GrandProductWitness::new_with_identity_prefix(
+			n_vars,
+			input_layer.clone(),
+			identity_count,
+		)
+		.unwrap();
+		let naive_product = P::iter_slice(&input_layer).take(1 << n_vars).product::<F>();
+		assert_eq!(witness.grand_product_evaluation(), naive_product);
+
+		let expected = GrandProductWitness::new(n_vars, input_layer).unwrap();
+		assert_eq!(witness.circuit_layers(), expected.circuit_layers());
+	}
+
+	let input_layer = vec![P::one(), P::from_scalars([F::ONE, F::new(2)])];
+	assert!(matches!(
+		GrandProductWitness::new_with_identity_prefix(2, input_layer, 4),
+		Err(Error::IdentityPrefixMismatch { index: 3 })
+	));
+}
+
+#[test]
+fn test_witness_config_matches_default() {
+	type F = BinaryField128b;
+	type P = PackedType<OptimalUnderlier256b, F>;
+
+	let mut rng = StdRng::seed_from_u64(0);
+	for (n_vars, input_len) in [(10, 1 << 9), (10, 300), (3, 1)] {
+		let input_layer = repeat_with(|| P::random(&mut rng))
+			.take(input_len)
+			.collect::<Vec<_>>();
+		let witness = GrandProductWitness::new(n_vars, input_layer.clone()).unwrap();
+
+		for par_chunk_log_size in [0, 2, 5, 20] {
+			let config = GpaWitnessConfig { par_chunk_log_size };
+			let configured_witness =
+				GrandProductWitness::new_with_config(n_vars, input_layer.clone(), config).unwrap();
+			assert_eq!(configured_witness.circuit_layers(), witness.circuit_layers());
+		}
+	}
+}
+
+#[test]
+fn test_prove_verify_public_products() {
+	type F = BinaryField128b;
+	type P = PackedType<OptimalUnderlier256b, F>;
+	type FS = BinaryField32b;
+
+	let evaluation_order = EvaluationOrder::HighToLow;
+	let mut rng = StdRng::seed_from_u64(0);
+	let domain_factory = IsomorphicEvaluationDomainFactory::<FS>::default();
+	let backend = binius_hal::make_portable_backend();
+
+	let (claims, witnesses) = generate_batch::<P, F>(&mut rng, 5, 3);
+	let n_vars = claims.iter().map(|claim| claim.n_vars).collect::<Vec<_>>();
+	let expected = claims.iter().map(|claim| claim.product).collect::<Vec<_>>();
+
+	// Products that are only known to the prover are sent before proving.
+	let mut sent_transcript = ProverTranscript::<HasherChallenger<Groestl256>>::new();
+	let sent_output = batch_prove_sent_products::<_, _, FS, _, _>(
+		evaluation_order,
+		witnesses.clone(),
+		domain_factory.clone(),
+		&mut sent_transcript,
+		&backend,
+	)
+	.unwrap();
+	let sent_proof = sent_transcript.finalize();
+
+	let mut public_transcript = ProverTranscript::<HasherChallenger<Groestl256>>::new();
+	let output = batch_prove_public_products::<_, _, FS, _, _>(
+		evaluation_order,
+		&expected,
+		witnesses.clone(),
+		domain_factory.clone(),
+		&mut public_transcript,
+		&backend,
+	)
+	.unwrap();
+	let public_proof = public_transcript.finalize();
+	assert_eq!(public_proof.len() + expected.len() * 16, sent_proof.len());
+
+	let mut verifier_transcript =
+		VerifierTranscript::<HasherChallenger<Groestl256>>::new(sent_proof);
+	let (sent_products, verified_claims) =
+		batch_verify_sent_products(evaluation_order, &n_vars, &mut verifier_transcript).unwrap();
+	verifier_transcript.finalize().unwrap();
+	assert_eq!(sent_products, expected);
+	for (proved_claim, verified_claim) in
+		sent_output.final_layer_claims.iter().zip(&verified_claims)
+	{
+		assert_eq!(proved_claim.eval, verified_claim.eval);
+		assert_eq!(proved_claim.eval_point, verified_claim.eval_point);
+	}
+
+	let mut verifier_transcript =
+		VerifierTranscript::<HasherChallenger<Groestl256>>::new(public_proof.clone());
+	let verified_claims = batch_verify_public_products(
+		evaluation_order,
+		&n_vars,
+		&expected,
+		&mut verifier_transcript,
+	)
+	.unwrap();
+	verifier_transcript.finalize().unwrap();
+	for (proved_claim, verified_claim) in output.final_layer_claims.iter().zip(&verified_claims) {
+		assert_eq!(proved_claim.eval, verified_claim.eval);
+		assert_eq!(proved_claim.eval_point, verified_claim.eval_point);
+	}
+
+	// The public products are bound to the challenges, so other products do not verify.
+	let mut other_expected = expected.clone();
+	other_expected[0] += F::ONE;
+	let mut verifier_transcript =
+		VerifierTranscript::<HasherChallenger<Groestl256>>::new(public_proof);
+	let result = batch_verify_public_products(
+		evaluation_order,
+		&n_vars,
+		&other_expected,
+		&mut verifier_transcript,
+	);
+	assert!(matches!(
+		result,
+		Err(Error::SumcheckError(SumcheckError::Verification(
+			VerificationError::IncorrectBatchEvaluation
+		)))
+	));
+
+	let mut wrong_expected = expected;
+	wrong_expected[1] += F::ONE;
+	let mut transcript = ProverTranscript::<HasherChallenger<Groestl256>>::new();
+	let result = batch_prove_public_products::<_, _, FS, _, _>(
+		evaluation_order,
+		&wrong_expected,
+		witnesses,
+		domain_factory,
+		&mut transcript,
+		&backend,
+	);
+	assert!(matches!(result.err(), Some(Error::PublicProductMismatch { index: 1 })));
+}
+
+/// A subscriber that enables and formats every span, as the tracing profilers do.
+struct FormattingSubscriber;
+
+impl tracing::Subscriber for FormattingSubscriber {
+	fn enabled(&self, _metadata: &tracing::Metadata<'_>) -> bool {
+		true
+	}
+
+	fn new_span(&self, span: &tracing::span::Attributes<'_>) -> tracing::span::Id {
+		let mut fields = String::new();
+		span.record(&mut |field: &tracing::field::Field, value: &dyn std::fmt::Debug| {
+			fields += &format!("{field}={value:?} ");
+		});
+		tracing::span::Id::from_u64(1)
+	}
+
+	fn record(&self, _span: &tracing::span::Id, _values: &tracing::span::Record<'_>) {}
+
+	fn record_follows_from(&self, _span: &tracing::span::Id, _follows: &tracing::span::Id) {}
+
+	fn event(&self, _event: &tracing::Event<'_>) {}
+
+	fn enter(&self, _span: &tracing::span::Id) {}
+
+	fn exit(&self, _span: &tracing::span::Id) {}
+}
+
+#[test]
+fn test_layer_spans_do_not_change_proof() {
+	type F = BinaryField128b;
+	type P = PackedType<OptimalUnderlier256b, F>;
+	type FDomain = BinaryField32b;
+
+	let prove = || {
+		let mut rng = StdRng::seed_from_u64(0);
+		let (mut claims, mut witnesses) = generate_batch::<P, F>(&mut rng, 5, 2);
+		let (more_claims, more_witnesses) = generate_batch::<P, F>(&mut rng, 3, 1);
+		claims.extend(more_claims);
+		witnesses.extend(more_witnesses);
+
+		let mut transcript = ProverTranscript::<HasherChallenger<Groestl256>>::new();
+		let output = batch_prove::<_, _, FDomain, _, _>(
+			EvaluationOrder::HighToLow,
+			witnesses,
+			&claims,
+			IsomorphicEvaluationDomainFactory::<FDomain>::default(),
+			&mut transcript,
+			&binius_hal::make_portable_backend(),
+		)
+		.unwrap();
+		(output.final_layer_claims, transcript.finalize())
+	};
+
+	let (claims_without_spans, proof_without_spans) = prove();
+	let (claims_with_spans, proof_with_spans) =
+		tracing::subscriber::with_default(FormattingSubscriber, prove);
+
+	assert_eq!(proof_with_spans, proof_without_spans);
+	for (with_spans, without_spans) in izip!(claims_with_spans, claims_without_spans) {
+		assert_eq!(with_spans.eval_point, without_spans.eval_point);
+		assert_eq!(with_spans.eval, without_spans.eval);
+	}
+}
+
+#[test]
+fn test_verify_single() {
+	type F = BinaryField128b;
+	type P = PackedType<OptimalUnderlier256b, F>;
+	type FDomain = BinaryField32b;
+
+	let prove = |claims: &[GrandProductClaim<F>], witnesses| {
+		let mut transcript = ProverTranscript::<HasherChallenger<Groestl256>>::new();
+		let output = batch_prove::<_, _, FDomain, _, _>(
+			EvaluationOrder::HighToLow,
+			witnesses,
+			claims,
+			IsomorphicEvaluationDomainFactory::<FDomain>::default(),
+			&mut transcript,
+			&binius_hal::make_portable_backend(),
+		)
+		.unwrap();
+		(output.final_layer_claims, transcript.finalize())
+	};
+
+	let mut rng = StdRng::seed_from_u64(0);
+	let (claims, witnesses) = generate_batch::<P, F>(&mut rng, 5, 1);
+	let (final_layer_claims, proof) = prove(&claims, witnesses);
+	let mut verifier_transcript = VerifierTranscript::<HasherChallenger<Groestl256>>::new(proof);
+	let final_layer_claim =
+		verify_single(EvaluationOrder::HighToLow, &claims[0], &mut verifier_transcript).unwrap();
+	verifier_transcript.finalize().unwrap();
+	assert_eq!(final_layer_claim.eval_point, final_layer_claims[0].eval_point);
+	assert_eq!(final_layer_claim.eval, final_layer_claims[0].eval);
+
+	let (claims, witnesses) = generate_batch::<P, F>(&mut rng, 5, 2);
+	let (_, proof) = prove(&claims, witnesses);
+	let mut verifier_transcript = VerifierTranscript::<HasherChallenger<Groestl256>>::new(proof);
+	let result = verify_single(EvaluationOrder::HighToLow, &claims[0], &mut verifier_transcript);
+	assert!(matches!(result, Err(Error::SingleClaimProofMismatch { layer: 0 })));
+
+	// Errors other than an incorrect layer evaluation are passed through.
+	let (claims, witnesses) = generate_batch::<P, F>(&mut rng, 5, 1);
+	let (_, mut proof) = prove(&claims, witnesses);
+	proof.truncate(proof.len() / 2);
+	let mut verifier_transcript = VerifierTranscript::<HasherChallenger<Groestl256>>::new(proof);
+	let result = verify_single(EvaluationOrder::HighToLow, &claims[0], &mut verifier_transcript);
+	assert!(matches!(result, Err(Error::SumcheckError(SumcheckError::TranscriptError(_)))));
+}
@@ -1,11 +1,14 @@
 // Copyright 2024-2025 Irreducible Inc.
 
+use std::slice;
+
 use binius_field::{Field, TowerField};
 use binius_math::{EvaluationOrder, extrapolate_line_scalar};
 use binius_utils::{
 	bail,
 	sorting::{stable_sort, unsort},
 };
+use itertools::izip;
 use tracing::instrument;
 
 use super::{Error, GrandProductClaim, gkr_gpa::LayerClaim};
@@ -14,23 +17,30 @@ use crate::{
 	fiat_shamir::{CanSample, Challenger},
 	polynomial::Error as PolynomialError,
 	protocols::sumcheck::{
-		self, CompositeSumClaim, EqIndSumcheckClaim, eq_ind::ClaimsSortingOrder, front_loaded,
+		self, CompositeSumClaim, EqIndSumcheckClaim, Error as SumcheckError, VerificationError,
+		eq_ind::ClaimsSortingOrder, front_loaded,
 	},
-	transcript::VerifierTranscript,
+	transcript::{ProofTape, VerifierTranscript},
 };
 
 /// Verifies batch reduction turning each GrandProductClaim into an EvalcheckMultilinearClaim
+///
+/// An empty batch yields no final layer claims and reads nothing from the transcript.
 #[instrument(skip_all, name = "gkr_gpa::batch_verify", level = "debug")]
 pub fn batch_verify<F, Challenger_>(
 	evaluation_order: EvaluationOrder,
 	claims: impl IntoIterator<Item = GrandProductClaim<F>>,
-	transcript: &mut VerifierTranscript<Challenger_>,
+	transcript: &mut VerifierTranscript<Challenger_, impl ProofTape>,
 ) -> Result<Vec<LayerClaim<F>>, Error>
 where
 	F: TowerField,
 	Challenger_: Challenger,
 {
 	let (original_indices, mut sorted_claims) = stable_sort(claims, |claim| claim.n_vars, true);
+	if sorted_claims.is_empty() {
+		return Ok(vec![]);
+	}
+
 	let max_n_vars = sorted_claims.first().map(|claim| claim.n_vars).unwrap_or(0);
 
 	// Create LayerClaims for each of the claims
@@ -75,6 +85,134 @@ where
 	Ok(final_layer_claims)
 }
 
+/// Verifies many independent grand product proofs, each with its own claims and transcript.
+///
+/// Every proof is verified with [`batch_verify`] and its transcript must be fully read. The proofs
+/// have independent transcripts, so no work is shared between them; this is a convenience over
+/// verifying them one after another.
+///
+/// As with [`batch_verify`], the returned final layer claims, one vector per proof in the order of
+/// its claims, must still be checked against the input multilinears of the proofs.
+///
+/// ## Throws
+///
+/// * `Error::BatchProofInvalid` with the index of the first proof that fails to verify, wrapping
+///   its error
+#[instrument(skip_all, name = "gkr_gpa::batch_verify_many", level = "debug")]
+pub fn batch_verify_many<F, Challenger_, Tape>(
+	evaluation_order: EvaluationOrder,
+	proofs: impl IntoIterator<Item = (Vec<GrandProductClaim<F>>, VerifierTranscript<Challenger_, Tape>)>,
+) -> Result<Vec<Vec<LayerClaim<F>>>, Error>
+where
+	F: TowerField,
+	Challenger_: Challenger,
+	Tape: ProofTape,
+{
+	proofs
+		.into_iter()
+		.enumerate()
+		.map(|(index, (claims, mut transcript))| {
+			let verify = || {
+				let final_layer_claims = batch_verify(evaluation_order, claims, &mut transcript)?;
+				transcript.finalize()?;
+				Ok(final_layer_claims)
+			};
+			verify().map_err(|error| Error::BatchProofInvalid {
+				index,
+				error: Box::new(error),
+			})
+		})
+		.collect()
+}
+
+/// Verifies a single grand product claim, reducing it to a claim on its input multilinear.
+///
+/// This is the verifier of the single-claim fast path of [`batch_prove`](super::batch_prove),
+/// which proves a batch of one claim without sorting the claims or tracking finished claims. The
+/// proof is the same as for a batch of one claim, so it is also accepted by [`batch_verify`].
+///
+/// ## Throws
+///
+/// * `Error::SingleClaimProofMismatch` if the final evaluation of a layer sumcheck is incorrect,
+///   which is the case for proofs batching several claims
+/// * Any other error of the layer reductions, such as a truncated proof, unchanged
+#[instrument(skip_all, name = "gkr_gpa::verify_single", level = "debug")]
+pub fn verify_single<F, Challenger_>(
+	evaluation_order: EvaluationOrder,
+	claim: &GrandProductClaim<F>,
+	transcript: &mut VerifierTranscript<Challenger_, impl ProofTape>,
+) -> Result<LayerClaim<F>, Error>
+where
+	F: TowerField,
+	Challenger_: Challenger,
+{
+	let mut layer_claim = LayerClaim {
+		eval_point: vec![],
+		eval: claim.product,
+	};
+	for layer in 0..claim.n_vars {
+		let next_layer_claims =
+			reduce_layer_claim_batch(evaluation_order, slice::from_ref(&layer_claim), transcript)
+				.map_err(|err| match err {
+				Error::SumcheckError(SumcheckError::Verification(
+					VerificationError::IncorrectBatchEvaluation,
+				)) => Error::SingleClaimProofMismatch { layer },
+				err => err,
+			})?;
+		layer_claim = next_layer_claims
+			.into_iter()
+			.next()
+			.expect("a layer reduction yields a claim for every input claim");
+	}
+	Ok(layer_claim)
+}
+
+/// Verifies grand product claims proven with
+/// [`batch_prove_public_products`](super::batch_prove_public_products).
+///
+/// The `i`-th claim is that the multilinear in `n_vars[i]` variables has the public product
+/// `expected[i]`, which is observed by the challenger rather than read from the transcript.
+pub fn batch_verify_public_products<F, Challenger_>(
+	evaluation_order: EvaluationOrder,
+	n_vars: &[usize],
+	expected: &[F],
+	transcript: &mut VerifierTranscript<Challenger_, impl ProofTape>,
+) -> Result<Vec<LayerClaim<F>>, Error>
+where
+	F: TowerField,
+	Challenger_: Challenger,
+{
+	if n_vars.len() != expected.len() {
+		bail!(Error::MismatchedPublicProductsLength);
+	}
+
+	transcript.observe().write_scalar_slice(expected);
+	let claims =
+		izip!(n_vars, expected).map(|(&n_vars, &product)| GrandProductClaim { n_vars, product });
+	batch_verify(evaluation_order, claims, transcript)
+}
+
+/// Verifies grand product claims proven with
+/// [`batch_prove_sent_products`](super::batch_prove_sent_products).
+///
+/// The products of the multilinears in `n_vars[i]` variables are read from the transcript, and
+/// returned together with the final layer claims. The caller must check the products.
+pub fn batch_verify_sent_products<F, Challenger_>(
+	evaluation_order: EvaluationOrder,
+	n_vars: &[usize],
+	transcript: &mut VerifierTranscript<Challenger_, impl ProofTape>,
+) -> Result<(Vec<F>, Vec<LayerClaim<F>>), Error>
+where
+	F: TowerField,
+	Challenger_: Challenger,
+{
+	let products = transcript.message().read_scalar_slice(n_vars.len())?;
+	let claims =
+		izip!(n_vars, &products).map(|(&n_vars, &product)| GrandProductClaim { n_vars, product });
+	let final_layer_claims = batch_verify(evaluation_order, claims, transcript)?;
+	Ok((products, final_layer_claims))
+}
+
 fn process_finished_claims<F: Field>(
 	n_claims: usize,
 	layer_no: usize,
@@ -106,7 +244,7 @@ fn process_finished_claims<F: Field>(
 fn reduce_layer_claim_batch<F, Challenger_>(
 	evaluation_order: EvaluationOrder,
 	claims: &[LayerClaim<F>],
-	transcript: &mut VerifierTranscript<Challenger_>,
+	transcript: &mut VerifierTranscript<Challenger_, impl ProofTape>,
 ) -> Result<Vec<LayerClaim<F>>, Error>
 where
 	F: TowerField,
@@ -144,6 +282,9 @@ where
 		})
 		.collect::<Result<Vec<_>, PolynomialError>>()?;
 
+	// The verifier reads as many round coefficients per round as the degree of the eq-ind
+	// sumcheck, 3 for the bivariate product times the equality indicator, which pins the domain
+	// the prover interpolates over without reading it from the proof.
 	let eq_ind_sumcheck_claim = EqIndSumcheckClaim::new(n_vars, n_multilinears, composite_sums)?;
 
 	let eq_ind_sumcheck_claims = [eq_ind_sumcheck_claim];
@@ -0,0 +1,158 @@
+// Copyright 2025 Irreducible Inc.
+
+use std::{collections::HashMap, mem};
+
+use binius_field::TowerField;
+use binius_math::CompositionPoly;
+use binius_utils::bail;
+
+use super::error::Error;
+use crate::{
+	oracle::{Error as OracleError, MultilinearOracleSet, MultilinearPolyVariant, OracleId},
+	protocols::evalcheck::EvalcheckMultilinearClaim,
+};
+
+/// Multiplications per hypercube vertex of the bivariate sumcheck of a shifted or packed oracle,
+/// which has two multilinears of degree two.
+const BIVARIATE_SUMCHECK_MULTS_PER_VERTEX: u64 = 5;
+
+/// The approximate work of a greedy evalcheck proof, see
+/// [`MultilinearOracleSet::estimate_prove_cost`].
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct ProveCostEstimate {
+	/// Number of multiplications in the field of the oracle set.
+	pub n_mults: u64,
+	/// Number of bytes allocated for evaluation queries and sumcheck multilinears.
+	pub memory_bytes: u64,
+}
+
+impl ProveCostEstimate {
+	const fn saturating_add(self, other: Self) -> Self {
+		Self {
+			n_mults: self.n_mults.saturating_add(other.n_mults),
+			memory_bytes: self.memory_bytes.saturating_add(other.memory_bytes),
+		}
+	}
+
+	/// Returns whether neither count exceeds that of `budget`.
+	pub const fn is_within(&self, budget: &Self) -> bool {
+		self.n_mults <= budget.n_mults && self.memory_bytes <= budget.memory_bytes
+	}
+}
+
+impl<F: TowerField> MultilinearOracleSet<F> {
+	/// Estimates the work of a [`prove`](super::prove) run on this oracle set and `claims`, without
+	/// a witness.
+	///
+	/// The estimate is meant to reject oversized circuits from untrusted sources before any witness
+	/// is generated. It counts the dominant work of every step that is linear in the hypercube size
+	/// of the step:
+	///
+	/// - a committed oracle is evaluated at the claimed point, which takes one multiplication and
+	///   one field element of the expanded query per vertex;
+	/// - a shifted or packed oracle is reduced by a bivariate sumcheck;
+	/// - a composite oracle is reduced by an MLE-check evaluating the composition at one point more
+	///   than its degree;
+	/// - transparent and structured oracles are evaluated succinctly and are free, while the other
+	///   virtual oracles only pass the claim on to their inner oracles.
+	///
+	/// Terms that are logarithmic in the hypercube size, the polynomial commitment opening and the
+	/// memory of the witness itself are left out. Shared subclaims are counted once per claim even
+	/// though the prover memoizes them, so the estimate errs on the high side. The per-vertex
+	/// constants are those of the portable backend on scalars; other backends and packings change
+	/// the actual counts by a constant factor, so budgets should be calibrated against the
+	/// estimates of known circuits rather than absolute numbers. Counts saturate at [`u64::MAX`]
+	/// rather than overflow.
+	///
+	/// The estimate is a relative measure of work, not a prediction of proving time. The time per
+	/// estimated multiplication depends on the machine and has to be calibrated per machine, for
+	/// instance with the `greedy_evalcheck/estimated_mults` benchmark, which compares the estimate
+	/// with [`prove`](super::prove).
+	pub fn estimate_prove_cost(
+		&self,
+		claims: impl IntoIterator<Item = EvalcheckMultilinearClaim<F>>,
+	) -> Result<ProveCostEstimate, Error> {
+		let mut memoized = HashMap::new();
+		claims
+			.into_iter()
+			.try_fold(ProveCostEstimate::default(), |acc, claim| {
+				if !self.is_valid_oracle_id(claim.id) {
+					bail!(OracleError::InvalidOracleId(claim.id));
+				}
+				Ok(acc.saturating_add(self.claim_cost(claim.id, &mut memoized)))
+			})
+	}
+
+	/// The cost of a claim on the oracle `id`, which does not depend on the evaluation point.
+	///
+	/// The cost of a claim is the cost of the step reducing it plus the costs of the claims on its
+	/// inner oracles. The oracle graph is traversed with an explicit stack, so that deep chains of
+	/// virtual oracles do not overflow the call stack.
+	fn claim_cost(
+		&self,
+		id: OracleId,
+		memoized: &mut HashMap<OracleId, ProveCostEstimate>,
+	) -> ProveCostEstimate {
+		// An oracle is pushed once to have its inner oracles costed, and once more to sum up.
+		let mut stack = vec![(id, false)];
+		while let Some((id, inner_costed)) = stack.pop() {
+			if memoized.contains_key(&id) {
+				continue;
+			}
+
+			let inner_ids = self[id].variant.inner_oracle_ids();
+			if !inner_costed {
+				stack.push((id, true));
+				stack.extend(
+					inner_ids
+						.into_iter()
+						.filter(|inner_id| !memoized.contains_key(inner_id))
+						.map(|inner_id| (inner_id, false)),
+				);
+				continue;
+			}
+
+			let cost = inner_ids
+				.iter()
+				.fold(self.step_cost(id), |acc, inner_id| acc.saturating_add(memoized[inner_id]));
+			memoized.insert(id, cost);
+		}
+		memoized[&id]
+	}
+
+	/// The cost of the step reducing a claim on the oracle `id` to claims on its inner oracles.
+	fn step_cost(&self, id: OracleId) -> ProveCostEstimate {
+		let oracle = &self[id];
+		let n_vertices = 1u64.checked_shl(oracle.n_vars() as u32).unwrap_or(u64::MAX);
+		let per_vertex = |n_mults: u64, n_elems: u64| ProveCostEstimate {
+			n_mults: n_vertices.saturating_mul(n_mults),
+			memory_bytes: n_vertices
+				.saturating_mul(n_elems)
+				.saturating_mul(mem::size_of::<F>() as u64),
+		};
+
+		match oracle.variant {
+			MultilinearPolyVariant::Transparent(_)
+			| MultilinearPolyVariant::Structured(_)
+			| MultilinearPolyVariant::Repeating { .. }
+			| MultilinearPolyVariant::Projected(_)
+			| MultilinearPolyVariant::ZeroPadded(_)
+			| MultilinearPolyVariant::LinearCombination(_) => ProveCostEstimate::default(),
+			MultilinearPolyVariant::Committed => per_vertex(1, 1),
+			MultilinearPolyVariant::Shifted(_) | MultilinearPolyVariant::Packed(_) => {
+				per_vertex(BIVARIATE_SUMCHECK_MULTS_PER_VERTEX, 2)
+			}
+			MultilinearPolyVariant::Composite(ref composite) => {
+				let n_inner = composite.n_polys() as u64;
+				let composition = composite.c();
+				let composition_mults = <_ as CompositionPoly<F>>::expression(composition)
+					.eval_cost()
+					.mult_cost_approx();
+				let n_evals = <_ as CompositionPoly<F>>::degree(composition) as u64 + 1;
+				// Each vertex evaluates the composition and the equality indicator at every
+				// evaluation point, and folds each multilinear once.
+				per_vertex(n_evals * (composition_mults as u64 + 1) + n_inner, n_inner + 1)
+			}
+		}
+	}
+}
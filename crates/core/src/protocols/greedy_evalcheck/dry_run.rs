@@ -0,0 +1,232 @@
+// Copyright 2025 Irreducible Inc.
+
+use std::{iter::repeat_with, mem};
+
+use binius_field::{Field, TowerField};
+use binius_math::EvaluationOrder;
+use rand::{SeedableRng, rngs::StdRng};
+
+use super::error::Error;
+use crate::{
+	oracle::{
+		ConstraintSet, ConstraintSetBuilder, Error as OracleError, MultilinearOracleSet,
+		MultilinearPolyVariant, OracleId,
+	},
+	protocols::{
+		evalcheck::{
+			ClaimVisitor, EvalPoint, EvalPointOracleIdMap, EvalcheckHint,
+			EvalcheckMultilinearClaim, add_composite_claim,
+			subclaims::{
+				add_bivariate_sumcheck_to_constraints, packed_sumcheck_meta, shifted_sumcheck_meta,
+			},
+			visit_claim,
+		},
+		sumcheck::{
+			self, BatchSumcheckOutput, MLEcheckClaimsWithMeta, SumcheckClaimsWithMeta,
+			constraint_set_mlecheck_claims, constraint_set_sumcheck_claims,
+		},
+	},
+};
+
+/// Predicts the committed evaluation claims of a greedy evalcheck proof without a witness.
+///
+/// Returns the oracle id and evaluation point length of each claim in the
+/// [`GreedyEvalcheckProveOutput::eval_claims`](super::GreedyEvalcheckProveOutput::eval_claims) of
+/// a [`prove`](super::prove) run on the same `oracles` and `claims`, in the same order. The
+/// evaluations of `claims` are ignored, and the oracle set is left untouched.
+///
+/// The sumcheck challenges of the proof are replaced by challenges sampled from a fixed seed.
+/// Virtual claims of the proof are deduplicated by their evaluation points, so the prediction is
+/// exact unless two sampled points coincide where the real ones do not, or vice versa, which
+/// happens with negligible probability.
+pub fn dry_run<F: TowerField>(
+	oracles: &MultilinearOracleSet<F>,
+	claims: impl IntoIterator<Item = EvalcheckMultilinearClaim<F>>,
+) -> Result<Vec<(OracleId, usize)>, Error> {
+	let mut oracles = oracles.clone();
+	let mut state = DryRunState::new(&mut oracles);
+	let mut rng = StdRng::seed_from_u64(0);
+
+	state.run_round(claims.into_iter().map(|claim| (claim.id, claim.eval_point)))?;
+
+	loop {
+		let mut new_claims = Vec::new();
+
+		let SumcheckClaimsWithMeta { metas, .. } =
+			constraint_set_sumcheck_claims(state.take_new_bivariate_sumchecks_constraints()?)?;
+		if !metas.is_empty() {
+			let max_n_vars = metas.last().map_or(0, |meta| meta.n_vars);
+			let output = BatchSumcheckOutput {
+				challenges: repeat_with(|| <F as Field>::random(&mut rng))
+					.take(max_n_vars)
+					.collect(),
+				multilinear_evals: metas
+					.iter()
+					.map(|meta| vec![F::ZERO; meta.oracle_ids.len()])
+					.collect(),
+			};
+			new_claims.extend(sumcheck::make_eval_claims(
+				EvaluationOrder::HighToLow,
+				metas,
+				output,
+			)?);
+		}
+
+		let constraint_sets = mem::take(&mut state.new_mlechecks_constraints)
+			.into_iter()
+			.map(|(_, builder)| builder.build_one(state.oracles))
+			.collect::<Result<Vec<_>, _>>()?;
+		let MLEcheckClaimsWithMeta { metas, .. } = constraint_set_mlecheck_claims(constraint_sets)?;
+		for meta in metas {
+			let output = BatchSumcheckOutput {
+				challenges: repeat_with(|| <F as Field>::random(&mut rng))
+					.take(meta.n_vars)
+					.collect(),
+				multilinear_evals: vec![vec![F::ZERO; meta.oracle_ids.len()]],
+			};
+			new_claims.extend(sumcheck::make_eval_claims(
+				EvaluationOrder::HighToLow,
+				vec![meta],
+				output,
+			)?);
+		}
+
+		if new_claims.is_empty() {
+			break;
+		}
+
+		state.run_round(
+			new_claims
+				.into_iter()
+				.map(|claim| (claim.id, claim.eval_point)),
+		)?;
+	}
+
+	Ok(state.committed_claims)
+}
+
+/// Visits claims in the order of [`EvalcheckProver`](crate::protocols::evalcheck::EvalcheckProver),
+/// with zero evaluations and no transcript.
+struct DryRunState<'a, F: TowerField> {
+	oracles: &'a mut MultilinearOracleSet<F>,
+	committed_claims: Vec<(OracleId, usize)>,
+	new_bivariate_sumchecks_constraints: Vec<ConstraintSetBuilder<F>>,
+	new_mlechecks_constraints: Vec<(EvalPoint<F>, ConstraintSetBuilder<F>)>,
+	// The index of each claim visited in this round.
+	claim_to_index: EvalPointOracleIdMap<usize, F>,
+	round_claim_index: usize,
+	// Shifted and packed claims, reduced to bivariate sumchecks at the end of the round.
+	projected_bivariate_claims: Vec<EvalcheckMultilinearClaim<F>>,
+}
+
+impl<'a, F: TowerField> DryRunState<'a, F> {
+	fn new(oracles: &'a mut MultilinearOracleSet<F>) -> Self {
+		Self {
+			oracles,
+			committed_claims: Vec::new(),
+			new_bivariate_sumchecks_constraints: Vec::new(),
+			new_mlechecks_constraints: Vec::new(),
+			claim_to_index: EvalPointOracleIdMap::new(),
+			round_claim_index: 0,
+			projected_bivariate_claims: Vec::new(),
+		}
+	}
+
+	fn take_new_bivariate_sumchecks_constraints(
+		&mut self,
+	) -> Result<Vec<ConstraintSet<F>>, OracleError> {
+		self.new_bivariate_sumchecks_constraints
+			.iter_mut()
+			.map(|builder| mem::take(builder).build_one(self.oracles))
+			.filter(|constraint| !matches!(constraint, Err(OracleError::EmptyConstraintSet)))
+			.collect()
+	}
+
+	fn run_round(
+		&mut self,
+		claims: impl IntoIterator<Item = (OracleId, EvalPoint<F>)>,
+	) -> Result<(), Error> {
+		self.claim_to_index.clear();
+		self.round_claim_index = 0;
+		for (id, eval_point) in claims {
+			visit_claim(
+				self,
+				EvalcheckMultilinearClaim {
+					id,
+					eval_point,
+					eval: F::ZERO,
+				},
+			)?;
+		}
+
+		for EvalcheckMultilinearClaim { id, eval_point, .. } in
+			mem::take(&mut self.projected_bivariate_claims)
+		{
+			match &self.oracles[id].variant {
+				MultilinearPolyVariant::Shifted(shifted) => {
+					let shifted = shifted.clone();
+					let meta = shifted_sumcheck_meta(self.oracles, &shifted, &eval_point)?;
+					add_bivariate_sumcheck_to_constraints(
+						&meta,
+						&mut self.new_bivariate_sumchecks_constraints,
+						shifted.block_size(),
+						F::ZERO,
+					);
+				}
+				MultilinearPolyVariant::Packed(packed) => {
+					let packed = packed.clone();
+					let meta = packed_sumcheck_meta(self.oracles, &packed, &eval_point)?;
+					add_bivariate_sumcheck_to_constraints(
+						&meta,
+						&mut self.new_bivariate_sumchecks_constraints,
+						packed.log_degree(),
+						F::ZERO,
+					);
+				}
+				_ => unreachable!(),
+			}
+		}
+		Ok(())
+	}
+}
+
+impl<F: TowerField> ClaimVisitor<F> for DryRunState<'_, F> {
+	fn oracles(&self) -> &MultilinearOracleSet<F> {
+		self.oracles
+	}
+
+	fn claim_index(&self, id: OracleId, eval_point: &EvalPoint<F>) -> Option<usize> {
+		self.claim_to_index.get(id, eval_point).copied()
+	}
+
+	fn insert_claim(&mut self, id: OracleId, eval_point: EvalPoint<F>) {
+		self.claim_to_index
+			.insert(id, eval_point, self.round_claim_index);
+		self.round_claim_index += 1;
+	}
+
+	fn write_hint(&mut self, _hint: EvalcheckHint) {}
+
+	fn visit_committed(&mut self, claim: EvalcheckMultilinearClaim<F>) {
+		self.committed_claims
+			.push((claim.id, claim.eval_point.len()));
+	}
+
+	fn visit_projected_bivariate(&mut self, claim: EvalcheckMultilinearClaim<F>) {
+		self.projected_bivariate_claims.push(claim);
+	}
+
+	fn visit_composite(&mut self, claim: EvalcheckMultilinearClaim<F>) {
+		add_composite_claim(self.oracles, &mut self.new_mlechecks_constraints, &claim);
+	}
+
+	fn linear_combination_subclaim_eval(&mut self, _id: OracleId, _eval_point: &EvalPoint<F>) -> F {
+		F::ZERO
+	}
+
+	// A valid witness evaluates to zero on zero-padded oracles where the selector does, so with
+	// zero evaluations the traversal skips the same claims as the prover.
+	fn zero_padded_subclaim_eval(&mut self, _id: OracleId, _eval_point: &EvalPoint<F>) -> F {
+		F::ZERO
+	}
+}
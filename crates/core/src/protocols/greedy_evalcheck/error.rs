@@ -22,6 +22,8 @@ pub enum Error {
 	Sumcheck(#[from] sumcheck::Error),
 	#[error("transcript error: {0}")]
 	TranscriptError(#[from] crate::transcript::Error),
+	#[error("witness error: {0}")]
+	Witness(#[from] crate::witness::Error),
 	#[error("oracle error: {0}")]
 	Oracle(#[from] crate::oracle::Error),
 }
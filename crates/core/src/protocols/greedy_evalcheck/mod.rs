@@ -11,13 +11,21 @@
 //! The greedy evalcheck protocol runs the full sequence of alternating evalcheck and sumcheck
 //! protocols to reduce several evaluation claims to a single PCS opening per batch.
 
+mod cost;
+mod dry_run;
 mod error;
 mod logging;
 mod prove;
+mod switchover;
 #[cfg(test)]
 mod tests;
 mod verify;
 
+pub use cost::ProveCostEstimate;
+pub use dry_run::dry_run;
 pub use error::*;
 pub use prove::*;
+pub use switchover::{
+	ADAPTIVE_SWITCHOVER_CANDIDATES, AdaptiveSwitchover, DEFAULT_SWITCHOVER_OFFSET, Switchover,
+};
 pub use verify::*;
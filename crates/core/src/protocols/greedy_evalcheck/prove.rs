@@ -1,10 +1,16 @@
 // Copyright 2024-2025 Irreducible Inc.
 
+use std::num::NonZeroUsize;
+
 use binius_field::{ExtensionField, Field, PackedExtension, PackedField, TowerField};
 use binius_hal::ComputationBackend;
 use binius_math::EvaluationDomainFactory;
 
-use super::{error::Error, logging::RegularSumcheckDimensionsData};
+use super::{
+	error::Error,
+	logging::RegularSumcheckDimensionsData,
+	switchover::{Switchover, SwitchoverState},
+};
 use crate::{
 	fiat_shamir::Challenger,
 	oracle::MultilinearOracleSet,
@@ -21,6 +27,17 @@ use crate::{
 pub struct GreedyEvalcheckProveOutput<'a, F: Field, P: PackedField> {
 	pub eval_claims: Vec<EvalcheckMultilinearClaim<F>>,
 	pub memoized_data: MemoizedData<'a, P>,
+	/// The offset of [`standard_switchover_heuristic`] chosen by a [`Switchover::Adaptive`],
+	/// whether in this proof or an earlier one, or `None` if the switchover was fixed or no
+	/// bivariate sumcheck has been proven with the tuning.
+	///
+	/// [`standard_switchover_heuristic`]: crate::protocols::sumcheck::standard_switchover_heuristic
+	pub tuned_switchover_offset: Option<isize>,
+	/// The number of proof bytes written by each step, starting with the initial evalcheck round
+	/// and followed by one entry per subsequent evalcheck round. These are the values recorded as
+	/// `proof_bytes` on the `[step]` tracing spans, and they sum to the bytes written by the
+	/// proof.
+	pub step_proof_bytes: Vec<usize>,
 }
 
 #[allow(clippy::too_many_arguments)]
@@ -42,27 +59,193 @@ where
 	Challenger_: Challenger,
 	Backend: ComputationBackend,
 {
-	let mut evalcheck_prover = EvalcheckProver::<F, P>::new(oracles, witness_index);
+	prove_streaming(
+		oracles,
+		witness_index,
+		claims,
+		NonZeroUsize::MAX,
+		switchover_fn,
+		transcript,
+		domain_factory,
+		backend,
+	)
+}
 
-	let claims: Vec<_> = claims.into_iter().collect();
+/// Like [`prove`], but consumes the initial claims in batches of at most
+/// `initial_claims_batch_size`, so that only one batch is held in memory at a time.
+///
+/// The proof is identical to the one produced by [`prove`].
+#[allow(clippy::too_many_arguments)]
+pub fn prove_streaming<'a, F, P, DomainField, Challenger_, Backend>(
+	oracles: &mut MultilinearOracleSet<F>,
+	witness_index: &'a mut MultilinearExtensionIndex<P>,
+	claims: impl IntoIterator<Item = EvalcheckMultilinearClaim<F>>,
+	initial_claims_batch_size: NonZeroUsize,
+	switchover_fn: impl Fn(usize) -> usize + Clone + 'static,
+	transcript: &mut ProverTranscript<Challenger_>,
+	domain_factory: impl EvaluationDomainFactory<DomainField>,
+	backend: &Backend,
+) -> Result<GreedyEvalcheckProveOutput<'a, F, P>, Error>
+where
+	F: TowerField + ExtensionField<DomainField>,
+	P: PackedField<Scalar = F>
+		+ PackedExtension<F, PackedSubfield = P>
+		+ PackedExtension<DomainField>,
+	DomainField: TowerField,
+	Challenger_: Challenger,
+	Backend: ComputationBackend,
+{
+	prove_with_switchover(
+		oracles,
+		witness_index,
+		claims,
+		initial_claims_batch_size,
+		Switchover::Fixed(switchover_fn),
+		transcript,
+		domain_factory,
+		backend,
+	)
+}
+
+/// Like [`prove_streaming`], but chooses the sumcheck switchover rounds as configured by
+/// `switchover`.
+///
+/// The proof does not depend on `switchover`.
+#[allow(clippy::too_many_arguments)]
+pub fn prove_with_switchover<'a, F, P, DomainField, Challenger_, Backend>(
+	oracles: &mut MultilinearOracleSet<F>,
+	witness_index: &'a mut MultilinearExtensionIndex<P>,
+	claims: impl IntoIterator<Item = EvalcheckMultilinearClaim<F>>,
+	initial_claims_batch_size: NonZeroUsize,
+	switchover: Switchover<impl Fn(usize) -> usize + 'static>,
+	transcript: &mut ProverTranscript<Challenger_>,
+	domain_factory: impl EvaluationDomainFactory<DomainField>,
+	backend: &Backend,
+) -> Result<GreedyEvalcheckProveOutput<'a, F, P>, Error>
+where
+	F: TowerField + ExtensionField<DomainField>,
+	P: PackedField<Scalar = F>
+		+ PackedExtension<F, PackedSubfield = P>
+		+ PackedExtension<DomainField>,
+	DomainField: TowerField,
+	Challenger_: Challenger,
+	Backend: ComputationBackend,
+{
+	let switchover = SwitchoverState::new(switchover);
+
+	// Surface missing committed witnesses up front rather than midway through the proof. This
+	// collects the claims, so they are no longer consumed in batches.
+	#[cfg(feature = "debug_validate_witness")]
+	let claims = {
+		let claims = claims.into_iter().collect::<Vec<_>>();
+		witness_index.validate_complete(oracles, claims.iter().map(|claim| claim.id))?;
+		claims
+	};
+
+	let mut evalcheck_prover = EvalcheckProver::<F, P>::new(oracles, witness_index);
 
 	// Prove the initial evalcheck claims
 	let initial_evalcheck_round_span = tracing::debug_span!(
 		"[step] Initial Evalcheck Round",
 		phase = "evalcheck",
-		perfetto_category = "phase.sub"
+		perfetto_category = "phase.sub",
+		proof_bytes = tracing::field::Empty,
 	)
 	.entered();
-	evalcheck_prover.prove(claims, transcript)?;
+	let mark = transcript.position();
+	evalcheck_prover.prove_streaming(claims, initial_claims_batch_size, transcript)?;
+	let mut step_proof_bytes = vec![transcript.bytes_since(mark)];
+	initial_evalcheck_round_span.record("proof_bytes", step_proof_bytes[0]);
 	drop(initial_evalcheck_round_span);
 
+	step_proof_bytes.extend(prove_rounds::<_, _, DomainField, _, _>(
+		&mut evalcheck_prover,
+		&switchover,
+		transcript,
+		domain_factory,
+		backend,
+	)?);
+
+	let committed_claims = evalcheck_prover
+		.committed_eval_claims_mut()
+		.drain(..)
+		.collect::<Vec<_>>();
+
+	Ok(GreedyEvalcheckProveOutput {
+		eval_claims: committed_claims,
+		memoized_data: evalcheck_prover.memoized_data,
+		tuned_switchover_offset: switchover.tuned_offset(),
+		step_proof_bytes,
+	})
+}
+
+/// Like [`prove`], but proves the claims with a prover owned by the caller and returns clones of
+/// its committed claims instead of draining them.
+///
+/// The prover keeps its committed claims and memoized data, so further claims can be proven with
+/// it afterwards, and each call returns all committed claims of the prover so far. This holds the
+/// committed claims in memory twice, and the memoized tensor expansions and partial evaluations
+/// for as long as the prover lives, where [`prove`] releases them at the end of the proof.
+pub fn prove_retaining<F, P, DomainField, Challenger_, Backend>(
+	evalcheck_prover: &mut EvalcheckProver<'_, '_, F, P>,
+	claims: impl IntoIterator<Item = EvalcheckMultilinearClaim<F>>,
+	switchover_fn: impl Fn(usize) -> usize + Clone + 'static,
+	transcript: &mut ProverTranscript<Challenger_>,
+	domain_factory: impl EvaluationDomainFactory<DomainField>,
+	backend: &Backend,
+) -> Result<Vec<EvalcheckMultilinearClaim<F>>, Error>
+where
+	F: TowerField + ExtensionField<DomainField>,
+	P: PackedField<Scalar = F>
+		+ PackedExtension<F, PackedSubfield = P>
+		+ PackedExtension<DomainField>,
+	DomainField: TowerField,
+	Challenger_: Challenger,
+	Backend: ComputationBackend,
+{
+	let switchover = SwitchoverState::new(Switchover::Fixed(switchover_fn));
+
+	evalcheck_prover.prove_streaming(claims, NonZeroUsize::MAX, transcript)?;
+	prove_rounds::<_, _, DomainField, _, _>(
+		evalcheck_prover,
+		&switchover,
+		transcript,
+		domain_factory,
+		backend,
+	)?;
+
+	Ok(evalcheck_prover.committed_eval_claims().clone())
+}
+
+/// Runs the alternating sumcheck and evalcheck rounds until no new sumcheck claims arise.
+///
+/// Returns the number of proof bytes written by each round.
+fn prove_rounds<F, P, DomainField, Challenger_, Backend>(
+	evalcheck_prover: &mut EvalcheckProver<'_, '_, F, P>,
+	switchover: &SwitchoverState,
+	transcript: &mut ProverTranscript<Challenger_>,
+	domain_factory: impl EvaluationDomainFactory<DomainField>,
+	backend: &Backend,
+) -> Result<Vec<usize>, Error>
+where
+	F: TowerField + ExtensionField<DomainField>,
+	P: PackedField<Scalar = F>
+		+ PackedExtension<F, PackedSubfield = P>
+		+ PackedExtension<DomainField>,
+	DomainField: TowerField,
+	Challenger_: Challenger,
+	Backend: ComputationBackend,
+{
+	let mut round_proof_bytes = Vec::new();
 	loop {
-		let _span = tracing::debug_span!(
+		let evalcheck_round_span = tracing::debug_span!(
 			"[step] Evalcheck Round",
 			phase = "evalcheck",
-			perfetto_category = "phase.sub"
+			perfetto_category = "phase.sub",
+			proof_bytes = tracing::field::Empty,
 		)
 		.entered();
+		let round_mark = transcript.position();
 
 		let new_bivariate_sumchecks =
 			evalcheck_prover.take_new_bivariate_sumchecks_constraints()?;
@@ -82,19 +265,29 @@ where
 				phase = "evalcheck",
 				perfetto_category = "task.main",
 				dimensions_data = ?dimensions_data,
+				proof_bytes = tracing::field::Empty,
 			)
 			.entered();
+			switchover.tune::<_, _, DomainField, _>(
+				&new_bivariate_sumchecks,
+				evalcheck_prover.witness_index,
+				domain_factory.clone(),
+				backend,
+			)?;
+
+			let mark = transcript.position();
 			let evalcheck_claims =
 				prove_bivariate_sumchecks_with_switchover::<_, _, DomainField, _, _>(
 					evalcheck_prover.witness_index,
 					new_bivariate_sumchecks,
 					transcript,
-					switchover_fn.clone(),
+					switchover.switchover_fn(),
 					domain_factory.clone(),
 					backend,
 				)?;
 
 			new_evalcheck_claims.extend(evalcheck_claims);
+			evalcheck_round_mle_fold_high_span.record("proof_bytes", transcript.bytes_since(mark));
 			drop(evalcheck_round_mle_fold_high_span);
 		}
 
@@ -110,8 +303,10 @@ where
 				phase = "evalcheck",
 				perfetto_category = "task.main",
 				dimensions_data = ?dimensions_data,
+				proof_bytes = tracing::field::Empty,
 			)
 			.entered();
+			let mark = transcript.position();
 
 			for ConstraintSetEqIndPoint {
 				eq_ind_challenges,
@@ -124,30 +319,29 @@ where
 					eq_ind_challenges,
 					&mut evalcheck_prover.memoized_data,
 					transcript,
-					switchover_fn.clone(),
+					switchover.switchover_fn(),
 					domain_factory.clone(),
 					backend,
 				)?;
 				new_evalcheck_claims.extend(evalcheck_claims);
 			}
 
+			evalcheck_round_mle_fold_high_span.record("proof_bytes", transcript.bytes_since(mark));
 			drop(evalcheck_round_mle_fold_high_span);
 		}
 
-		if new_evalcheck_claims.is_empty() {
-			break;
+		let done = new_evalcheck_claims.is_empty();
+		if !done {
+			evalcheck_prover.prove(new_evalcheck_claims, transcript)?;
 		}
 
-		evalcheck_prover.prove(new_evalcheck_claims, transcript)?;
+		let proof_bytes = transcript.bytes_since(round_mark);
+		evalcheck_round_span.record("proof_bytes", proof_bytes);
+		round_proof_bytes.push(proof_bytes);
+		if done {
+			break;
+		}
 	}
 
-	let committed_claims = evalcheck_prover
-		.committed_eval_claims_mut()
-		.drain(..)
-		.collect::<Vec<_>>();
-
-	Ok(GreedyEvalcheckProveOutput {
-		eval_claims: committed_claims,
-		memoized_data: evalcheck_prover.memoized_data,
-	})
+	Ok(round_proof_bytes)
 }
@@ -0,0 +1,197 @@
+// Copyright 2025 Irreducible Inc.
+
+use std::{cell::Cell, rc::Rc, time::Instant};
+
+use binius_field::{ExtensionField, Field, PackedExtension, PackedField, TowerField};
+use binius_hal::ComputationBackend;
+use binius_math::{EvaluationDomainFactory, EvaluationOrder};
+use rand::{SeedableRng, rngs::StdRng};
+
+use crate::{
+	oracle::ConstraintSet,
+	protocols::sumcheck::{
+		Error as SumcheckError,
+		prove::{SumcheckProver, oracles::constraint_sets_sumcheck_provers_metas},
+		standard_switchover_heuristic,
+	},
+	witness::MultilinearExtensionIndex,
+};
+
+/// Offsets `k` of [`standard_switchover_heuristic`] tried by [`Switchover::Adaptive`].
+pub const ADAPTIVE_SWITCHOVER_CANDIDATES: [isize; 5] = [-3, -2, -1, 0, 1];
+
+/// The offset of [`standard_switchover_heuristic`] used by [`Switchover::Adaptive`] before it
+/// has been tuned.
+pub const DEFAULT_SWITCHOVER_OFFSET: isize = -2;
+
+/// The number of sumcheck rounds [`Switchover::Adaptive`] runs for each candidate.
+///
+/// This covers the switchover rounds of all candidates for extension degrees up to 128.
+const ADAPTIVE_SWITCHOVER_PREFIX_ROUNDS: usize = 8;
+
+/// How the sumcheck provers of greedy evalcheck choose the round in which they switch from
+/// evaluating the subfield multilinears over a tensor-expanded query to folding them.
+///
+/// The switchover round affects proving time only, so the proof is the same for any choice.
+pub enum Switchover<S = fn(usize) -> usize> {
+	/// Uses the given function from extension degree to switchover round.
+	Fixed(S),
+	/// Uses the offset of [`standard_switchover_heuristic`] tuned by an [`AdaptiveSwitchover`],
+	/// which is tuned during this proof if no earlier proof has tuned it.
+	Adaptive(AdaptiveSwitchover),
+}
+
+impl Switchover {
+	/// A switchover with the offset tuned by `tuning`.
+	pub fn adaptive(tuning: &AdaptiveSwitchover) -> Self {
+		Self::Adaptive(tuning.clone())
+	}
+}
+
+/// The offset of [`standard_switchover_heuristic`], tuned empirically once and shared by every
+/// proof using the tuning with [`Switchover::adaptive`].
+///
+/// The first proof that proves a bivariate sumcheck tunes the offset: the largest bivariate
+/// sumcheck of its first batch is proven for a prefix of [`ADAPTIVE_SWITCHOVER_PREFIX_ROUNDS`]
+/// rounds with each of the [`ADAPTIVE_SWITCHOVER_CANDIDATES`], folding with challenges sampled
+/// from the seed, and the fastest candidate is recorded. Sumchecks proven before the tuning use
+/// [`DEFAULT_SWITCHOVER_OFFSET`]. Later proofs use the recorded offset without timing anything.
+///
+/// The tuned offset depends on timings, and hence may vary between processes, but the proof
+/// does not. Tuning proves the prefix once per candidate, so it only pays off when amortized over
+/// several proofs. To skip it in later processes, store [`Self::offset`] and pass it as a fixed
+/// [`standard_switchover_heuristic`].
+#[derive(Debug, Clone)]
+pub struct AdaptiveSwitchover {
+	seed: u64,
+	offset: Rc<Cell<Option<isize>>>,
+}
+
+impl AdaptiveSwitchover {
+	/// An untuned switchover, to be tuned with challenges sampled from `seed`.
+	pub fn new(seed: u64) -> Self {
+		Self {
+			seed,
+			offset: Rc::new(Cell::new(None)),
+		}
+	}
+
+	/// The tuned offset, or `None` if no proof has tuned it yet.
+	pub fn offset(&self) -> Option<isize> {
+		self.offset.get()
+	}
+}
+
+/// The state of a [`Switchover`] during proving.
+pub(super) enum SwitchoverState {
+	Fixed(Rc<dyn Fn(usize) -> usize>),
+	Adaptive(AdaptiveSwitchover),
+}
+
+impl SwitchoverState {
+	pub fn new<S: Fn(usize) -> usize + 'static>(switchover: Switchover<S>) -> Self {
+		match switchover {
+			Switchover::Fixed(switchover_fn) => Self::Fixed(Rc::new(switchover_fn)),
+			Switchover::Adaptive(tuning) => Self::Adaptive(tuning),
+		}
+	}
+
+	/// Tunes an adaptive switchover on the largest of the given bivariate sumcheck constraint sets,
+	/// if neither this proof nor an earlier one has tuned it yet.
+	pub fn tune<'a, F, P, FDomain, Backend>(
+		&self,
+		constraint_sets: &[ConstraintSet<F>],
+		witness: &MultilinearExtensionIndex<'a, P>,
+		domain_factory: impl EvaluationDomainFactory<FDomain>,
+		backend: &'a Backend,
+	) -> Result<(), SumcheckError>
+	where
+		P: PackedField<Scalar = F>
+			+ PackedExtension<F, PackedSubfield = P>
+			+ PackedExtension<FDomain>,
+		F: TowerField + ExtensionField<FDomain>,
+		FDomain: Field,
+		Backend: ComputationBackend,
+	{
+		let largest = constraint_sets
+			.iter()
+			.max_by_key(|constraint_set| constraint_set.n_vars);
+		if let (Self::Adaptive(tuning), Some(constraint_set)) = (self, largest) {
+			if tuning.offset().is_none() {
+				let offset = tune_switchover_offset(
+					constraint_set,
+					witness,
+					domain_factory,
+					backend,
+					tuning.seed,
+				)?;
+				tuning.offset.set(Some(offset));
+			}
+		}
+		Ok(())
+	}
+
+	/// The switchover function to use for the next sumcheck.
+	pub fn switchover_fn(&self) -> impl Fn(usize) -> usize + Clone + 'static {
+		let switchover_fn = match self {
+			Self::Fixed(switchover_fn) => switchover_fn.clone(),
+			Self::Adaptive(tuning) => Rc::new(standard_switchover_heuristic(
+				tuning.offset().unwrap_or(DEFAULT_SWITCHOVER_OFFSET),
+			)),
+		};
+		move |extension_degree| switchover_fn(extension_degree)
+	}
+
+	/// The offset chosen by an adaptive switchover, if it has been tuned.
+	pub fn tuned_offset(&self) -> Option<isize> {
+		match self {
+			Self::Fixed(_) => None,
+			Self::Adaptive(tuning) => tuning.offset(),
+		}
+	}
+}
+
+/// Returns the candidate offset that proves a prefix of the given sumcheck the fastest.
+fn tune_switchover_offset<'a, F, P, FDomain, Backend>(
+	constraint_set: &ConstraintSet<F>,
+	witness: &MultilinearExtensionIndex<'a, P>,
+	domain_factory: impl EvaluationDomainFactory<FDomain>,
+	backend: &'a Backend,
+	seed: u64,
+) -> Result<isize, SumcheckError>
+where
+	P: PackedField<Scalar = F> + PackedExtension<F, PackedSubfield = P> + PackedExtension<FDomain>,
+	F: TowerField + ExtensionField<FDomain>,
+	FDomain: Field,
+	Backend: ComputationBackend,
+{
+	let mut best = None;
+	for offset in ADAPTIVE_SWITCHOVER_CANDIDATES {
+		let mut rng = StdRng::seed_from_u64(seed);
+		let start = Instant::now();
+
+		let provers = constraint_sets_sumcheck_provers_metas(
+			EvaluationOrder::HighToLow,
+			vec![constraint_set.clone()],
+			witness,
+			domain_factory.clone(),
+			standard_switchover_heuristic(offset),
+			backend,
+		)?
+		.provers;
+		for mut prover in provers {
+			for _ in 0..prover.n_vars().min(ADAPTIVE_SWITCHOVER_PREFIX_ROUNDS) {
+				prover.execute(F::ONE)?;
+				prover.fold(<F as Field>::random(&mut rng))?;
+			}
+		}
+
+		let elapsed = start.elapsed();
+		if best.is_none_or(|(_, best_elapsed)| elapsed < best_elapsed) {
+			best = Some((offset, elapsed));
+		}
+	}
+
+	let (offset, _) = best.expect("there is at least one candidate");
+	Ok(offset)
+}
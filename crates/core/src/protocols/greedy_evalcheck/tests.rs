@@ -1,6 +1,7 @@
 // Copyright 2024-2025 Irreducible Inc.
-use std::iter::repeat_with;
+use std::{iter::repeat_with, num::NonZeroUsize};
 
+use assert_matches::assert_matches;
 use binius_field::{
 	BinaryField1b, BinaryField32b, BinaryField128b, ExtensionField, Field, PackedBinaryField1x128b,
 	PackedBinaryField128x1b, PackedExtension, PackedField, RepackedExtension, TowerField,
@@ -12,20 +13,24 @@ use binius_macros::arith_expr;
 use binius_math::{DefaultEvaluationDomainFactory, MultilinearExtension};
 use bytemuck::Pod;
 use either::Either;
+use itertools::izip;
 use rand::{SeedableRng, rngs::StdRng};
 
 use crate::{
 	fiat_shamir::HasherChallenger,
-	oracle::{MultilinearOracleSet, ShiftVariant},
+	oracle::{MultilinearOracleSet, OracleId, ShiftVariant},
 	polynomial::MultivariatePoly,
 	protocols::{
-		evalcheck::EvalcheckMultilinearClaim,
-		greedy_evalcheck::{prove, verify},
+		evalcheck::{EvalcheckMultilinearClaim, EvalcheckProver},
+		greedy_evalcheck::{
+			AdaptiveSwitchover, Error, Switchover, dry_run, prove, prove_retaining,
+			prove_streaming, prove_with_switchover, verify,
+		},
 		sumcheck::standard_switchover_heuristic,
 	},
-	transcript::ProverTranscript,
+	transcript::{ProverTranscript, VerifierTranscript},
 	transparent::select_row::SelectRow,
-	witness::MultilinearExtensionIndex,
+	witness::{self, MultilinearExtensionIndex},
 };
 
 type FExtension = BinaryField128b;
@@ -55,7 +60,14 @@ pub fn shift_one<P: PackedField>(evals: &mut [P], bits: usize, variant: ShiftVar
 	}
 }
 
-fn run_test_evalcheck_composite_projected<P, FExtension, PExtension>(n_vars: usize)
+#[allow(clippy::type_complexity)]
+fn composite_projected_instance<P, FExtension, PExtension>(
+	n_vars: usize,
+) -> (
+	MultilinearOracleSet<FExtension>,
+	MultilinearExtensionIndex<'static, PExtension>,
+	[EvalcheckMultilinearClaim<FExtension>; 2],
+)
 where
 	P: PackedField<Scalar = BinaryField1b> + Pod,
 	P::Scalar: TowerField,
@@ -156,6 +168,23 @@ where
 		eval,
 	};
 
+	(oracles, witness_index, [composite_claim, shifted_claim])
+}
+
+fn run_test_evalcheck_composite_projected<P, FExtension, PExtension>(n_vars: usize)
+where
+	P: PackedField<Scalar = BinaryField1b> + Pod,
+	P::Scalar: TowerField,
+	FExtension: TowerField + ExtensionField<BinaryField1b> + ExtensionField<FDomain>,
+	PExtension: PackedField<Scalar = FExtension>
+		+ PackedExtension<FDomain>
+		+ RepackedExtension<P>
+		+ RepackedExtension<PExtension>
+		+ Pod,
+{
+	let (mut oracles, mut witness_index, [composite_claim, shifted_claim]) =
+		composite_projected_instance::<P, FExtension, PExtension>(n_vars);
+	let backend = make_portable_backend();
 	let domain_factory = DefaultEvaluationDomainFactory::<FDomain>::default();
 
 	let mut transcript = ProverTranscript::<HasherChallenger<Groestl256>>::new();
@@ -178,3 +207,474 @@ where
 fn test_evalcheck_composite_projected() {
 	run_test_evalcheck_composite_projected::<PackedBinaryField128x1b, FExtension, PExtension>(8);
 }
+
+#[test]
+fn test_evalcheck_streaming_matches_bulk() {
+	let backend = make_portable_backend();
+	let domain_factory = DefaultEvaluationDomainFactory::<FDomain>::default();
+
+	let (mut oracles, mut witness_index, [composite_claim, shifted_claim]) =
+		composite_projected_instance::<PackedBinaryField128x1b, FExtension, PExtension>(8);
+	let (mut streamed_oracles, mut streamed_witness_index, _) =
+		composite_projected_instance::<PackedBinaryField128x1b, FExtension, PExtension>(8);
+	// The repeated claim is deduplicated across batches.
+	let claims = [composite_claim.clone(), shifted_claim, composite_claim];
+
+	let mut bulk_transcript = ProverTranscript::<HasherChallenger<Groestl256>>::new();
+	let bulk_output = prove::<_, _, FDomain, _, _>(
+		&mut oracles.clone(),
+		&mut witness_index,
+		claims.clone(),
+		standard_switchover_heuristic(-2),
+		&mut bulk_transcript,
+		&domain_factory,
+		&backend,
+	)
+	.unwrap();
+
+	let mut streamed_transcript = ProverTranscript::<HasherChallenger<Groestl256>>::new();
+	let streamed_output = prove_streaming::<_, _, FDomain, _, _>(
+		&mut streamed_oracles,
+		&mut streamed_witness_index,
+		claims.clone(),
+		NonZeroUsize::MIN,
+		standard_switchover_heuristic(-2),
+		&mut streamed_transcript,
+		&domain_factory,
+		&backend,
+	)
+	.unwrap();
+
+	assert_eq!(bulk_output.eval_claims, streamed_output.eval_claims);
+
+	let bulk_proof = bulk_transcript.finalize();
+	assert_eq!(bulk_proof, streamed_transcript.finalize());
+
+	let mut transcript = VerifierTranscript::<HasherChallenger<Groestl256>>::new(bulk_proof);
+	verify(&mut oracles, claims, &mut transcript).unwrap();
+	transcript.finalize().unwrap();
+}
+
+#[test]
+fn test_evalcheck_adaptive_switchover_matches_fixed() {
+	let backend = make_portable_backend();
+	let domain_factory = DefaultEvaluationDomainFactory::<FDomain>::default();
+
+	let (mut oracles, mut witness_index, claims) =
+		composite_projected_instance::<PackedBinaryField128x1b, FExtension, PExtension>(8);
+	let (mut adaptive_oracles, mut adaptive_witness_index, _) =
+		composite_projected_instance::<PackedBinaryField128x1b, FExtension, PExtension>(8);
+
+	let mut fixed_transcript = ProverTranscript::<HasherChallenger<Groestl256>>::new();
+	let fixed_output = prove::<_, _, FDomain, _, _>(
+		&mut oracles.clone(),
+		&mut witness_index,
+		claims.clone(),
+		standard_switchover_heuristic(-2),
+		&mut fixed_transcript,
+		&domain_factory,
+		&backend,
+	)
+	.unwrap();
+
+	let tuning = AdaptiveSwitchover::new(0);
+	let mut adaptive_transcript = ProverTranscript::<HasherChallenger<Groestl256>>::new();
+	let adaptive_output = prove_with_switchover::<_, _, FDomain, _, _>(
+		&mut adaptive_oracles,
+		&mut adaptive_witness_index,
+		claims.clone(),
+		NonZeroUsize::MAX,
+		Switchover::adaptive(&tuning),
+		&mut adaptive_transcript,
+		&domain_factory,
+		&backend,
+	)
+	.unwrap();
+
+	assert_eq!(fixed_output.eval_claims, adaptive_output.eval_claims);
+	assert_eq!(fixed_output.tuned_switchover_offset, None);
+	assert!(adaptive_output.tuned_switchover_offset.is_some());
+	assert_eq!(tuning.offset(), adaptive_output.tuned_switchover_offset);
+
+	let fixed_proof = fixed_transcript.finalize();
+	assert_eq!(fixed_proof, adaptive_transcript.finalize());
+
+	// A later proof reuses the tuned offset.
+	let (mut reused_oracles, mut reused_witness_index, _) =
+		composite_projected_instance::<PackedBinaryField128x1b, FExtension, PExtension>(8);
+	let mut reused_transcript = ProverTranscript::<HasherChallenger<Groestl256>>::new();
+	let reused_output = prove_with_switchover::<_, _, FDomain, _, _>(
+		&mut reused_oracles,
+		&mut reused_witness_index,
+		claims.clone(),
+		NonZeroUsize::MAX,
+		Switchover::adaptive(&tuning),
+		&mut reused_transcript,
+		&domain_factory,
+		&backend,
+	)
+	.unwrap();
+	assert_eq!(reused_output.tuned_switchover_offset, adaptive_output.tuned_switchover_offset);
+	assert_eq!(fixed_proof, reused_transcript.finalize());
+
+	let mut transcript = VerifierTranscript::<HasherChallenger<Groestl256>>::new(fixed_proof);
+	verify(&mut oracles, claims, &mut transcript).unwrap();
+	transcript.finalize().unwrap();
+}
+
+#[test]
+fn test_evalcheck_step_proof_bytes_sum_to_proof_size() {
+	let backend = make_portable_backend();
+	let domain_factory = DefaultEvaluationDomainFactory::<FDomain>::default();
+
+	let (mut oracles, mut witness_index, claims) =
+		composite_projected_instance::<PackedBinaryField128x1b, FExtension, PExtension>(8);
+
+	let mut transcript = ProverTranscript::<HasherChallenger<Groestl256>>::new();
+	let output = prove::<_, _, FDomain, _, _>(
+		&mut oracles,
+		&mut witness_index,
+		claims,
+		standard_switchover_heuristic(-2),
+		&mut transcript,
+		&domain_factory,
+		&backend,
+	)
+	.unwrap();
+
+	// The initial round, at least one sumcheck round and the final round without new claims.
+	assert!(output.step_proof_bytes.len() >= 3);
+	assert_eq!(output.step_proof_bytes.last(), Some(&0));
+
+	// The format version header is written before any evalcheck step.
+	let proof_len = transcript.finalize().len() - 1;
+	assert!(proof_len > 0);
+	assert_eq!(output.step_proof_bytes.iter().sum::<usize>(), proof_len);
+}
+
+#[test]
+fn test_validate_complete_reports_unfilled_committed_oracle() {
+	let n_vars = 4;
+	let mut rng = StdRng::seed_from_u64(0);
+
+	let mut oracles = MultilinearOracleSet::<FExtension>::new();
+	let filled_id = oracles.add_committed(n_vars, FExtension::TOWER_LEVEL);
+	let unfilled_id = oracles.add_committed(n_vars, FExtension::TOWER_LEVEL);
+	let unreachable_id = oracles.add_committed(n_vars, FExtension::TOWER_LEVEL);
+	#[allow(deprecated)]
+	let comp = arith_expr!(FExtension[x, y] = x * y);
+	let composite_id = oracles
+		.add_composite_mle(n_vars, [filled_id, unfilled_id], comp)
+		.unwrap();
+
+	let random_witness = |rng: &mut StdRng, n_vars: usize| {
+		let values = repeat_with(|| PExtension::random(&mut *rng))
+			.take(1 << n_vars)
+			.collect::<Vec<_>>();
+		MultilinearExtension::from_values(values)
+			.unwrap()
+			.specialize_arc_dyn()
+	};
+
+	let mut witness_index = MultilinearExtensionIndex::<PExtension>::new();
+	witness_index
+		.update_multilin_poly([
+			(filled_id, random_witness(&mut rng, n_vars)),
+			(composite_id, random_witness(&mut rng, n_vars)),
+		])
+		.unwrap();
+
+	assert_matches!(
+		witness_index.validate_complete(&oracles, [composite_id]),
+		Err(witness::Error::MissingWitness { id }) if id == unfilled_id
+	);
+	// Committed oracles that the claimed oracles do not depend on need no witness.
+	witness_index
+		.validate_complete(&oracles, [filled_id])
+		.unwrap();
+
+	#[cfg(feature = "debug_validate_witness")]
+	{
+		let eval_point = repeat_with(|| <FExtension as Field>::random(&mut rng))
+			.take(n_vars)
+			.collect::<Vec<_>>();
+		let claim = EvalcheckMultilinearClaim {
+			id: composite_id,
+			eval_point: eval_point.into(),
+			eval: FExtension::ZERO,
+		};
+		let mut transcript = ProverTranscript::<HasherChallenger<Groestl256>>::new();
+		let result = prove::<_, _, FDomain, _, _>(
+			&mut oracles.clone(),
+			&mut witness_index,
+			[claim],
+			standard_switchover_heuristic(-2),
+			&mut transcript,
+			DefaultEvaluationDomainFactory::<FDomain>::default(),
+			&make_portable_backend(),
+		);
+		assert_matches!(
+			result.err(),
+			Some(Error::Witness(witness::Error::MissingWitness { id })) if id == unfilled_id
+		);
+	}
+
+	witness_index
+		.update_multilin_poly([(unfilled_id, random_witness(&mut rng, n_vars - 1))])
+		.unwrap();
+	assert_matches!(
+		witness_index.validate_complete(&oracles, [composite_id]),
+		Err(witness::Error::IncorrectNumberOfVariables { id, expected, actual })
+			if id == unfilled_id && expected == n_vars && actual == n_vars - 1
+	);
+
+	witness_index
+		.update_multilin_poly([(unfilled_id, random_witness(&mut rng, n_vars))])
+		.unwrap();
+	witness_index
+		.validate_complete(&oracles, [composite_id])
+		.unwrap();
+	assert_matches!(
+		witness_index.validate_complete(&oracles, [composite_id, unreachable_id]),
+		Err(witness::Error::MissingWitness { id }) if id == unreachable_id
+	);
+}
+
+/// An instance with committed claims on two committed oracles, arising from claims on two
+/// composites and a shift at two points.
+fn committed_product_instance() -> (
+	MultilinearOracleSet<FExtension>,
+	MultilinearExtensionIndex<'static, PExtension>,
+	[EvalcheckMultilinearClaim<FExtension>; 3],
+) {
+	let n_vars = 6;
+	let mut rng = StdRng::seed_from_u64(0);
+	let backend = make_portable_backend();
+
+	let mut oracles = MultilinearOracleSet::<FExtension>::new();
+	let a_id = oracles.add_committed(n_vars, FExtension::TOWER_LEVEL);
+	let b_id = oracles.add_committed(n_vars, FExtension::TOWER_LEVEL);
+	#[allow(deprecated)]
+	let product = arith_expr!(FExtension[x, y] = x * y);
+	#[allow(deprecated)]
+	let product_plus = arith_expr!(FExtension[x, y] = x * y + y);
+	let product_id = oracles
+		.add_composite_mle(n_vars, [a_id, b_id], product)
+		.unwrap();
+	let product_plus_id = oracles
+		.add_composite_mle(n_vars, [b_id, a_id], product_plus)
+		.unwrap();
+	let shifted_id = oracles
+		.add_shifted(a_id, 1, n_vars, ShiftVariant::CircularLeft)
+		.unwrap();
+
+	let a = repeat_with(|| <FExtension as Field>::random(&mut rng))
+		.take(1 << n_vars)
+		.collect::<Vec<_>>();
+	let b = repeat_with(|| <FExtension as Field>::random(&mut rng))
+		.take(1 << n_vars)
+		.collect::<Vec<_>>();
+	let product_values = izip!(&a, &b).map(|(&a, &b)| a * b).collect::<Vec<_>>();
+	let product_plus_values = izip!(&a, &b).map(|(&a, &b)| b * a + a).collect::<Vec<_>>();
+	let mut shifted_values = pack_slice::<PExtension>(&a);
+	shift_one(&mut shifted_values, n_vars, ShiftVariant::CircularLeft);
+
+	let witnesses = [
+		(a_id, pack_slice::<PExtension>(&a)),
+		(b_id, pack_slice(&b)),
+		(product_id, pack_slice(&product_values)),
+		(product_plus_id, pack_slice(&product_plus_values)),
+		(shifted_id, shifted_values),
+	]
+	.map(|(id, values)| (id, MultilinearExtension::from_values(values).unwrap()));
+
+	let mut random_point = || {
+		repeat_with(|| <FExtension as Field>::random(&mut rng))
+			.take(n_vars)
+			.collect::<Vec<_>>()
+	};
+	let point = random_point();
+	let other_point = random_point();
+	let claims = [
+		(product_id, &point),
+		(product_plus_id, &other_point),
+		(shifted_id, &point),
+	]
+	.map(|(id, eval_point)| {
+		let (_, witness) = witnesses.iter().find(|(other, _)| *other == id).unwrap();
+		let query = backend.multilinear_query::<FExtension>(eval_point).unwrap();
+		EvalcheckMultilinearClaim {
+			id,
+			eval_point: eval_point.clone().into(),
+			eval: witness.evaluate(query.to_ref()).unwrap(),
+		}
+	});
+
+	let mut witness_index = MultilinearExtensionIndex::<PExtension>::new();
+	witness_index
+		.update_multilin_poly(witnesses.map(|(id, witness)| (id, witness.specialize_arc_dyn())))
+		.unwrap();
+
+	(oracles, witness_index, claims)
+}
+
+#[test]
+fn test_dry_run_predicts_committed_claims() {
+	let (mut oracles, mut witness_index, claims) = committed_product_instance();
+	let backend = make_portable_backend();
+
+	let predicted = dry_run(&oracles, claims.clone()).unwrap();
+
+	let mut transcript = ProverTranscript::<HasherChallenger<Groestl256>>::new();
+	let output = prove::<_, _, FDomain, _, _>(
+		&mut oracles.clone(),
+		&mut witness_index,
+		claims.clone(),
+		standard_switchover_heuristic(-2),
+		&mut transcript,
+		DefaultEvaluationDomainFactory::<FDomain>::default(),
+		&backend,
+	)
+	.unwrap();
+
+	let committed = output
+		.eval_claims
+		.iter()
+		.map(|claim| (claim.id, claim.eval_point.len()))
+		.collect::<Vec<_>>();
+	assert!(!committed.is_empty());
+	assert_eq!(predicted, committed);
+
+	let mut transcript = transcript.into_verifier();
+	verify(&mut oracles, claims, &mut transcript).unwrap();
+}
+
+#[test]
+fn test_prove_retaining_keeps_prover_reusable() {
+	let backend = make_portable_backend();
+	let domain_factory = DefaultEvaluationDomainFactory::<FDomain>::default();
+	let (mut oracles, mut witness_index, [product_claim, product_plus_claim, shifted_claim]) =
+		committed_product_instance();
+
+	let mut draining_transcript = ProverTranscript::<HasherChallenger<Groestl256>>::new();
+	let draining_claims = prove::<_, _, FDomain, _, _>(
+		&mut oracles.clone(),
+		&mut witness_index,
+		[product_claim.clone(), shifted_claim.clone()],
+		standard_switchover_heuristic(-2),
+		&mut draining_transcript,
+		&domain_factory,
+		&backend,
+	)
+	.unwrap()
+	.eval_claims;
+
+	let mut retaining_oracles = oracles.clone();
+	let mut evalcheck_prover = EvalcheckProver::new(&mut retaining_oracles, &mut witness_index);
+	let mut retaining_transcript = ProverTranscript::<HasherChallenger<Groestl256>>::new();
+	let retained = prove_retaining::<_, _, FDomain, _, _>(
+		&mut evalcheck_prover,
+		[product_claim.clone(), shifted_claim.clone()],
+		standard_switchover_heuristic(-2),
+		&mut retaining_transcript,
+		&domain_factory,
+		&backend,
+	)
+	.unwrap();
+	assert!(!retained.is_empty());
+	assert_eq!(retained, draining_claims);
+	assert_eq!(evalcheck_prover.committed_eval_claims(), &retained);
+
+	// The second stage continues the transcript of the first.
+	let retained_after = prove_retaining::<_, _, FDomain, _, _>(
+		&mut evalcheck_prover,
+		[product_plus_claim.clone()],
+		standard_switchover_heuristic(-2),
+		&mut retaining_transcript,
+		&domain_factory,
+		&backend,
+	)
+	.unwrap();
+	assert!(retained_after.len() > retained.len());
+	assert_eq!(retained_after[..retained.len()], retained);
+
+	let proof = retaining_transcript.finalize();
+	let first_stage_proof = draining_transcript.finalize();
+	assert_eq!(proof[..first_stage_proof.len()], first_stage_proof);
+
+	let mut transcript = VerifierTranscript::<HasherChallenger<Groestl256>>::new(proof);
+	let first_stage_claims =
+		verify(&mut oracles, [product_claim, shifted_claim], &mut transcript).unwrap();
+	assert_eq!(first_stage_claims, retained);
+	let second_stage_claims = verify(&mut oracles, [product_plus_claim], &mut transcript).unwrap();
+	assert_eq!(second_stage_claims, retained_after[retained.len()..]);
+	transcript.finalize().unwrap();
+}
+
+#[test]
+fn test_estimate_prove_cost() {
+	let estimate = |n_vars: usize| {
+		let mut oracles = MultilinearOracleSet::<FExtension>::new();
+		let a_id = oracles.add_committed(n_vars, FExtension::TOWER_LEVEL);
+		let b_id = oracles.add_committed(n_vars, FExtension::TOWER_LEVEL);
+		#[allow(deprecated)]
+		let product = arith_expr!(FExtension[x, y] = x * y);
+		let product_id = oracles
+			.add_composite_mle(n_vars, [a_id, b_id], product)
+			.unwrap();
+		let shifted_id = oracles
+			.add_shifted(a_id, 1, n_vars, ShiftVariant::CircularLeft)
+			.unwrap();
+
+		let claims = [product_id, shifted_id].map(|id| EvalcheckMultilinearClaim {
+			id,
+			eval_point: vec![FExtension::ZERO; n_vars].into(),
+			eval: FExtension::ZERO,
+		});
+		oracles.estimate_prove_cost(claims).unwrap()
+	};
+
+	// The MLE-check of the product evaluates a degree 2 composition with one multiplication at 3
+	// points and folds 2 multilinears, the bivariate sumcheck of the shift takes 5
+	// multiplications, and each of the 3 resulting committed claims takes 1 per vertex.
+	let small = estimate(6);
+	assert_eq!(small.n_mults, (3 * 2 + 2 + 5 + 3) << 6);
+	assert_eq!(small.memory_bytes, ((3 + 2 + 3) * 16) << 6);
+
+	let large = estimate(7);
+	assert_eq!(large.n_mults, 2 * small.n_mults);
+	assert_eq!(large.memory_bytes, 2 * small.memory_bytes);
+	assert!(small.is_within(&large));
+	assert!(!large.is_within(&small));
+
+	let oracles = MultilinearOracleSet::<FExtension>::new();
+	let claim = EvalcheckMultilinearClaim {
+		id: OracleId::from_index(0),
+		eval_point: Vec::new().into(),
+		eval: FExtension::ZERO,
+	};
+	assert_matches!(oracles.estimate_prove_cost([claim]), Err(Error::Oracle(_)));
+}
+
+#[test]
+fn test_estimate_prove_cost_of_deep_oracle_chain() {
+	// A chain deep enough to overflow the stack of a recursive traversal.
+	let mut oracles = MultilinearOracleSet::<FExtension>::new();
+	let committed_id = oracles.add_committed(1, FExtension::TOWER_LEVEL);
+	let mut id = committed_id;
+	for _ in 0..100_000 {
+		id = oracles
+			.add_linear_combination(1, [(id, FExtension::ONE)])
+			.unwrap();
+	}
+
+	let claim = |id| EvalcheckMultilinearClaim {
+		id,
+		eval_point: vec![FExtension::ZERO].into(),
+		eval: FExtension::ZERO,
+	};
+	assert_eq!(
+		oracles.estimate_prove_cost([claim(id)]).unwrap(),
+		oracles.estimate_prove_cost([claim(committed_id)]).unwrap()
+	);
+}
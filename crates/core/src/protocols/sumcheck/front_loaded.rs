@@ -16,7 +16,7 @@ use super::{
 use crate::{
 	fiat_shamir::{CanSample, Challenger},
 	protocols::sumcheck::{BatchSumcheckOutput, SumcheckClaim},
-	transcript::{TranscriptReader, VerifierTranscript},
+	transcript::{ProofTape, TranscriptReader, VerifierTranscript},
 };
 
 #[derive(Debug)]
@@ -233,6 +233,8 @@ where
 					None => 0,
 				};
 
+				// Reading exactly `degree` truncated coefficients bounds the degree of the round
+				// polynomial by the maximum individual degree of the remaining compositions.
 				let proof_vals = transcript.read_scalar_slice(degree)?;
 				let round_proof = RoundProof(RoundCoeffs(proof_vals));
 				self.last_coeffs_or_sum = CoeffsOrSums::Coeffs(round_proof.recover(sum));
@@ -274,7 +276,7 @@ where
 	/// Verifies a front-loaded batch sumcheck protocol execution.
 	pub fn run<Challenger_>(
 		mut self,
-		transcript: &mut VerifierTranscript<Challenger_>,
+		transcript: &mut VerifierTranscript<Challenger_, impl ProofTape>,
 	) -> Result<BatchSumcheckOutput<F>, Error>
 	where
 		Challenger_: Challenger,
@@ -5,6 +5,7 @@ use std::{
 	sync::Arc,
 };
 
+use assert_matches::assert_matches;
 use binius_field::{
 	BinaryField, BinaryField8b, BinaryField32b, BinaryField128b, ExtensionField, Field,
 	PackedBinaryField1x128b, PackedBinaryField4x32b, PackedExtension, PackedField,
@@ -27,8 +28,9 @@ use itertools::izip;
 use rand::{Rng, SeedableRng, rngs::StdRng};
 
 use super::{
-	BatchSumcheckOutput, SumcheckClaim,
+	BatchSumcheckOutput, Error, SumcheckClaim, VerificationError,
 	common::CompositeSumClaim,
+	constraint_set_sumcheck_claim,
 	front_loaded::BatchVerifier as FrontLoadedBatchVerifier,
 	prove::{
 		RegularSumcheckProver, batch_prove, front_loaded::BatchProver as FrontLoadedBatchProver,
@@ -38,6 +40,7 @@ use super::{
 use crate::{
 	composition::index_composition,
 	fiat_shamir::{CanSample, HasherChallenger},
+	oracle::{CompositePolyOracle, ConstraintSetBuilder, MultilinearOracleSet},
 	polynomial::{IdentityCompositionPoly, MultilinearComposite},
 	protocols::{
 		sumcheck::prove::SumcheckProver,
@@ -543,3 +546,82 @@ fn test_prove_verify_batch_front_loaded() {
 		},
 	]);
 }
+
+#[test]
+fn test_reject_over_degree_round_polynomials() {
+	type P = PackedBinaryField4x32b;
+	type FDomain = BinaryField8b;
+	type FE = BinaryField128b;
+	type PE = PackedBinaryField1x128b;
+
+	let evaluation_order = EvaluationOrder::LowToHigh;
+	let backend = make_portable_backend();
+	let domain_factory = IsomorphicEvaluationDomainFactory::<FDomain>::default();
+
+	// The prover sends degree 3 round polynomials for a claim that the verifier bounds by degree 2.
+	// The verifier reads two coefficients per round, so the rounds are misaligned with the proof
+	// and the final evaluation check fails.
+	let prover_shape = TestSumcheckClaimShape {
+		n_vars: 4,
+		degree: 3,
+	};
+	let verifier_shape = TestSumcheckClaimShape {
+		n_vars: 4,
+		degree: 2,
+	};
+	let make_sumcheck = |claim_shape| {
+		make_test_sumcheck::<FE, FDomain, P, PE, _>(
+			evaluation_order,
+			claim_shape,
+			StdRng::seed_from_u64(0),
+			&domain_factory,
+			&backend,
+		)
+	};
+
+	let (_, _, prover) = make_sumcheck(&prover_shape);
+	let (_, low_degree_claim, _) = make_sumcheck(&verifier_shape);
+	let mut transcript = ProverTranscript::<HasherChallenger<Groestl256>>::new();
+	batch_prove(vec![prover], &mut transcript).unwrap();
+	let mut transcript = transcript.into_verifier();
+	assert_matches!(
+		batch_verify(evaluation_order, &[low_degree_claim], &mut transcript),
+		Err(Error::Verification(VerificationError::IncorrectBatchEvaluation))
+	);
+
+	let (_, _, prover) = make_sumcheck(&prover_shape);
+	let (_, low_degree_claim, _) = make_sumcheck(&verifier_shape);
+	let mut transcript = ProverTranscript::<HasherChallenger<Groestl256>>::new();
+	let batch_prover = FrontLoadedBatchProver::new(vec![prover], &mut transcript).unwrap();
+	batch_prover.run(&mut transcript).unwrap();
+	let mut transcript = transcript.into_verifier();
+	let verifier = FrontLoadedBatchVerifier::new(&[low_degree_claim], &mut transcript).unwrap();
+	assert_matches!(
+		verifier.run(&mut transcript),
+		Err(Error::Verification(VerificationError::IncorrectBatchEvaluation))
+	);
+}
+
+#[test]
+fn test_degree_bound_matches_composite_oracle() {
+	type F = BinaryField128b;
+
+	let n_vars = 4;
+	let mut oracles = MultilinearOracleSet::<F>::new();
+	let ids = repeat_with(|| oracles.add_committed(n_vars, BinaryField8b::TOWER_LEVEL))
+		.take(3)
+		.collect::<Vec<_>>();
+	let composition = TestProductComposition::new(3);
+	let mut builder = ConstraintSetBuilder::new();
+	builder.add_sumcheck(ids.clone(), CompositionPoly::<F>::expression(&composition), F::ZERO);
+	let composite = CompositePolyOracle::new(
+		n_vars,
+		ids.iter().map(|&id| oracles[id].clone()).collect(),
+		composition,
+	)
+	.unwrap();
+
+	let constraint_set = builder.build_one(&oracles).unwrap();
+	let (claim, _) = constraint_set_sumcheck_claim(constraint_set).unwrap();
+	assert_eq!(claim.max_individual_degree(), composite.max_individual_degree().unwrap());
+}
@@ -74,6 +74,8 @@ where
 			active_index += 1;
 		}
 
+		// Reading exactly `max_degree` truncated coefficients bounds the degree of the round
+		// polynomial by the maximum individual degree of the active compositions.
 		let coeffs = transcript.message().read_scalar_slice(max_degree)?;
 		let round_proof = RoundProof(RoundCoeffs(coeffs));
 
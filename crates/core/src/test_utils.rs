@@ -0,0 +1,77 @@
+// Copyright 2025 Irreducible Inc.
+
+//! Utilities for tests of the proof system.
+
+/// Guards against proof size regressions.
+///
+/// The guard stores the expected byte length of a proof, such as
+/// `ProverTranscript::finalize().len()`, and fails a test if a proof grows beyond that baseline
+/// plus a tolerance. Proofs that shrink pass, but the baseline should then be lowered to keep the
+/// guard tight.
+#[derive(Debug, Clone, Copy)]
+pub struct ProofSizeGuard {
+	name: &'static str,
+	baseline: usize,
+	tolerance: usize,
+}
+
+impl ProofSizeGuard {
+	/// Creates a guard for the proof `name` allowing no growth over `baseline` bytes.
+	pub const fn new(name: &'static str, baseline: usize) -> Self {
+		Self {
+			name,
+			baseline,
+			tolerance: 0,
+		}
+	}
+
+	/// Allows proofs to grow by up to `tolerance` bytes over the baseline.
+	pub const fn with_tolerance(self, tolerance: usize) -> Self {
+		Self { tolerance, ..self }
+	}
+
+	/// The largest proof size in bytes that passes the guard.
+	pub const fn max_size(&self) -> usize {
+		self.baseline + self.tolerance
+	}
+
+	/// Returns whether a proof of `size` bytes passes the guard.
+	pub const fn check(&self, size: usize) -> bool {
+		size <= self.max_size()
+	}
+
+	/// Panics if a proof of `size` bytes does not pass the guard.
+	#[track_caller]
+	pub fn assert(&self, size: usize) {
+		assert!(
+			self.check(size),
+			"proof size regression in {}: {size} bytes exceeds the baseline of {} bytes by {} \
+			 (tolerance {} bytes)",
+			self.name,
+			self.baseline,
+			size - self.baseline,
+			self.tolerance,
+		);
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn test_proof_size_guard() {
+		let guard = ProofSizeGuard::new("test", 100).with_tolerance(10);
+		assert_eq!(guard.max_size(), 110);
+		assert!(guard.check(90));
+		assert!(guard.check(110));
+		assert!(!guard.check(111));
+		guard.assert(105);
+	}
+
+	#[test]
+	#[should_panic(expected = "proof size regression in test: 101 bytes")]
+	fn test_proof_size_guard_regression() {
+		ProofSizeGuard::new("test", 100).assert(101);
+	}
+}
@@ -0,0 +1,178 @@
+// Copyright 2025 Irreducible Inc.
+
+//! Comparison of proofs against a description of their layout, used to debug nondeterminism.
+//!
+//! Two proving runs on the same statement and witness must produce identical proofs. When they do
+//! not, the first differing byte is only useful together with the meaning of the proof field it
+//! belongs to, which is described by a [`ProofSchema`].
+
+use super::{TranscriptOp, TranscriptOpKind};
+
+/// A field of a proof, a run of consecutive bytes with a description of their meaning.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ProofField {
+	pub label: String,
+	pub len: usize,
+}
+
+/// The layout of a proof as a sequence of labelled fields.
+///
+/// A schema starts with the format version byte heading every proof.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ProofSchema {
+	fields: Vec<ProofField>,
+}
+
+impl ProofSchema {
+	/// Creates a schema containing only the format version byte.
+	pub fn new() -> Self {
+		Self {
+			fields: vec![ProofField {
+				label: "format version".to_string(),
+				len: 1,
+			}],
+		}
+	}
+
+	/// Creates the schema of the proof written by a transcript with the given operation log.
+	///
+	/// Messages and decommitments are written to the proof, so every one of them becomes a field
+	/// labelled with its position in the log, its kind and the serialized type. The operation log
+	/// is only recorded in builds with `debug_assertions`.
+	pub fn from_operation_log(ops: &[TranscriptOp]) -> Self {
+		let mut schema = Self::new();
+		for (index, op) in ops.iter().enumerate() {
+			if matches!(op.kind, TranscriptOpKind::Message | TranscriptOpKind::Decommitment) {
+				schema.push(format!("{:?} {index}: {}", op.kind, op.type_name), op.bytes.len());
+			}
+		}
+		schema
+	}
+
+	/// Appends a field of `len` bytes.
+	pub fn push(&mut self, label: impl Into<String>, len: usize) {
+		self.fields.push(ProofField {
+			label: label.into(),
+			len,
+		});
+	}
+
+	pub fn fields(&self) -> &[ProofField] {
+		&self.fields
+	}
+
+	/// The length in bytes of a proof with this schema.
+	pub fn proof_len(&self) -> usize {
+		self.fields.iter().map(|field| field.len).sum()
+	}
+}
+
+impl Default for ProofSchema {
+	fn default() -> Self {
+		Self::new()
+	}
+}
+
+/// The first difference between two proofs, see [`diff`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TranscriptDiff {
+	/// The offset of the first byte at which the proofs differ, or at which the shorter proof
+	/// ends.
+	pub byte_offset: usize,
+	/// The index of the schema field containing the byte, which is the number of fields if the
+	/// byte is past the end of the schema.
+	pub field_index: usize,
+	/// The label of the field containing the byte.
+	pub label: String,
+}
+
+/// Finds the first field in which the proofs `a` and `b` differ, according to `schema`.
+///
+/// Returns `None` if the proofs are identical. A proof that is a strict prefix of the other
+/// differs at the byte following its end.
+pub fn diff(a: &[u8], b: &[u8], schema: &ProofSchema) -> Option<TranscriptDiff> {
+	let byte_offset = match a.iter().zip(b).position(|(a, b)| a != b) {
+		Some(offset) => offset,
+		None if a.len() == b.len() => return None,
+		None => a.len().min(b.len()),
+	};
+
+	let mut field_start = 0;
+	for (field_index, field) in schema.fields.iter().enumerate() {
+		if byte_offset < field_start + field.len {
+			return Some(TranscriptDiff {
+				byte_offset,
+				field_index,
+				label: field.label.clone(),
+			});
+		}
+		field_start += field.len;
+	}
+	Some(TranscriptDiff {
+		byte_offset,
+		field_index: schema.fields.len(),
+		label: "past the end of the schema".to_string(),
+	})
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn test_diff() {
+		let mut schema = ProofSchema::new();
+		schema.push("a", 2);
+		schema.push("b", 3);
+		let proof = [1, 10, 11, 20, 21, 22];
+
+		assert_eq!(diff(&proof, &proof, &schema), None);
+
+		let mut corrupted = proof;
+		corrupted[4] ^= 1;
+		assert_eq!(
+			diff(&proof, &corrupted, &schema),
+			Some(TranscriptDiff {
+				byte_offset: 4,
+				field_index: 2,
+				label: "b".to_string(),
+			})
+		);
+
+		let diff_short = diff(&proof, &proof[..3], &schema).unwrap();
+		assert_eq!((diff_short.byte_offset, diff_short.field_index), (3, 2));
+
+		let long = [1, 10, 11, 20, 21, 22, 30];
+		let diff_long = diff(&proof, &long, &schema).unwrap();
+		assert_eq!((diff_long.byte_offset, diff_long.field_index), (6, 3));
+	}
+
+	#[test]
+	#[cfg(debug_assertions)]
+	fn test_schema_from_operation_log() {
+		use binius_field::{BinaryField8b, BinaryField32b, BinaryField128b};
+		use binius_hash::groestl::Groestl256;
+
+		use crate::{
+			fiat_shamir::{CanSample, HasherChallenger},
+			transcript::ProverTranscript,
+		};
+
+		let mut transcript =
+			ProverTranscript::<HasherChallenger<Groestl256>>::new().with_operation_log();
+		transcript.message().write_scalar(BinaryField32b::new(1));
+		let _: BinaryField128b = transcript.sample();
+		transcript
+			.decommitment()
+			.write_scalar(BinaryField8b::new(2));
+
+		let schema = ProofSchema::from_operation_log(transcript.operation_log());
+		let lens = schema
+			.fields()
+			.iter()
+			.map(|field| field.len)
+			.collect::<Vec<_>>();
+		assert_eq!(lens, [1, 4, 1]);
+		assert_eq!(schema.proof_len(), transcript.finalize().len());
+	}
+}
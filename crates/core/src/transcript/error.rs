@@ -4,8 +4,33 @@
 pub enum Error {
 	#[error("Transcript is not empty, {remaining} bytes")]
 	TranscriptNotEmpty { remaining: usize },
-	#[error("Not enough bytes in the buffer")]
-	NotEnoughBytes,
+	/// A read needed more bytes than remain in the proof, whether it is buffered or streamed.
+	///
+	/// `needed` is the size of the value read, or `None` for values whose size is only found
+	/// while deserializing them.
+	#[error(
+		"Proof is unexpectedly short, needed {} but {available} remain",
+		display_needed(*.needed)
+	)]
+	ProofUnexpectedlyShort {
+		needed: Option<usize>,
+		available: usize,
+	},
+	#[error("Transcript format version {found} does not match the expected version {expected}")]
+	TranscriptVersionMismatch { found: u8, expected: u8 },
+	#[error("No nonce among the first {max_iters} satisfies the grinding predicate")]
+	GrindingFailed { max_iters: usize },
+	#[error("The challenge for the grinding nonce {nonce} does not satisfy the predicate")]
+	GrindingPredicateFailed { nonce: u64 },
+	#[error("The grinding nonce achieves difficulty {achieved} but {required} is required")]
+	InsufficientWork { achieved: u32, required: u32 },
 	#[error("Serialization error: {0}")]
 	Serialization(#[from] binius_utils::SerializationError),
 }
+
+fn display_needed(needed: Option<usize>) -> String {
+	match needed {
+		Some(needed) => format!("{needed} bytes"),
+		None => "more bytes".to_string(),
+	}
+}
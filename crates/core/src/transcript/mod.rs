@@ -12,17 +12,42 @@
 //! secure to write a Merkle tree root to the transcript tape, sample a random index, then provide
 //! the Merkle leaf opening at that index in the advice tape.
 
+mod diff;
 mod error;
+mod operation_log;
+mod read_tape;
+mod schema;
 
-use std::{fs::File, io::Write, iter::repeat_with, slice};
+use std::{
+	fs::File,
+	io::{Read, Write},
+	iter::repeat_with,
+	slice,
+};
 
 use binius_field::{PackedField, TowerField};
-use binius_utils::{DeserializeBytes, SerializationMode, SerializeBytes};
+use binius_hash::groestl::Groestl256;
+use binius_utils::{DeserializeBytes, SerializationError, SerializationMode, SerializeBytes};
 use bytes::{Buf, BufMut, Bytes, BytesMut, buf::UninitSlice};
+pub use diff::*;
+use digest::Output;
 pub use error::Error;
+use operation_log::OperationLog;
+pub use operation_log::{TranscriptOp, TranscriptOpKind};
+pub use read_tape::{DEFAULT_READ_WINDOW, ReadTape};
+pub use schema::TranscriptField;
 use tracing::warn;
 
-use crate::fiat_shamir::{CanSample, CanSampleBits, Challenger};
+use crate::fiat_shamir::{CanSample, CanSampleBits, CanSampleWithSpec, ChallengeSpec, Challenger};
+
+/// The version of the proof format written by [`ProverTranscript::new`].
+///
+/// The version is the first byte of every proof. It is not observed by the challenger, so it
+/// does not affect any sampled challenge.
+pub const TRANSCRIPT_FORMAT_VERSION: u8 = 1;
+
+/// The 32-byte Grøstl-256 digest of a Merkle tree root.
+pub type GroestlDigest = Output<Groestl256>;
 
 /// Prover transcript over some Challenger that writes to the internal tape and `CanSample<F:
 /// TowerField>`
@@ -33,19 +58,42 @@ use crate::fiat_shamir::{CanSample, CanSampleBits, Challenger};
 pub struct ProverTranscript<Challenger> {
 	combined: FiatShamirBuf<BytesMut, Challenger>,
 	debug_assertions: bool,
+	log: OperationLog,
+	/// How the challenger was initialized, see [`Self::with_seed`] and [`Self::fork`].
+	origin: ChallengerOrigin<Challenger>,
+}
+
+/// The initial state of the challenger of a [`ProverTranscript`], which the verifier transcript
+/// returned by [`ProverTranscript::into_verifier`] starts from.
+#[derive(Debug)]
+enum ChallengerOrigin<Challenger> {
+	Default,
+	Seed(Vec<u8>),
+	Fork(Challenger),
 }
 
 /// Verifier transcript over some Challenger that reads from the internal tape and `CanSample<F:
 /// TowerField>`
 ///
+/// The proof is read from a [`ProofTape`], which is either a buffer holding the whole proof or a
+/// [`ReadTape`] streaming it from an [`io::Read`](std::io::Read) source.
+///
 /// You must manually call the destructor with `finalize()` to check anything that's written is
 /// fully read out
 #[derive(Debug, Clone)]
-pub struct VerifierTranscript<Challenger> {
-	combined: FiatShamirBuf<Bytes, Challenger>,
+pub struct VerifierTranscript<Challenger, Tape: ProofTape = Bytes> {
+	combined: FiatShamirBuf<Tape, Challenger>,
 	debug_assertions: bool,
+	log: OperationLog,
 }
 
+/// A source of proof bytes for a [`VerifierTranscript`].
+pub trait ProofTape: Buf {}
+
+impl ProofTape for Bytes {}
+
+impl<R: Read> ProofTape for ReadTape<R> {}
+
 #[derive(Debug, Default, Clone)]
 struct FiatShamirBuf<Inner, Challenger> {
 	buffer: Inner,
@@ -105,16 +153,72 @@ impl<Challenger_: Default + Challenger> ProverTranscript<Challenger_> {
 	/// By default debug assertions are set to the feature flag `debug_assertions`. You may also
 	/// change the debug flag with [`Self::set_debug`].
 	pub fn new() -> Self {
+		Self::with_format_version(TRANSCRIPT_FORMAT_VERSION)
+	}
+
+	/// Creates a new prover transcript whose proof is headed by the given format version.
+	pub fn with_format_version(version: u8) -> Self {
+		let mut combined = FiatShamirBuf::<BytesMut, Challenger_>::default();
+		combined.buffer.put_u8(version);
 		Self {
-			combined: Default::default(),
+			combined,
 			debug_assertions: cfg!(debug_assertions),
+			log: OperationLog::default(),
+			origin: ChallengerOrigin::Default,
 		}
 	}
 
-	pub fn into_verifier(self) -> VerifierTranscript<Challenger_> {
+	/// Creates a new prover transcript whose challenges are all bound to `seed`.
+	///
+	/// The seed is observed before any message, so it may be used to bind the proof to a circuit
+	/// or to public parameters. The seed is not written to the proof, so the verifier transcript
+	/// must be created with [`VerifierTranscript::with_seed`] on the same seed.
+	pub fn with_seed(seed: &[u8]) -> Self {
+		let mut transcript = Self::new();
+		observe_seed(&mut transcript.combined.challenger, seed);
+		transcript.origin = ChallengerOrigin::Seed(seed.to_vec());
+		transcript
+	}
+
+	pub fn into_verifier(mut self) -> VerifierTranscript<Challenger_> {
+		let origin = std::mem::replace(&mut self.origin, ChallengerOrigin::Default);
 		let transcript = self.finalize();
 
-		VerifierTranscript::new(transcript)
+		match origin {
+			ChallengerOrigin::Default => VerifierTranscript::try_new(transcript),
+			ChallengerOrigin::Seed(seed) => VerifierTranscript::with_seed(transcript, &seed),
+			ChallengerOrigin::Fork(challenger) => VerifierTranscript::from_tape_with_challenger(
+				Bytes::from(transcript),
+				TRANSCRIPT_FORMAT_VERSION,
+				challenger,
+			),
+		}
+		.expect("the prover transcript is headed by the current format version")
+	}
+}
+
+impl<Challenger_: Challenger + Clone> ProverTranscript<Challenger_> {
+	/// Creates a transcript for an independent proof whose challenges are bound to everything
+	/// observed by this transcript so far, and to `suffix`.
+	///
+	/// The fork starts from a copy of the challenger, which then observes `suffix`, and writes to
+	/// an empty proof tape. Forks with distinct suffixes, for example the indices of the workers
+	/// of a distributed prover, sample independent challenges, while all of them depend on the
+	/// shared prefix. Each fork is verified by forking the verifier transcript at the same point
+	/// with [`VerifierTranscript::fork`]. This transcript is left unchanged.
+	pub fn fork(&self, suffix: &[u8]) -> Self {
+		let mut combined = FiatShamirBuf {
+			buffer: BytesMut::new(),
+			challenger: self.combined.challenger.clone(),
+		};
+		combined.buffer.put_u8(TRANSCRIPT_FORMAT_VERSION);
+		observe_seed(&mut combined.challenger, suffix);
+		Self {
+			origin: ChallengerOrigin::Fork(combined.challenger.clone()),
+			combined,
+			debug_assertions: self.debug_assertions,
+			log: OperationLog::default(),
+		}
 	}
 }
 
@@ -165,6 +269,42 @@ impl<Challenger_: Challenger> ProverTranscript<Challenger_> {
 		self.debug_assertions = debug;
 	}
 
+	/// Enables recording of the operations performed on the transcript, which are returned by
+	/// [`Self::operation_log`].
+	///
+	/// Recording copies the bytes of every operation, so it is off by default. It is only
+	/// possible in builds with `debug_assertions`, and this method does nothing in other builds.
+	pub fn with_operation_log(mut self) -> Self {
+		self.log.enable();
+		self
+	}
+
+	/// Returns the log of all operations performed on the transcript so far, which is empty
+	/// unless recording was enabled with [`Self::with_operation_log`].
+	///
+	/// The log can be passed to [`VerifierTranscript::set_expected_operation_log`] to check that
+	/// the verifier reads and samples in exactly the order the prover wrote and sampled.
+	#[cfg(debug_assertions)]
+	pub fn operation_log(&self) -> &[TranscriptOp] {
+		self.log.ops()
+	}
+
+	/// Returns the number of bytes written to the proof tape so far.
+	///
+	/// The returned value can be used as a mark for [`Self::bytes_since`].
+	pub fn position(&self) -> usize {
+		self.combined.buffer.len()
+	}
+
+	/// Returns the number of bytes written to the proof tape since `mark`.
+	///
+	/// ## Panics
+	///
+	/// * If `mark` is greater than the current [`Self::position`].
+	pub fn bytes_since(&self, mark: usize) -> usize {
+		self.position() - mark
+	}
+
 	/// Returns a writeable buffer that only observes the data written, without writing it to the
 	/// proof tape.
 	///
@@ -176,6 +316,8 @@ impl<Challenger_: Challenger> ProverTranscript<Challenger_> {
 		TranscriptWriter {
 			buffer: self.combined.challenger.observer(),
 			debug_assertions: self.debug_assertions,
+			kind: TranscriptOpKind::Observe,
+			log: &mut self.log,
 		}
 	}
 
@@ -191,6 +333,8 @@ impl<Challenger_: Challenger> ProverTranscript<Challenger_> {
 		TranscriptWriter {
 			buffer: &mut self.combined.buffer,
 			debug_assertions: self.debug_assertions,
+			kind: TranscriptOpKind::Decommitment,
+			log: &mut self.log,
 		}
 	}
 
@@ -204,24 +348,274 @@ impl<Challenger_: Challenger> ProverTranscript<Challenger_> {
 		TranscriptWriter {
 			buffer: &mut self.combined,
 			debug_assertions: self.debug_assertions,
+			kind: TranscriptOpKind::Message,
+			log: &mut self.log,
+		}
+	}
+}
+
+impl<Challenger_: Challenger> ProverTranscript<Challenger_> {
+	/// Writes a Merkle tree root as an observed message.
+	///
+	/// The root is written as its raw 32 bytes, which are the same bytes as written by
+	/// `message().write(root)`, without the per-value overhead of the generic serialization. A
+	/// commitment must be written before sampling the challenges that select its openings, so that
+	/// the challenges depend on the commitment.
+	pub fn write_root(&mut self, root: &GroestlDigest) {
+		self.message().write_bytes(root);
+	}
+}
+
+impl<Challenger_: Challenger, Tape: ProofTape> VerifierTranscript<Challenger_, Tape> {
+	/// Reads a Merkle tree root written with [`ProverTranscript::write_root`].
+	pub fn read_root(&mut self) -> Result<GroestlDigest, Error> {
+		let mut root = GroestlDigest::default();
+		self.message().read_bytes(&mut root)?;
+		Ok(root)
+	}
+}
+
+/// The number of bytes of a grinding challenge, see [`ProverTranscript::grind`].
+pub const GRINDING_CHALLENGE_BYTES: usize = 32;
+
+impl<Challenger_: Challenger + Clone> ProverTranscript<Challenger_> {
+	/// Grinds for a nonce whose challenge satisfies `predicate`, as a proof of work.
+	///
+	/// The nonces `0, 1, ...` are tried in order, each by observing it on a copy of the challenger
+	/// and sampling [`GRINDING_CHALLENGE_BYTES`] bytes from the copy. The first nonce whose
+	/// challenge satisfies `predicate` is written to the transcript as a message, and its challenge
+	/// is sampled, so the transcript continues as it does in [`VerifierTranscript::verify_grind`].
+	///
+	/// ## Throws
+	///
+	/// * `Error::GrindingFailed` if none of the first `max_iters` nonces satisfies `predicate`
+	pub fn grind(
+		&mut self,
+		predicate: impl Fn(&[u8]) -> bool,
+		max_iters: usize,
+	) -> Result<u64, Error> {
+		let mut challenge = [0u8; GRINDING_CHALLENGE_BYTES];
+		for nonce in 0..max_iters as u64 {
+			let mut challenger = self.combined.challenger.clone();
+			challenger.observer().put_slice(&nonce.to_le_bytes());
+			challenger.sampler().copy_to_slice(&mut challenge);
+			if predicate(&challenge) {
+				write_u64(&mut self.message(), nonce);
+				sample_grinding_challenge_logged(&mut self.combined.challenger, &mut self.log);
+				return Ok(nonce);
+			}
 		}
+		Err(Error::GrindingFailed { max_iters })
 	}
 }
 
+impl<Challenger_: Challenger, Tape: ProofTape> VerifierTranscript<Challenger_, Tape> {
+	/// Reads a nonce written with [`ProverTranscript::grind`] and checks that its challenge
+	/// satisfies `predicate`.
+	///
+	/// ## Throws
+	///
+	/// * `Error::GrindingPredicateFailed` if the challenge does not satisfy `predicate`
+	pub fn verify_grind(&mut self, predicate: impl Fn(&[u8]) -> bool) -> Result<u64, Error> {
+		let nonce = read_u64(&mut self.message())?;
+		let challenge =
+			sample_grinding_challenge_logged(&mut self.combined.challenger, &mut self.log);
+		if !predicate(&challenge) {
+			return Err(Error::GrindingPredicateFailed { nonce });
+		}
+		Ok(nonce)
+	}
+
+	/// Reads a nonce written with [`ProverTranscript::grind`] and returns the difficulty of its
+	/// challenge, see [`grinding_difficulty`].
+	///
+	/// ## Throws
+	///
+	/// * `Error::InsufficientWork` if the difficulty is less than `required`
+	pub fn verify_grind_difficulty(&mut self, required: u32) -> Result<u32, Error> {
+		read_u64(&mut self.message())?;
+		let challenge =
+			sample_grinding_challenge_logged(&mut self.combined.challenger, &mut self.log);
+		let achieved = grinding_difficulty(&challenge);
+		if achieved < required {
+			return Err(Error::InsufficientWork { achieved, required });
+		}
+		Ok(achieved)
+	}
+}
+
+/// The difficulty of a grinding challenge, its number of leading zero bits.
+///
+/// Bits are counted from the most significant bit of the first byte, so a prover grinding for
+/// difficulty `bits` uses the predicate `|challenge| grinding_difficulty(challenge) >= bits`.
+pub fn grinding_difficulty(challenge: &[u8]) -> u32 {
+	let mut bits = 0;
+	for &byte in challenge {
+		if byte != 0 {
+			return bits + byte.leading_zeros();
+		}
+		bits += 8;
+	}
+	bits
+}
+
+fn sample_grinding_challenge_logged(
+	challenger: &mut impl Challenger,
+	log: &mut OperationLog,
+) -> [u8; GRINDING_CHALLENGE_BYTES] {
+	let mut challenge = [0u8; GRINDING_CHALLENGE_BYTES];
+	challenger.sampler().copy_to_slice(&mut challenge);
+	if log.is_enabled() {
+		log.push(TranscriptOp::new::<[u8; GRINDING_CHALLENGE_BYTES]>(
+			TranscriptOpKind::Sample,
+			challenge.to_vec(),
+		));
+	}
+	challenge
+}
+
 impl<Challenger_: Default + Challenger> VerifierTranscript<Challenger_> {
+	/// Creates a verifier transcript reading a proof of the current format version, skipping its
+	/// version header without checking it.
+	///
+	/// This is meant for callers that know the format version of the proof, such as a test
+	/// reading back the proof it just made. Use [`Self::try_new`] to reject a proof missing its
+	/// version header or of a different format version.
 	pub fn new(vec: Vec<u8>) -> Self {
+		let mut buffer = Bytes::from(vec);
+		buffer.advance(buffer.remaining().min(1));
+		Self::from_parts(buffer, Challenger_::default())
+	}
+
+	/// Creates a verifier transcript reading a proof of the current format version, failing if
+	/// the proof does not start with the version header.
+	///
+	/// ## Throws
+	///
+	/// * `Error::ProofUnexpectedlyShort` if the proof is missing its version header
+	/// * `Error::TranscriptVersionMismatch` if the proof has a different format version
+	pub fn try_new(vec: Vec<u8>) -> Result<Self, Error> {
+		Self::with_format_version(vec, TRANSCRIPT_FORMAT_VERSION)
+	}
+
+	/// Creates a verifier transcript reading a proof of the given format version.
+	pub fn with_format_version(vec: Vec<u8>, expected: u8) -> Result<Self, Error> {
+		Self::from_tape(Bytes::from(vec), expected)
+	}
+
+	/// Creates a verifier transcript reading a proof made with
+	/// [`ProverTranscript::with_seed`] on the same seed.
+	///
+	/// ## Throws
+	///
+	/// * `Error::ProofUnexpectedlyShort` if the proof is missing its version header
+	/// * `Error::TranscriptVersionMismatch` if the proof has a different format version
+	pub fn with_seed(vec: Vec<u8>, seed: &[u8]) -> Result<Self, Error> {
+		let mut transcript = Self::try_new(vec)?;
+		observe_seed(&mut transcript.combined.challenger, seed);
+		Ok(transcript)
+	}
+}
+
+impl<Challenger_: Challenger + Clone, Tape: ProofTape> VerifierTranscript<Challenger_, Tape> {
+	/// Creates a verifier transcript reading the proof of a transcript made with
+	/// [`ProverTranscript::fork`] on the same `suffix`, from a prover transcript in the state of
+	/// this one.
+	///
+	/// ## Throws
+	///
+	/// * `Error::ProofUnexpectedlyShort` if the proof is missing its version header
+	/// * `Error::TranscriptVersionMismatch` if the proof has a different format version
+	pub fn fork(
+		&self,
+		vec: Vec<u8>,
+		suffix: &[u8],
+	) -> Result<VerifierTranscript<Challenger_>, Error> {
+		let mut challenger = self.combined.challenger.clone();
+		observe_seed(&mut challenger, suffix);
+		let mut transcript = VerifierTranscript::from_tape_with_challenger(
+			Bytes::from(vec),
+			TRANSCRIPT_FORMAT_VERSION,
+			challenger,
+		)?;
+		transcript.debug_assertions = self.debug_assertions;
+		Ok(transcript)
+	}
+}
+
+/// Observes `seed`, prefixed by its length so that it is not confused with the messages observed
+/// after it.
+fn observe_seed(challenger: &mut impl Challenger, seed: &[u8]) {
+	let observer = challenger.observer();
+	observer.put_u64_le(seed.len() as u64);
+	observer.put_slice(seed);
+}
+
+impl<Challenger_: Default + Challenger, R: Read> VerifierTranscript<Challenger_, ReadTape<R>> {
+	/// Creates a verifier transcript streaming a proof of `len` bytes of the current format
+	/// version from `reader`.
+	///
+	/// Only a window of [`DEFAULT_READ_WINDOW`] bytes of the proof is held in memory at a time.
+	///
+	/// ## Throws
+	///
+	/// * `Error::ProofUnexpectedlyShort` if the proof is missing its version header
+	/// * `Error::TranscriptVersionMismatch` if the proof has a different format version
+	pub fn from_reader(reader: R, len: usize) -> Result<Self, Error> {
+		Self::from_read_tape(ReadTape::new(reader, len))
+	}
+
+	/// Creates a verifier transcript streaming a proof of the current format version from `tape`.
+	pub fn from_read_tape(tape: ReadTape<R>) -> Result<Self, Error> {
+		Self::from_tape(tape, TRANSCRIPT_FORMAT_VERSION)
+	}
+
+	/// Returns the I/O error that truncated the proof stream, if any.
+	///
+	/// A read failing with [`Error::ProofUnexpectedlyShort`] may be caused by such an error.
+	pub const fn read_error(&self) -> Option<&std::io::Error> {
+		self.combined.buffer.read_error()
+	}
+}
+
+impl<Challenger_: Default + Challenger, Tape: ProofTape> VerifierTranscript<Challenger_, Tape> {
+	fn from_tape(buffer: Tape, expected: u8) -> Result<Self, Error> {
+		Self::from_tape_with_challenger(buffer, expected, Challenger_::default())
+	}
+}
+
+impl<Challenger_: Challenger, Tape: ProofTape> VerifierTranscript<Challenger_, Tape> {
+	/// Creates a verifier transcript after reading the format version header of the proof.
+	fn from_tape_with_challenger(
+		mut buffer: Tape,
+		expected: u8,
+		challenger: Challenger_,
+	) -> Result<Self, Error> {
+		if !buffer.has_remaining() {
+			return Err(Error::ProofUnexpectedlyShort {
+				needed: Some(1),
+				available: 0,
+			});
+		}
+		let found = buffer.get_u8();
+		if found != expected {
+			return Err(Error::TranscriptVersionMismatch { found, expected });
+		}
+		Ok(Self::from_parts(buffer, challenger))
+	}
+
+	fn from_parts(buffer: Tape, challenger: Challenger_) -> Self {
 		Self {
-			combined: FiatShamirBuf {
-				challenger: Challenger_::default(),
-				buffer: Bytes::from(vec),
-			},
+			combined: FiatShamirBuf { challenger, buffer },
 			debug_assertions: cfg!(debug_assertions),
+			log: OperationLog::default(),
 		}
 	}
 }
 
-impl<Challenger_: Challenger> VerifierTranscript<Challenger_> {
+impl<Challenger_: Challenger, Tape: ProofTape> VerifierTranscript<Challenger_, Tape> {
 	pub fn finalize(self) -> Result<(), Error> {
+		self.log.assert_complete();
 		if self.combined.buffer.has_remaining() {
 			return Err(Error::TranscriptNotEmpty {
 				remaining: self.combined.buffer.remaining(),
@@ -234,6 +628,37 @@ impl<Challenger_: Challenger> VerifierTranscript<Challenger_> {
 		self.debug_assertions = debug;
 	}
 
+	/// Enables recording of the operations performed on the transcript, which are returned by
+	/// [`Self::operation_log`].
+	///
+	/// Recording copies the bytes of every operation, so it is off by default. It is only
+	/// possible in builds with `debug_assertions`, and this method does nothing in other builds.
+	pub fn with_operation_log(mut self) -> Self {
+		self.log.enable();
+		self
+	}
+
+	/// Returns the log of all operations performed on the transcript so far, which is empty
+	/// unless recording was enabled with [`Self::with_operation_log`] or
+	/// [`Self::set_expected_operation_log`].
+	#[cfg(debug_assertions)]
+	pub fn operation_log(&self) -> &[TranscriptOp] {
+		self.log.ops()
+	}
+
+	/// Sets the operation log recorded by the prover, against which every subsequent operation
+	/// is checked. This enables recording on this transcript.
+	///
+	/// ## Panics
+	///
+	/// * If any operation was already performed on this transcript.
+	/// * On any later operation that does not match the prover's operation at the same position,
+	///   and in [`Self::finalize`] if the prover performed more operations than the verifier.
+	#[cfg(debug_assertions)]
+	pub fn set_expected_operation_log(&mut self, log: Vec<TranscriptOp>) {
+		self.log.set_expected(log);
+	}
+
 	/// Returns a writable buffer that only observes the data written, without reading it from the
 	/// proof tape.
 	///
@@ -245,6 +670,8 @@ impl<Challenger_: Challenger> VerifierTranscript<Challenger_> {
 		TranscriptWriter {
 			buffer: self.combined.challenger.observer(),
 			debug_assertions: self.debug_assertions,
+			kind: TranscriptOpKind::Observe,
+			log: &mut self.log,
 		}
 	}
 
@@ -257,6 +684,8 @@ impl<Challenger_: Challenger> VerifierTranscript<Challenger_> {
 		TranscriptReader {
 			buffer: &mut self.combined.buffer,
 			debug_assertions: self.debug_assertions,
+			kind: TranscriptOpKind::Decommitment,
+			log: &mut self.log,
 		}
 	}
 
@@ -270,12 +699,14 @@ impl<Challenger_: Challenger> VerifierTranscript<Challenger_> {
 		TranscriptReader {
 			buffer: &mut self.combined,
 			debug_assertions: self.debug_assertions,
+			kind: TranscriptOpKind::Message,
+			log: &mut self.log,
 		}
 	}
 }
 
 // Useful warnings to see if we are neglecting to read any advice or transcript entirely
-impl<Challenger> Drop for VerifierTranscript<Challenger> {
+impl<Challenger, Tape: ProofTape> Drop for VerifierTranscript<Challenger, Tape> {
 	fn drop(&mut self) {
 		if self.combined.buffer.has_remaining() {
 			warn!(
@@ -289,6 +720,8 @@ impl<Challenger> Drop for VerifierTranscript<Challenger> {
 pub struct TranscriptReader<'a, B: Buf> {
 	buffer: &'a mut B,
 	debug_assertions: bool,
+	kind: TranscriptOpKind,
+	log: &'a mut OperationLog,
 }
 
 impl<B: Buf> TranscriptReader<'_, B> {
@@ -297,24 +730,40 @@ impl<B: Buf> TranscriptReader<'_, B> {
 	}
 
 	pub fn read<T: DeserializeBytes>(&mut self) -> Result<T, Error> {
-		let mode = SerializationMode::CanonicalTower;
-		T::deserialize(self.buffer(), mode).map_err(Into::into)
+		self.read_logged(None)
 	}
 
 	pub fn read_vec<T: DeserializeBytes>(&mut self, n: usize) -> Result<Vec<T>, Error> {
-		let mode = SerializationMode::CanonicalTower;
-		let mut buffer = self.buffer();
-		repeat_with(move || T::deserialize(&mut buffer, mode).map_err(Into::into))
-			.take(n)
-			.collect()
+		repeat_with(|| self.read_logged(None)).take(n).collect()
 	}
 
 	pub fn read_bytes(&mut self, buf: &mut [u8]) -> Result<(), Error> {
-		let buffer = self.buffer();
+		let buffer = &mut *self.buffer;
 		if buffer.remaining() < buf.len() {
-			return Err(Error::NotEnoughBytes);
+			return Err(Error::ProofUnexpectedlyShort {
+				needed: Some(buf.len()),
+				available: buffer.remaining(),
+			});
+		}
+		// Copy chunk by chunk, as a streamed proof may turn out to be truncated while copying.
+		let mut filled = 0;
+		while filled < buf.len() {
+			let chunk = buffer.chunk();
+			if chunk.is_empty() {
+				return Err(Error::ProofUnexpectedlyShort {
+					needed: Some(buf.len()),
+					available: filled,
+				});
+			}
+			let n = chunk.len().min(buf.len() - filled);
+			buf[filled..filled + n].copy_from_slice(&chunk[..n]);
+			buffer.advance(n);
+			filled += n;
+		}
+		if self.log.is_enabled() {
+			self.log
+				.push(TranscriptOp::new::<[u8]>(self.kind, buf.to_vec()));
 		}
-		buffer.copy_to_slice(buf);
 		Ok(())
 	}
 
@@ -325,10 +774,16 @@ impl<B: Buf> TranscriptReader<'_, B> {
 	}
 
 	pub fn read_scalar_slice_into<F: TowerField>(&mut self, buf: &mut [F]) -> Result<(), Error> {
-		let mut buffer = self.buffer();
+		let elem_size = serialized_scalar_size::<F>();
 		for elem in buf {
-			let mode = SerializationMode::CanonicalTower;
-			*elem = DeserializeBytes::deserialize(&mut buffer, mode)?;
+			let available = self.buffer.remaining();
+			if available < elem_size {
+				return Err(Error::ProofUnexpectedlyShort {
+					needed: Some(elem_size),
+					available,
+				});
+			}
+			*elem = self.read_logged(Some(elem_size))?;
 		}
 		Ok(())
 	}
@@ -362,11 +817,84 @@ impl<B: Buf> TranscriptReader<'_, B> {
 			assert_eq!(msg_bytes, buffer);
 		}
 	}
+
+	/// Reads a `T`, whose serialized size is `needed` if it is known in advance.
+	fn read_logged<T: DeserializeBytes>(&mut self, needed: Option<usize>) -> Result<T, Error> {
+		let mode = SerializationMode::CanonicalTower;
+		let available = self.buffer.remaining();
+		if !self.log.is_enabled() {
+			return T::deserialize(&mut *self.buffer, mode)
+				.map_err(|err| map_read_error(err, needed, available));
+		}
+
+		// Deserialize through a buffer recording the consumed bytes, which may span several
+		// chunks of the underlying buffer.
+		let mut recording = RecordingBuf {
+			inner: &mut *self.buffer,
+			bytes: Vec::new(),
+		};
+		let value = T::deserialize(&mut recording, mode)
+			.map_err(|err| map_read_error(err, needed, available))?;
+		let bytes = recording.bytes;
+		self.log.push(TranscriptOp::new::<T>(self.kind, bytes));
+		Ok(value)
+	}
+}
+
+/// Maps an error deserializing a value of size `needed` from a proof with `available` bytes left.
+fn map_read_error(err: SerializationError, needed: Option<usize>, available: usize) -> Error {
+	match err {
+		SerializationError::NotEnoughBytes => Error::ProofUnexpectedlyShort { needed, available },
+		err => err.into(),
+	}
+}
+
+/// A buffer recording the bytes consumed from an inner buffer.
+struct RecordingBuf<'a, B> {
+	inner: &'a mut B,
+	bytes: Vec<u8>,
+}
+
+impl<B: Buf> Buf for RecordingBuf<'_, B> {
+	fn remaining(&self) -> usize {
+		self.inner.remaining()
+	}
+
+	fn chunk(&self) -> &[u8] {
+		self.inner.chunk()
+	}
+
+	fn advance(&mut self, mut cnt: usize) {
+		while cnt > 0 {
+			let chunk = self.inner.chunk();
+			let n = chunk.len().min(cnt);
+			if n == 0 {
+				// Advancing past the end of the inner buffer panics there, as for any buffer.
+				self.inner.advance(cnt);
+				return;
+			}
+			self.bytes.extend_from_slice(&chunk[..n]);
+			self.inner.advance(n);
+			cnt -= n;
+		}
+	}
+}
+
+/// The size of a field element in its canonical tower serialization.
+fn serialized_scalar_size<F: TowerField>() -> usize {
+	let mut bytes = [0u8; 16];
+	let mut writer = &mut bytes[..];
+	F::ZERO
+		.serialize(&mut writer, SerializationMode::CanonicalTower)
+		.expect("a tower field element fits in 16 bytes");
+	16 - writer.len()
 }
 
 pub struct TranscriptWriter<'a, B: BufMut> {
 	buffer: &'a mut B,
 	debug_assertions: bool,
+	kind: TranscriptOpKind,
+	log: &'a mut OperationLog,
 }
 
 impl<B: BufMut> TranscriptWriter<'_, B> {
@@ -375,26 +903,25 @@ impl<B: BufMut> TranscriptWriter<'_, B> {
 	}
 
 	pub fn write<T: SerializeBytes>(&mut self, value: &T) {
-		self.proof_size_event_wrapper(|buffer| {
-			value
-				.serialize(buffer, SerializationMode::CanonicalTower)
-				.expect("TODO: propagate error");
-		});
+		self.proof_size_event_wrapper(|writer| writer.write_logged(value));
 	}
 
 	pub fn write_slice<T: SerializeBytes>(&mut self, values: &[T]) {
-		self.proof_size_event_wrapper(|buffer| {
+		self.proof_size_event_wrapper(|writer| {
 			for value in values {
-				value
-					.serialize(&mut *buffer, SerializationMode::CanonicalTower)
-					.expect("TODO: propagate error");
+				writer.write_logged(value);
 			}
 		});
 	}
 
 	pub fn write_bytes(&mut self, data: &[u8]) {
-		self.proof_size_event_wrapper(|buffer| {
-			buffer.put_slice(data);
+		self.proof_size_event_wrapper(|writer| {
+			writer.buffer.put_slice(data);
+			if writer.log.is_enabled() {
+				writer
+					.log
+					.push(TranscriptOp::new::<[u8]>(writer.kind, data.to_vec()));
+			}
 		});
 	}
 
@@ -403,10 +930,9 @@ impl<B: BufMut> TranscriptWriter<'_, B> {
 	}
 
 	pub fn write_scalar_iter<F: TowerField>(&mut self, it: impl IntoIterator<Item = F>) {
-		self.proof_size_event_wrapper(move |buffer| {
+		self.proof_size_event_wrapper(move |writer| {
 			for elem in it {
-				SerializeBytes::serialize(&elem, &mut *buffer, SerializationMode::CanonicalTower)
-					.expect("TODO: propagate error");
+				writer.write_logged(&elem);
 			}
 		});
 	}
@@ -436,24 +962,89 @@ impl<B: BufMut> TranscriptWriter<'_, B> {
 		}
 	}
 
-	fn proof_size_event_wrapper<F: FnOnce(&mut B)>(&mut self, f: F) {
-		let buffer = self.buffer();
-		let start_bytes = buffer.remaining_mut();
-		f(buffer);
-		let end_bytes = buffer.remaining_mut();
+	fn write_logged<T: SerializeBytes>(&mut self, value: &T) {
+		let mode = SerializationMode::CanonicalTower;
+		if self.log.is_enabled() {
+			let mut bytes = Vec::new();
+			value
+				.serialize(&mut bytes, mode)
+				.expect("TODO: propagate error");
+			self.buffer.put_slice(&bytes);
+			self.log.push(TranscriptOp::new::<T>(self.kind, bytes));
+		} else {
+			value
+				.serialize(&mut *self.buffer, mode)
+				.expect("TODO: propagate error");
+		}
+	}
+
+	fn proof_size_event_wrapper<F: FnOnce(&mut Self)>(&mut self, f: F) {
+		let start_bytes = self.buffer.remaining_mut();
+		f(self);
+		let end_bytes = self.buffer.remaining_mut();
 		tracing::event!(name: "incremental_proof_size", tracing::Level::INFO, counter=true, incremental=true, value=start_bytes - end_bytes);
 	}
 }
 
-impl<F, Challenger_> CanSample<F> for VerifierTranscript<Challenger_>
+fn sample_logged<F: TowerField>(challenger: &mut impl Challenger, log: &mut OperationLog) -> F {
+	let mode = SerializationMode::CanonicalTower;
+	let value: F = DeserializeBytes::deserialize(challenger.sampler(), mode)
+		.expect("challenger has infinite buffer");
+	if log.is_enabled() {
+		let mut bytes = Vec::new();
+		SerializeBytes::serialize(&value, &mut bytes, mode)
+			.expect("serializing a field element into a vector cannot fail");
+		log.push(TranscriptOp::new::<F>(TranscriptOpKind::Sample, bytes));
+	}
+	value
+}
+
+/// Samples `n` field elements with a single squeeze of the challenger.
+///
+/// The challenges equal those of `n` consecutive calls to [`sample_logged`], and are logged as
+/// such.
+fn sample_vec_logged<F: TowerField>(
+	challenger: &mut impl Challenger,
+	log: &mut OperationLog,
+	n: usize,
+) -> Vec<F> {
+	let mode = SerializationMode::CanonicalTower;
+	let mut zero = Vec::new();
+	SerializeBytes::serialize(&F::ZERO, &mut zero, mode)
+		.expect("serializing a field element into a vector cannot fail");
+	let elem_size = zero.len();
+
+	let mut bytes = vec![0u8; n * elem_size];
+	challenger.sampler().copy_to_slice(&mut bytes);
+	let mut bytes = bytes.as_slice();
+	let values = repeat_with(|| {
+		DeserializeBytes::deserialize(&mut bytes, mode).expect("sampled exactly n elements")
+	})
+	.take(n)
+	.collect::<Vec<F>>();
+	if log.is_enabled() {
+		for value in &values {
+			let mut bytes = Vec::new();
+			SerializeBytes::serialize(value, &mut bytes, mode)
+				.expect("serializing a field element into a vector cannot fail");
+			log.push(TranscriptOp::new::<F>(TranscriptOpKind::Sample, bytes));
+		}
+	}
+	values
+}
+
+impl<F, Challenger_, Tape> CanSample<F> for VerifierTranscript<Challenger_, Tape>
 where
 	F: TowerField,
 	Challenger_: Challenger,
+	Tape: ProofTape,
 {
 	fn sample(&mut self) -> F {
-		let mode = SerializationMode::CanonicalTower;
-		DeserializeBytes::deserialize(self.combined.challenger.sampler(), mode)
-			.expect("challenger has infinite buffer")
+		sample_logged(&mut self.combined.challenger, &mut self.log)
+	}
+
+	fn sample_vec(&mut self, n: usize) -> Vec<F> {
+		sample_vec_logged(&mut self.combined.challenger, &mut self.log, n)
 	}
 }
 
@@ -463,9 +1054,96 @@ where
 	Challenger_: Challenger,
 {
 	fn sample(&mut self) -> F {
-		let mode = SerializationMode::CanonicalTower;
-		DeserializeBytes::deserialize(self.combined.challenger.sampler(), mode)
-			.expect("challenger has infinite buffer")
+		sample_logged(&mut self.combined.challenger, &mut self.log)
+	}
+
+	fn sample_vec(&mut self, n: usize) -> Vec<F> {
+		sample_vec_logged(&mut self.combined.challenger, &mut self.log, n)
+	}
+}
+
+impl<Challenger_: Challenger> ProverTranscript<Challenger_> {
+	/// Samples a single challenge and broadcasts it to all lanes of a packed field element.
+	///
+	/// This is for protocols that apply one challenge to every evaluation of a packed multilinear,
+	/// such as folding by a sumcheck round challenge. The transcript advances as in one call to
+	/// [`CanSample::sample`].
+	pub fn sample_packed<P: PackedField<Scalar: TowerField>>(&mut self) -> P {
+		P::broadcast(sample_logged(&mut self.combined.challenger, &mut self.log))
+	}
+
+	/// Samples an independent challenge for each lane of a packed field element.
+	///
+	/// This is for protocols that need a batch of challenges whose count is a multiple of the
+	/// packing width, such as random linear combinations of many claims. The lanes equal the
+	/// challenges of [`CanSample::sample_vec`] with `P::WIDTH` elements, in order.
+	pub fn sample_packed_distinct<P: PackedField<Scalar: TowerField>>(&mut self) -> P {
+		P::from_scalars(sample_vec_logged(&mut self.combined.challenger, &mut self.log, P::WIDTH))
+	}
+}
+
+impl<Challenger_: Challenger, Tape: ProofTape> VerifierTranscript<Challenger_, Tape> {
+	/// Samples a challenge as [`ProverTranscript::sample_packed`] does.
+	pub fn sample_packed<P: PackedField<Scalar: TowerField>>(&mut self) -> P {
+		P::broadcast(sample_logged(&mut self.combined.challenger, &mut self.log))
+	}
+
+	/// Samples challenges as [`ProverTranscript::sample_packed_distinct`] does.
+	pub fn sample_packed_distinct<P: PackedField<Scalar: TowerField>>(&mut self) -> P {
+		P::from_scalars(sample_vec_logged(&mut self.combined.challenger, &mut self.log, P::WIDTH))
+	}
+}
+
+/// Samples an element of the subfield given by `spec`, consuming [`ChallengeSpec::n_bytes`]
+/// bytes of the challenger.
+fn sample_with_spec_logged<F: TowerField>(
+	challenger: &mut impl Challenger,
+	log: &mut OperationLog,
+	spec: ChallengeSpec,
+) -> F {
+	assert!(
+		spec.tower_level() <= F::TOWER_LEVEL,
+		"cannot sample a challenge at tower level {} into a field of tower level {}",
+		spec.tower_level(),
+		F::TOWER_LEVEL
+	);
+
+	let mut bytes = vec![0u8; ChallengeSpec::full::<F>().n_bytes()];
+	let n_bytes = spec.n_bytes();
+	challenger.sampler().copy_to_slice(&mut bytes[..n_bytes]);
+	if spec.tower_level() < 3 {
+		bytes[0] &= (1u8 << (1 << spec.tower_level())) - 1;
+	}
+
+	// Canonical tower elements are embedded into larger tower fields by zero-extension.
+	let value: F =
+		DeserializeBytes::deserialize(bytes.as_slice(), SerializationMode::CanonicalTower)
+			.expect("buffer has the serialized size of F");
+	if log.is_enabled() {
+		bytes.truncate(n_bytes);
+		log.push(TranscriptOp::new::<F>(TranscriptOpKind::Sample, bytes));
+	}
+	value
+}
+
+impl<F, Challenger_, Tape> CanSampleWithSpec<F> for VerifierTranscript<Challenger_, Tape>
+where
+	F: TowerField,
+	Challenger_: Challenger,
+	Tape: ProofTape,
+{
+	fn sample_with_spec(&mut self, spec: ChallengeSpec) -> F {
+		sample_with_spec_logged(&mut self.combined.challenger, &mut self.log, spec)
+	}
+}
+
+impl<F, Challenger_> CanSampleWithSpec<F> for ProverTranscript<Challenger_>
+where
+	F: TowerField,
+	Challenger_: Challenger,
+{
+	fn sample_with_spec(&mut self, spec: ChallengeSpec) -> F {
+		sample_with_spec_logged(&mut self.combined.challenger, &mut self.log, spec)
 	}
 }
 
@@ -487,12 +1165,25 @@ fn sample_bits_reader<Reader: Buf>(mut reader: Reader, bits: usize) -> u32 {
 	mask & unmasked
 }
 
-impl<Challenger_> CanSampleBits<u32> for VerifierTranscript<Challenger_>
+fn sample_bits_logged(
+	challenger: &mut impl Challenger,
+	log: &mut OperationLog,
+	bits: usize,
+) -> u32 {
+	let value = sample_bits_reader(challenger.sampler(), bits);
+	if log.is_enabled() {
+		log.push(TranscriptOp::new::<u32>(TranscriptOpKind::Sample, value.to_le_bytes().to_vec()));
+	}
+	value
+}
+
+impl<Challenger_, Tape> CanSampleBits<u32> for VerifierTranscript<Challenger_, Tape>
 where
 	Challenger_: Challenger,
+	Tape: ProofTape,
 {
 	fn sample_bits(&mut self, bits: usize) -> u32 {
-		sample_bits_reader(self.combined.challenger.sampler(), bits)
+		sample_bits_logged(&mut self.combined.challenger, &mut self.log, bits)
 	}
 }
 
@@ -501,7 +1192,7 @@ where
 	Challenger_: Challenger,
 {
 	fn sample_bits(&mut self, bits: usize) -> u32 {
-		sample_bits_reader(self.combined.challenger.sampler(), bits)
+		sample_bits_logged(&mut self.combined.challenger, &mut self.log, bits)
 	}
 }
 
@@ -521,6 +1212,7 @@ mod tests {
 	use binius_field::{
 		AESTowerField8b, AESTowerField16b, AESTowerField32b, AESTowerField128b, BinaryField8b,
 		BinaryField32b, BinaryField64b, BinaryField128b, BinaryField128bPolyval,
+		PackedBinaryField4x32b,
 	};
 	use binius_hash::groestl::Groestl256;
 	use rand::{RngCore, thread_rng};
@@ -667,7 +1359,7 @@ mod tests {
 
 		let mut taped_transcript = taped_transcript.into_verifier();
 
-		assert!(untaped_transcript.finalize().is_empty());
+		assert_eq!(untaped_transcript.finalize(), [TRANSCRIPT_FORMAT_VERSION]);
 
 		for array in sampled_arrays {
 			let _: BinaryField64b = taped_transcript.message().read_scalar().unwrap();
@@ -679,6 +1371,198 @@ mod tests {
 		taped_transcript.finalize().unwrap();
 	}
 
+	#[test]
+	fn test_transcript_fork() {
+		type Transcript = ProverTranscript<HasherChallenger<Groestl256>>;
+
+		let first_challenge = |mut transcript: Transcript| -> BinaryField128b {
+			transcript
+				.message()
+				.write_scalar(BinaryField32b::new(0xDEADBEEF));
+			transcript.sample()
+		};
+		let parent_with_prefix = |prefix: u32| {
+			let mut transcript = Transcript::new();
+			transcript
+				.message()
+				.write_scalar(BinaryField32b::new(prefix));
+			transcript
+		};
+
+		let parent = parent_with_prefix(1);
+		assert_ne!(
+			first_challenge(parent.fork(b"worker 0")),
+			first_challenge(parent.fork(b"worker 1"))
+		);
+		assert_eq!(
+			first_challenge(parent.fork(b"worker 0")),
+			first_challenge(parent.fork(b"worker 0"))
+		);
+		assert_ne!(
+			first_challenge(parent.fork(b"worker 0")),
+			first_challenge(parent_with_prefix(2).fork(b"worker 0"))
+		);
+
+		// Every fork is verified by forking the verifier transcript of the shared prefix.
+		let mut fork = parent.fork(b"worker 0");
+		fork.message().write_scalar(BinaryField32b::new(0xDEADBEEF));
+		let prover_challenge: BinaryField128b = fork.sample();
+		let fork_proof = fork.finalize();
+
+		let mut verifier_parent = parent.into_verifier();
+		let _: BinaryField32b = verifier_parent.message().read_scalar().unwrap();
+		let mut verifier_fork = verifier_parent.fork(fork_proof, b"worker 0").unwrap();
+		let _: BinaryField32b = verifier_fork.message().read_scalar().unwrap();
+		let verifier_challenge: BinaryField128b = verifier_fork.sample();
+		assert_eq!(verifier_challenge, prover_challenge);
+		verifier_fork.finalize().unwrap();
+		verifier_parent.finalize().unwrap();
+
+		let mut fork = parent_with_prefix(1).fork(b"worker 0");
+		fork.message().write_scalar(BinaryField32b::new(0xDEADBEEF));
+		let prover_challenge: BinaryField128b = fork.sample();
+		let mut verifier_fork = fork.into_verifier();
+		let _: BinaryField32b = verifier_fork.message().read_scalar().unwrap();
+		let verifier_challenge: BinaryField128b = verifier_fork.sample();
+		assert_eq!(verifier_challenge, prover_challenge);
+	}
+
+	#[test]
+	fn test_transcript_with_seed() {
+		type Transcript = ProverTranscript<HasherChallenger<Groestl256>>;
+
+		let first_challenge = |mut transcript: Transcript| -> BinaryField128b {
+			transcript
+				.message()
+				.write_scalar(BinaryField32b::new(0xDEADBEEF));
+			transcript.sample()
+		};
+		assert_ne!(
+			first_challenge(Transcript::with_seed(b"circuit a")),
+			first_challenge(Transcript::with_seed(b"circuit b"))
+		);
+		assert_ne!(first_challenge(Transcript::with_seed(b"")), first_challenge(Transcript::new()));
+
+		let mut prover_transcript = Transcript::with_seed(b"circuit a");
+		prover_transcript
+			.message()
+			.write_scalar(BinaryField32b::new(0xDEADBEEF));
+		let prover_challenge: BinaryField128b = prover_transcript.sample();
+		let proof = prover_transcript.finalize();
+
+		let mut verifier_transcript =
+			VerifierTranscript::<HasherChallenger<Groestl256>>::with_seed(
+				proof.clone(),
+				b"circuit a",
+			)
+			.unwrap();
+		let _: BinaryField32b = verifier_transcript.message().read_scalar().unwrap();
+		let verifier_challenge: BinaryField128b = verifier_transcript.sample();
+		assert_eq!(verifier_challenge, prover_challenge);
+		verifier_transcript.finalize().unwrap();
+
+		let mut unseeded_transcript =
+			VerifierTranscript::<HasherChallenger<Groestl256>>::new(proof);
+		let _: BinaryField32b = unseeded_transcript.message().read_scalar().unwrap();
+		let unseeded_challenge: BinaryField128b = unseeded_transcript.sample();
+		assert_ne!(unseeded_challenge, prover_challenge);
+		unseeded_transcript.finalize().unwrap();
+	}
+
+	#[test]
+	fn test_grinding() {
+		let leading_zeros = |challenge: &[u8]| challenge[0] >> 4 == 0;
+
+		let mut prover_transcript = ProverTranscript::<HasherChallenger<Groestl256>>::new();
+		prover_transcript
+			.message()
+			.write_scalar(BinaryField32b::new(0xDEADBEEF));
+		let nonce = prover_transcript.grind(leading_zeros, 1 << 10).unwrap();
+		let prover_challenge: BinaryField128b = prover_transcript.sample();
+
+		let mut verifier_transcript = prover_transcript.into_verifier();
+		let _: BinaryField32b = verifier_transcript.message().read_scalar().unwrap();
+		assert_eq!(verifier_transcript.verify_grind(leading_zeros).unwrap(), nonce);
+		let verifier_challenge: BinaryField128b = verifier_transcript.sample();
+		assert_eq!(verifier_challenge, prover_challenge);
+		verifier_transcript.finalize().unwrap();
+
+		let mut prover_transcript = ProverTranscript::<HasherChallenger<Groestl256>>::new();
+		assert!(matches!(
+			prover_transcript.grind(|_| false, 4),
+			Err(Error::GrindingFailed { max_iters: 4 })
+		));
+
+		let mut prover_transcript = ProverTranscript::<HasherChallenger<Groestl256>>::new();
+		write_u64(&mut prover_transcript.message(), 0);
+		let mut verifier_transcript = prover_transcript.into_verifier();
+		assert!(matches!(
+			verifier_transcript.verify_grind(|_| false),
+			Err(Error::GrindingPredicateFailed { nonce: 0 })
+		));
+	}
+
+	#[test]
+	fn test_grinding_difficulty() {
+		assert_eq!(grinding_difficulty(&[0x80, 0x00]), 0);
+		assert_eq!(grinding_difficulty(&[0x0f, 0xff]), 4);
+		assert_eq!(grinding_difficulty(&[0x00, 0x01]), 15);
+		assert_eq!(grinding_difficulty(&[0x00, 0x00]), 16);
+
+		let exactly_four = |challenge: &[u8]| grinding_difficulty(challenge) == 4;
+		let mut prover_transcript = ProverTranscript::<HasherChallenger<Groestl256>>::new();
+		prover_transcript.grind(exactly_four, 1 << 10).unwrap();
+		let proof = prover_transcript.finalize();
+
+		let mut verifier_transcript =
+			VerifierTranscript::<HasherChallenger<Groestl256>>::new(proof.clone());
+		assert_eq!(verifier_transcript.verify_grind_difficulty(3).unwrap(), 4);
+		verifier_transcript.finalize().unwrap();
+
+		let mut verifier_transcript =
+			VerifierTranscript::<HasherChallenger<Groestl256>>::new(proof);
+		assert!(matches!(
+			verifier_transcript.verify_grind_difficulty(5),
+			Err(Error::InsufficientWork {
+				achieved: 4,
+				required: 5
+			})
+		));
+	}
+
+	#[test]
+	fn test_write_read_root() {
+		let root_a = GroestlDigest::from([0x5a; 32]);
+		let mut root_b = root_a;
+		root_b[31] ^= 1;
+
+		let prove = |root: &GroestlDigest| {
+			let mut transcript = ProverTranscript::<HasherChallenger<Groestl256>>::new();
+			transcript.write_root(root);
+			let challenge: BinaryField128b = transcript.sample();
+			(transcript, challenge)
+		};
+		let (transcript, challenge_a) = prove(&root_a);
+		let (generic_transcript, _) = {
+			let mut transcript = ProverTranscript::<HasherChallenger<Groestl256>>::new();
+			transcript.message().write(&root_a);
+			let challenge: BinaryField128b = transcript.sample();
+			(transcript, challenge)
+		};
+		let (_, challenge_b) = prove(&root_b);
+		assert_ne!(challenge_a, challenge_b);
+
+		let proof = transcript.finalize();
+		assert_eq!(proof, generic_transcript.finalize());
+
+		let mut verifier_transcript =
+			VerifierTranscript::<HasherChallenger<Groestl256>>::new(proof);
+		assert_eq!(verifier_transcript.read_root().unwrap(), root_a);
+		let challenge: BinaryField128b = verifier_transcript.sample();
+		assert_eq!(challenge, challenge_a);
+		verifier_transcript.finalize().unwrap();
+	}
+
 	#[test]
 	fn test_transcript_debug() {
 		let mut transcript = ProverTranscript::<HasherChallenger<Groestl256>>::new();
@@ -690,6 +1574,323 @@ mod tests {
 			.read_debug("test_transcript_debug");
 	}
 
+	#[cfg(debug_assertions)]
+	fn write_replay_transcript() -> ProverTranscript<HasherChallenger<Groestl256>> {
+		let mut transcript =
+			ProverTranscript::<HasherChallenger<Groestl256>>::new().with_operation_log();
+		transcript.observe().write_scalar(BinaryField32b::new(7));
+		transcript
+			.message()
+			.write_scalar(BinaryField128b::new(0x55669900112233550000CCDDFFEEAABB));
+		let _: BinaryField128b = transcript.sample();
+		transcript.message().write_scalar(BinaryField8b::new(0x96));
+		transcript.decommitment().write_bytes(&[1, 2, 3]);
+		let _ = transcript.sample_bits(5);
+		transcript
+	}
+
+	#[test]
+	#[cfg(debug_assertions)]
+	fn test_transcript_replay() {
+		let prover_transcript = write_replay_transcript();
+		let log = prover_transcript.operation_log().to_vec();
+		assert_eq!(
+			log.iter().map(|op| op.kind).collect::<Vec<_>>(),
+			[
+				TranscriptOpKind::Observe,
+				TranscriptOpKind::Message,
+				TranscriptOpKind::Sample,
+				TranscriptOpKind::Message,
+				TranscriptOpKind::Decommitment,
+				TranscriptOpKind::Sample,
+			]
+		);
+
+		let mut verifier_transcript = prover_transcript.into_verifier();
+		verifier_transcript.set_expected_operation_log(log.clone());
+		verifier_transcript
+			.observe()
+			.write_scalar(BinaryField32b::new(7));
+		let _: BinaryField128b = verifier_transcript.message().read_scalar().unwrap();
+		let _: BinaryField128b = verifier_transcript.sample();
+		let _: BinaryField8b = verifier_transcript.message().read_scalar().unwrap();
+		let mut bytes = [0u8; 3];
+		verifier_transcript
+			.decommitment()
+			.read_bytes(&mut bytes)
+			.unwrap();
+		let _ = verifier_transcript.sample_bits(5);
+
+		assert_eq!(verifier_transcript.operation_log(), log);
+		verifier_transcript.finalize().unwrap();
+	}
+
+	#[test]
+	#[cfg(debug_assertions)]
+	fn test_operation_log_is_opt_in() {
+		let mut transcript = ProverTranscript::<HasherChallenger<Groestl256>>::new();
+		transcript.message().write_scalar(BinaryField8b::new(0x96));
+		let _: BinaryField128b = transcript.sample();
+		assert!(transcript.operation_log().is_empty());
+
+		let mut verifier_transcript = transcript.into_verifier();
+		let _: BinaryField8b = verifier_transcript.message().read_scalar().unwrap();
+		let _: BinaryField128b = verifier_transcript.sample();
+		assert!(verifier_transcript.operation_log().is_empty());
+		verifier_transcript.finalize().unwrap();
+	}
+
+	#[test]
+	#[cfg(debug_assertions)]
+	#[should_panic(expected = "transcript operation 2 diverges")]
+	fn test_transcript_replay_detects_reordering() {
+		let prover_transcript = write_replay_transcript();
+		let log = prover_transcript.operation_log().to_vec();
+
+		let mut verifier_transcript = prover_transcript.into_verifier();
+		verifier_transcript.set_expected_operation_log(log);
+		verifier_transcript
+			.observe()
+			.write_scalar(BinaryField32b::new(7));
+		let _: BinaryField128b = verifier_transcript.message().read_scalar().unwrap();
+		// The prover sampled a challenge before writing the next message.
+		let _: BinaryField8b = verifier_transcript.message().read_scalar().unwrap();
+	}
+
+	#[test]
+	#[cfg(debug_assertions)]
+	#[should_panic(expected = "diverges")]
+	fn test_transcript_replay_detects_type_mismatch() {
+		let prover_transcript = write_replay_transcript();
+		let log = prover_transcript.operation_log().to_vec();
+
+		let mut verifier_transcript = prover_transcript.into_verifier();
+		verifier_transcript.set_expected_operation_log(log);
+		verifier_transcript
+			.observe()
+			.write_scalar(BinaryField32b::new(7));
+		let _: AESTowerField128b = verifier_transcript.message().read_scalar().unwrap();
+	}
+
+	#[test]
+	fn test_sample_vec_matches_individual_samples() {
+		fn check<F: TowerField>(n: usize) {
+			let mut batched = ProverTranscript::<HasherChallenger<Groestl256>>::new();
+			let mut individual = ProverTranscript::<HasherChallenger<Groestl256>>::new();
+			for transcript in [&mut batched, &mut individual] {
+				transcript.observe().write_bytes(b"statement");
+			}
+
+			let batched_values: Vec<F> = batched.sample_vec(n);
+			let individual_values = (0..n).map(|_| individual.sample()).collect::<Vec<F>>();
+			assert_eq!(batched_values, individual_values);
+
+			// The challengers are left in the same state.
+			let batched_next: F = batched.sample();
+			let individual_next: F = individual.sample();
+			assert_eq!(batched_next, individual_next);
+
+			let mut verifier = batched.into_verifier();
+			verifier.observe().write_bytes(b"statement");
+			let verifier_values: Vec<F> = verifier.sample_vec(n);
+			assert_eq!(verifier_values, individual_values);
+		}
+
+		for n in [0, 1, 3, 64] {
+			check::<BinaryField32b>(n);
+			check::<BinaryField128b>(n);
+			check::<BinaryField128bPolyval>(n);
+		}
+	}
+
+	#[test]
+	fn test_sample_packed() {
+		let mut packed = ProverTranscript::<HasherChallenger<Groestl256>>::new();
+		let mut scalar = ProverTranscript::<HasherChallenger<Groestl256>>::new();
+
+		let broadcast: PackedBinaryField4x32b = packed.sample_packed();
+		let expected: BinaryField32b = scalar.sample();
+		assert!(broadcast.iter().all(|lane| lane == expected));
+
+		let distinct: PackedBinaryField4x32b = packed.sample_packed_distinct();
+		let expected: Vec<BinaryField32b> = scalar.sample_vec(4);
+		assert_eq!(distinct.iter().collect::<Vec<_>>(), expected);
+
+		let mut verifier = packed.into_verifier();
+		assert_eq!(verifier.sample_packed::<PackedBinaryField4x32b>(), broadcast);
+		assert_eq!(verifier.sample_packed_distinct::<PackedBinaryField4x32b>(), distinct);
+	}
+
+	#[test]
+	fn test_sample_with_spec_consumes_subfield_bytes() {
+		let mut with_spec = ProverTranscript::<HasherChallenger<Groestl256>>::new();
+		let mut reference = ProverTranscript::<HasherChallenger<Groestl256>>::new();
+
+		let spec = ChallengeSpec::new(BinaryField8b::TOWER_LEVEL);
+		assert_eq!(spec.n_bytes(), 1);
+		let challenge: BinaryField128b = with_spec.sample_with_spec(spec);
+		let expected: BinaryField8b = reference.sample();
+		assert_eq!(BinaryField8b::try_from(challenge), Ok(expected));
+
+		// Only one byte was consumed, so the challengers are left in the same state.
+		let next: BinaryField128b = with_spec.sample();
+		let expected_next: BinaryField128b = reference.sample();
+		assert_eq!(next, expected_next);
+
+		let mut verifier = with_spec.into_verifier();
+		let verifier_challenge: BinaryField128b = verifier.sample_with_spec(spec);
+		assert_eq!(verifier_challenge, challenge);
+		let _: BinaryField128b = verifier.sample();
+	}
+
+	#[test]
+	fn test_transcript_version_mismatch() {
+		let version = TRANSCRIPT_FORMAT_VERSION;
+		let mut transcript =
+			ProverTranscript::<HasherChallenger<Groestl256>>::with_format_version(version);
+		transcript.message().write_scalar(BinaryField32b::new(7));
+		let proof = transcript.finalize();
+
+		let result = VerifierTranscript::<HasherChallenger<Groestl256>>::with_format_version(
+			proof.clone(),
+			version + 1,
+		);
+		assert!(matches!(
+			result.err(),
+			Some(Error::TranscriptVersionMismatch { found, expected })
+				if found == version && expected == version + 1
+		));
+
+		let mut verifier_transcript =
+			VerifierTranscript::<HasherChallenger<Groestl256>>::with_format_version(proof, version)
+				.unwrap();
+		let value: BinaryField32b = verifier_transcript.message().read_scalar().unwrap();
+		assert_eq!(value, BinaryField32b::new(7));
+		verifier_transcript.finalize().unwrap();
+	}
+
+	#[test]
+	fn test_transcript_missing_version() {
+		let result = VerifierTranscript::<HasherChallenger<Groestl256>>::try_new(Vec::new());
+		assert!(matches!(
+			result.err(),
+			Some(Error::ProofUnexpectedlyShort {
+				needed: Some(1),
+				available: 0
+			})
+		));
+	}
+
+	#[test]
+	fn test_transcript_new_skips_header() {
+		type Challenger = HasherChallenger<Groestl256>;
+
+		let mut prover_transcript = ProverTranscript::<Challenger>::new();
+		prover_transcript
+			.message()
+			.write_scalar(BinaryField32b::new(7));
+		let mut proof = prover_transcript.finalize();
+		proof[0] = TRANSCRIPT_FORMAT_VERSION + 1;
+
+		assert!(matches!(
+			VerifierTranscript::<Challenger>::try_new(proof.clone()),
+			Err(Error::TranscriptVersionMismatch { found, expected })
+				if found == TRANSCRIPT_FORMAT_VERSION + 1 && expected == TRANSCRIPT_FORMAT_VERSION
+		));
+
+		let mut verifier_transcript = VerifierTranscript::<Challenger>::new(proof);
+		let value: BinaryField32b = verifier_transcript.message().read_scalar().unwrap();
+		assert_eq!(value, BinaryField32b::new(7));
+		verifier_transcript.finalize().unwrap();
+	}
+
+	#[test]
+	fn test_read_value_straddling_read_tape_chunks() {
+		type Challenger = HasherChallenger<Groestl256>;
+
+		let values = (0..10u128)
+			.map(|i| BinaryField128b::new(i * 0x0123_4567_89ab_cdef_0123_4567_89ab_cdef))
+			.collect::<Vec<_>>();
+		let mut prover_transcript = ProverTranscript::<Challenger>::new();
+		prover_transcript.message().write_slice(&values);
+		let proof = prover_transcript.finalize();
+
+		// After the version byte, every value spans two windows of 16 bytes.
+		let tape = ReadTape::with_window(proof.as_slice(), proof.len(), 16);
+		let mut verifier_transcript = VerifierTranscript::<Challenger, _>::from_read_tape(tape)
+			.unwrap()
+			.with_operation_log();
+		let read = verifier_transcript
+			.message()
+			.read_vec::<BinaryField128b>(values.len())
+			.unwrap();
+		assert_eq!(read, values);
+		#[cfg(debug_assertions)]
+		assert!(
+			verifier_transcript
+				.operation_log()
+				.iter()
+				.all(|op| op.bytes.len() == 16)
+		);
+		verifier_transcript.finalize().unwrap();
+
+		// A truncated field element reports its full size as needed, while a value read through
+		// `DeserializeBytes` has no size known in advance.
+		let mut verifier_transcript = VerifierTranscript::<Challenger>::new(proof[..25].to_vec());
+		let _: BinaryField128b = verifier_transcript.message().read().unwrap();
+		assert!(matches!(
+			verifier_transcript
+				.message()
+				.read_scalar::<BinaryField128b>(),
+			Err(Error::ProofUnexpectedlyShort {
+				needed: Some(16),
+				available: 8
+			})
+		));
+		assert!(matches!(
+			verifier_transcript.message().read::<BinaryField128b>(),
+			Err(Error::ProofUnexpectedlyShort {
+				needed: None,
+				available: 8
+			})
+		));
+	}
+
+	#[test]
+	fn test_read_tape_reads_more_than_half_window() {
+		type Challenger = HasherChallenger<Groestl256>;
+
+		let bytes = (0..=255u8).cycle().take(1000).collect::<Vec<_>>();
+		let mut prover_transcript = ProverTranscript::<Challenger>::new();
+		prover_transcript.message().write_bytes(&bytes);
+		let proof = prover_transcript.finalize();
+
+		let tape = ReadTape::with_window(proof.as_slice(), proof.len(), 64);
+		assert_eq!(tape.remaining(), proof.len());
+		let mut verifier_transcript =
+			VerifierTranscript::<Challenger, _>::from_read_tape(tape).unwrap();
+		let mut read = vec![0; bytes.len()];
+		verifier_transcript.message().read_bytes(&mut read).unwrap();
+		assert_eq!(read, bytes);
+		verifier_transcript.finalize().unwrap();
+
+		let truncated = &proof[..proof.len() - 1];
+		let tape = ReadTape::with_window(truncated, proof.len(), 64);
+		let mut verifier_transcript =
+			VerifierTranscript::<Challenger, _>::from_read_tape(tape).unwrap();
+		assert!(matches!(
+			verifier_transcript.message().read_bytes(&mut read),
+			Err(Error::ProofUnexpectedlyShort {
+				needed: Some(1000),
+				available: 999
+			})
+		));
+		assert_eq!(
+			verifier_transcript.read_error().map(std::io::Error::kind),
+			Some(std::io::ErrorKind::UnexpectedEof)
+		);
+	}
+
 	#[test]
 	#[should_panic]
 	fn test_transcript_debug_fail() {
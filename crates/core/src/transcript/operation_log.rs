@@ -0,0 +1,120 @@
+// Copyright 2025 Irreducible Inc.
+
+//! Structural logging of transcript operations, used to detect prover/verifier drift.
+//!
+//! When compiled with `debug_assertions`, a transcript can be asked to record every value written
+//! to or read from it and every sampled challenge as a [`TranscriptOp`]. Recording is off by
+//! default, so transcripts that do not opt in pay nothing for it. A verifier transcript can be
+//! given the prover's log, in which case each operation it performs is checked against the
+//! operation the prover performed at the same position. This turns a mismatch in absorption order,
+//! which would otherwise surface as an opaque verification failure, into a panic pointing at the
+//! first diverging operation.
+
+use std::any::type_name;
+
+/// The kind of transcript operation.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TranscriptOpKind {
+	/// A value observed by the challenger without being written to the proof tape.
+	Observe,
+	/// A prover message, written to the proof tape and observed by the challenger.
+	Message,
+	/// A decommitment, written to the proof tape without being observed.
+	Decommitment,
+	/// A challenge sampled from the challenger.
+	Sample,
+}
+
+/// A single recorded transcript operation.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TranscriptOp {
+	pub kind: TranscriptOpKind,
+	/// Name of the type that was serialized or sampled.
+	pub type_name: &'static str,
+	/// The serialized bytes of the value.
+	pub bytes: Vec<u8>,
+}
+
+impl TranscriptOp {
+	pub fn new<T: ?Sized>(kind: TranscriptOpKind, bytes: Vec<u8>) -> Self {
+		Self {
+			kind,
+			type_name: type_name::<T>(),
+			bytes,
+		}
+	}
+}
+
+/// A log of transcript operations, optionally checked against an expected sequence.
+#[derive(Debug, Default, Clone)]
+pub(super) struct OperationLog {
+	enabled: bool,
+	ops: Vec<TranscriptOp>,
+	expected: Option<Vec<TranscriptOp>>,
+}
+
+impl OperationLog {
+	/// Whether operations are being recorded.
+	///
+	/// Recording is off until [`Self::enable`] is called, and is only possible in builds with
+	/// `debug_assertions`.
+	pub const fn is_enabled(&self) -> bool {
+		cfg!(debug_assertions) && self.enabled
+	}
+
+	pub const fn enable(&mut self) {
+		self.enabled = true;
+	}
+
+	#[cfg(debug_assertions)]
+	pub fn ops(&self) -> &[TranscriptOp] {
+		&self.ops
+	}
+
+	#[cfg(debug_assertions)]
+	pub fn set_expected(&mut self, expected: Vec<TranscriptOp>) {
+		assert!(
+			self.ops.is_empty(),
+			"the expected operation log must be set before any transcript operation"
+		);
+		self.enabled = true;
+		self.expected = Some(expected);
+	}
+
+	/// Records an operation.
+	///
+	/// ## Panics
+	///
+	/// * If an expected log is set and the operation does not match the expected operation at the
+	///   same position.
+	pub fn push(&mut self, op: TranscriptOp) {
+		if let Some(expected) = &self.expected {
+			let index = self.ops.len();
+			match expected.get(index) {
+				Some(expected_op) => assert_eq!(
+					expected_op, &op,
+					"transcript operation {index} diverges from the expected operation log"
+				),
+				None => panic!(
+					"transcript operation {index} ({op:?}) exceeds the expected operation log of \
+					 length {}",
+					expected.len()
+				),
+			}
+		}
+		self.ops.push(op);
+	}
+
+	/// Asserts that all expected operations have been performed.
+	pub fn assert_complete(&self) {
+		if let Some(expected) = &self.expected {
+			assert_eq!(
+				self.ops.len(),
+				expected.len(),
+				"transcript performed {} operations, but the expected operation log has {}",
+				self.ops.len(),
+				expected.len()
+			);
+		}
+	}
+}
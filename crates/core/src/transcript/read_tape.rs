@@ -0,0 +1,140 @@
+// Copyright 2025 Irreducible Inc.
+
+use std::io::{self, Read};
+
+use bytes::Buf;
+
+/// The default size in bytes of the window buffered by a [`ReadTape`].
+pub const DEFAULT_READ_WINDOW: usize = 1 << 16;
+
+/// A proof tape that reads a proof of known length incrementally from an [`io::Read`] source.
+///
+/// The tape buffers a window of at most `window` bytes of the proof. Whenever fewer than half of
+/// the window is buffered, the consumed bytes are discarded and the window is refilled from the
+/// reader, so the memory used is bounded independently of the proof size. Partial reads are
+/// retried until half of the window is buffered or the whole proof has been read. Bytes of the
+/// reader past the length of the proof are never read.
+///
+/// [`Buf::remaining`] reports the number of bytes of the proof that have not been consumed, most
+/// of which are not buffered yet. If the reader ends early or fails with an I/O error, the proof
+/// is truncated to the bytes read so far, which is detected at least half a window ahead of the
+/// bytes consumed. The error is kept to be inspected with [`Self::read_error`].
+#[derive(Debug)]
+pub struct ReadTape<R> {
+	reader: R,
+	buffer: Vec<u8>,
+	start: usize,
+	end: usize,
+	// The number of bytes consumed from the proof so far.
+	consumed: usize,
+	// The length of the proof, which is reduced to the bytes read if the reader ends early.
+	len: usize,
+	error: Option<io::Error>,
+}
+
+impl<R: Read> ReadTape<R> {
+	/// Creates a tape reading a proof of `len` bytes, buffering a window of
+	/// [`DEFAULT_READ_WINDOW`] bytes.
+	pub fn new(reader: R, len: usize) -> Self {
+		Self::with_window(reader, len, DEFAULT_READ_WINDOW)
+	}
+
+	/// Creates a tape reading a proof of `len` bytes, buffering a window of `window` bytes.
+	///
+	/// ## Panics
+	///
+	/// * If `window` is less than 2.
+	pub fn with_window(reader: R, len: usize, window: usize) -> Self {
+		assert!(window >= 2, "the read window must hold at least 2 bytes");
+		let mut tape = Self {
+			reader,
+			buffer: vec![0; window],
+			start: 0,
+			end: 0,
+			consumed: 0,
+			len,
+			error: None,
+		};
+		tape.fill();
+		tape
+	}
+
+	/// Returns the I/O error that truncated the proof, if any.
+	///
+	/// A reader ending before the length of the proof is reported as an error of kind
+	/// [`io::ErrorKind::UnexpectedEof`].
+	pub const fn read_error(&self) -> Option<&io::Error> {
+		self.error.as_ref()
+	}
+
+	const fn buffered(&self) -> usize {
+		self.end - self.start
+	}
+
+	fn fill(&mut self) {
+		let low_water = self.buffer.len() / 2;
+		if self.buffered() >= low_water || self.consumed + self.buffered() == self.len {
+			return;
+		}
+
+		self.buffer.copy_within(self.start..self.end, 0);
+		self.end -= self.start;
+		self.start = 0;
+
+		while self.end < low_water && self.consumed + self.end < self.len {
+			let unread = self.len - self.consumed - self.end;
+			let limit = self.buffer.len().min(self.end + unread);
+			match self.reader.read(&mut self.buffer[self.end..limit]) {
+				Ok(0) => self.truncate(io::ErrorKind::UnexpectedEof.into()),
+				Ok(n) => self.end += n,
+				Err(err) if err.kind() == io::ErrorKind::Interrupted => {}
+				Err(err) => self.truncate(err),
+			}
+		}
+	}
+
+	fn truncate(&mut self, err: io::Error) {
+		self.error = Some(err);
+		self.len = self.consumed + self.end;
+	}
+}
+
+impl<R: Read> Buf for ReadTape<R> {
+	fn remaining(&self) -> usize {
+		self.len - self.consumed
+	}
+
+	fn chunk(&self) -> &[u8] {
+		&self.buffer[self.start..self.end]
+	}
+
+	fn advance(&mut self, mut cnt: usize) {
+		assert!(cnt <= self.remaining());
+		while cnt > 0 {
+			let step = cnt.min(self.buffered());
+			assert!(step > 0, "the proof stream ended before the bytes advanced over");
+			self.start += step;
+			self.consumed += step;
+			cnt -= step;
+			self.fill();
+		}
+	}
+
+	/// Copies bytes into `dst`, refilling the window as often as needed.
+	///
+	/// ## Panics
+	///
+	/// * If fewer than `dst.len()` bytes remain, including when the reader turns out to end before
+	///   the bytes copied.
+	fn copy_to_slice(&mut self, mut dst: &mut [u8]) {
+		assert!(dst.len() <= self.remaining());
+		while !dst.is_empty() {
+			let chunk = self.chunk();
+			assert!(!chunk.is_empty(), "the proof stream ended before the bytes copied");
+			let n = chunk.len().min(dst.len());
+			dst[..n].copy_from_slice(&chunk[..n]);
+			dst = &mut dst[n..];
+			self.advance(n);
+		}
+	}
+}
@@ -0,0 +1,40 @@
+// Copyright 2025 Irreducible Inc.
+
+use binius_field::TowerField;
+use binius_utils::SerializationMode;
+
+use super::TranscriptOpKind;
+
+/// A value in the transcript of a protocol, as listed by
+/// [`gkr_gpa::transcript_schema`](crate::protocols::gkr_gpa::transcript_schema).
+///
+/// A schema lists the values in the order the prover writes and samples them. Only
+/// [`TranscriptOpKind::Message`] values occupy bytes of the proof, while
+/// [`TranscriptOpKind::Sample`] values are drawn from the challenger, which a verifier needs to
+/// keep its Fiat-Shamir state in step with the prover.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TranscriptField {
+	/// A name for the value, unique within the schema.
+	pub name: String,
+	pub kind: TranscriptOpKind,
+	/// The size of the value in its canonical tower serialization.
+	pub n_bytes: usize,
+	/// The tower level of the binary field the value is an element of.
+	pub tower_level: usize,
+}
+
+impl TranscriptField {
+	/// A field element of `F`.
+	pub fn scalar<F: TowerField>(kind: TranscriptOpKind, name: impl ToString) -> Self {
+		let mut bytes = Vec::new();
+		F::ZERO
+			.serialize(&mut bytes, SerializationMode::CanonicalTower)
+			.expect("serializing a field element into a vector cannot fail");
+		Self {
+			name: name.to_string(),
+			kind,
+			n_bytes: bytes.len(),
+			tower_level: F::TOWER_LEVEL,
+		}
+	}
+}
@@ -32,6 +32,15 @@ impl<F: TowerField> Constant<F> {
 			n_vars,
 		}
 	}
+
+	/// Creates a constant polynomial at the minimal tower level containing `value`.
+	pub fn with_min_tower_level(n_vars: usize, value: F) -> Self {
+		Self {
+			value,
+			tower_level: value.min_tower_level(),
+			n_vars,
+		}
+	}
 }
 
 #[erased_serialize_bytes]
@@ -1,11 +1,22 @@
 // Copyright 2024-2025 Irreducible Inc.
 
-use std::{fmt::Debug, sync::Arc};
+use std::{collections::HashSet, fmt::Debug, iter, sync::Arc};
 
-use binius_field::PackedField;
-use binius_math::MultilinearPoly;
+use binius_field::{PackedField, TowerField, packed::set_packed_slice};
+use binius_math::{MLEDirectAdapter, MultilinearExtension, MultilinearPoly, MultilinearQuery};
+use binius_maybe_rayon::prelude::*;
+use binius_utils::bail;
+use itertools::Itertools;
 
-use crate::{oracle::OracleId, polynomial::Error as PolynomialError};
+use crate::{
+	oracle::{
+		MultilinearOracleSet, MultilinearPolyOracle, MultilinearPolyVariant, OracleId, ShiftVariant,
+	},
+	polynomial::{
+		Error as PolynomialError, MultilinearComposite,
+		test_utils::decompose_index_to_hypercube_point,
+	},
+};
 
 pub type MultilinearWitness<'a, P> = Arc<dyn MultilinearPoly<P> + Send + Sync + 'a>;
 
@@ -31,6 +42,12 @@ where
 pub enum Error {
 	#[error("witness not found for oracle {id}")]
 	MissingWitness { id: OracleId },
+	#[error("witness for oracle {id} has {actual} variables, expected {expected}")]
+	IncorrectNumberOfVariables {
+		id: OracleId,
+		expected: usize,
+		actual: usize,
+	},
 	#[error("witness for oracle id {id} does not have an explicit backing multilinear")]
 	NoExplicitBackingMultilinearExtension { id: OracleId },
 	#[error(
@@ -41,6 +58,10 @@ pub enum Error {
 		field_log_extension_degree: usize,
 		entry_log_extension_degree: usize,
 	},
+	#[error("oracle {id} is not a shifted oracle")]
+	NotShiftedOracle { id: OracleId },
+	#[error("oracle {id} is not a linear combination oracle")]
+	NotLinearCombinationOracle { id: OracleId },
 	#[error("polynomial error: {0}")]
 	Polynomial(#[from] PolynomialError),
 	#[error("HAL error: {0}")]
@@ -76,6 +97,269 @@ where
 		self.entries.get(id.index()).is_some_and(Option::is_some)
 	}
 
+	/// Checks that every committed oracle the oracles `ids` depend on has a witness with the
+	/// oracle's number of variables.
+	///
+	/// Committed oracles are the leaves of the oracle graph, so this covers the committed inputs of
+	/// composite and other virtual oracles reachable from `ids`, and no committed oracle outside
+	/// of them. Proving with an incomplete index otherwise fails midway through the protocol with
+	/// a less informative error. Invalid oracle ids are skipped and left for the prover to report.
+	///
+	/// ## Throws
+	///
+	/// * `Error::MissingWitness` for the first committed oracle without a witness
+	/// * `Error::IncorrectNumberOfVariables` for the first witness of the wrong size
+	pub fn validate_complete<F: TowerField>(
+		&self,
+		oracles: &MultilinearOracleSet<F>,
+		ids: impl IntoIterator<Item = OracleId>,
+	) -> Result<(), Error> {
+		let mut visited = HashSet::new();
+		let mut stack = ids.into_iter().collect::<Vec<_>>();
+		while let Some(id) = stack.pop() {
+			if !oracles.is_valid_oracle_id(id) || !visited.insert(id) {
+				continue;
+			}
+
+			let oracle = &oracles[id];
+			if !oracle.variant.is_committed() {
+				stack.extend(oracle.variant.inner_oracle_ids());
+				continue;
+			}
+
+			let n_vars = self.get_multilin_poly(id)?.n_vars();
+			if n_vars != oracle.n_vars() {
+				bail!(Error::IncorrectNumberOfVariables {
+					id,
+					expected: oracle.n_vars(),
+					actual: n_vars,
+				});
+			}
+		}
+		Ok(())
+	}
+
+	/// Computes and inserts the witness of the shifted oracle `shifted_id` from the witness of
+	/// the oracle it shifts.
+	///
+	/// The hypercube evaluations are shifted within every block of `2^block_size` evaluations
+	/// according to the offset and variant of the oracle. Circular shifts wrap evaluations
+	/// around the block, while logical shifts fill the vacated positions with zeros.
+	///
+	/// ## Throws
+	///
+	/// * `Error::NotShiftedOracle` if `shifted_id` is not a shifted oracle
+	/// * `Error::MissingWitness` if the witness of the source oracle is not in the index
+	pub fn populate_shift<F>(
+		&mut self,
+		oracles: &MultilinearOracleSet<F>,
+		shifted_id: OracleId,
+	) -> Result<(), Error>
+	where
+		F: TowerField,
+		P: PackedField<Scalar = F>,
+	{
+		let oracle = &oracles[shifted_id];
+		if !matches!(oracle.variant, MultilinearPolyVariant::Shifted(_)) {
+			bail!(Error::NotShiftedOracle { id: shifted_id });
+		}
+
+		let witness = MLEDirectAdapter::from(self.materialize(oracle)?).upcast_arc_dyn();
+		self.update_multilin_poly([(shifted_id, witness)])
+	}
+
+	/// Computes and inserts the witnesses of the linear combination oracles `ids` in parallel.
+	///
+	/// All inputs are validated before any witness is computed, so on error the index is left
+	/// unchanged. The combinations are computed from the witnesses already in the index, so a
+	/// combination over another one of `ids` must be populated in a separate call. The
+	/// evaluations of every distinct combined oracle are read once and shared by all combinations
+	/// over it.
+	///
+	/// ## Throws
+	///
+	/// * `Error::NotLinearCombinationOracle` if one of `ids` is not a linear combination oracle
+	/// * `Error::MissingWitness` if the witness of an oracle combined by one of `ids` is not in the
+	///   index
+	/// * `Error::IncorrectNumberOfVariables` if the witness of a combined oracle has a different
+	///   number of variables than the combination
+	pub fn populate_linear_combinations<F>(
+		&mut self,
+		oracles: &MultilinearOracleSet<F>,
+		ids: &[OracleId],
+	) -> Result<(), Error>
+	where
+		F: TowerField,
+		P: PackedField<Scalar = F>,
+	{
+		for &id in ids {
+			let MultilinearPolyVariant::LinearCombination(linear_combination) =
+				&oracles[id].variant
+			else {
+				bail!(Error::NotLinearCombinationOracle { id });
+			};
+			for input_id in linear_combination.polys() {
+				let n_vars = self.get_multilin_poly(input_id)?.n_vars();
+				if n_vars != linear_combination.n_vars() {
+					bail!(Error::IncorrectNumberOfVariables {
+						id: input_id,
+						expected: linear_combination.n_vars(),
+						actual: n_vars,
+					});
+				}
+			}
+		}
+
+		let linear_combinations = ids
+			.iter()
+			.map(|&id| match &oracles[id].variant {
+				MultilinearPolyVariant::LinearCombination(linear_combination) => {
+					(id, linear_combination)
+				}
+				_ => unreachable!("the variants are checked above"),
+			})
+			.collect::<Vec<_>>();
+
+		let input_ids = linear_combinations
+			.iter()
+			.flat_map(|(_, linear_combination)| linear_combination.polys())
+			.unique()
+			.collect::<Vec<_>>();
+		let input_evals = input_ids
+			.par_iter()
+			.map(|&id| {
+				let poly = self.get_multilin_poly(id)?;
+				(0..1 << poly.n_vars())
+					.map(|index| Ok(poly.evaluate_on_hypercube(index)?))
+					.collect::<Result<Vec<F>, Error>>()
+			})
+			.collect::<Result<Vec<_>, Error>>()?;
+
+		let witnesses = linear_combinations
+			.par_iter()
+			.map(|&(id, linear_combination)| {
+				let inputs = linear_combination
+					.polys()
+					.map(|input_id| {
+						let position = input_ids
+							.iter()
+							.position(|&shared_id| shared_id == input_id)
+							.expect("input_ids contains the inputs of every combination");
+						&input_evals[position]
+					})
+					.collect::<Vec<_>>();
+				let witness = multilinear_from_fn(linear_combination.n_vars(), |index| {
+					Ok(iter::zip(linear_combination.coefficients(), &inputs)
+						.fold(linear_combination.offset(), |acc, (coeff, evals)| {
+							acc + coeff * evals[index]
+						}))
+				})?;
+				Ok((id, MLEDirectAdapter::from(witness).upcast_arc_dyn()))
+			})
+			.collect::<Result<Vec<_>, Error>>()?;
+		self.update_multilin_poly(witnesses)
+	}
+
+	/// Computes the full witness column of `oracle`, its evaluations over the hypercube.
+	///
+	/// Virtual oracles are evaluated from the witnesses of the oracles they are defined over,
+	/// which must be in the index, as inner oracles are not materialized recursively. The
+	/// witnesses of committed and packed oracles are copied from the index, as a packed oracle
+	/// reinterprets the data of its inner witness rather than computing on it.
+	///
+	/// ## Throws
+	///
+	/// * `Error::MissingWitness` if a witness that the oracle is computed from is not in the index
+	pub fn materialize<F>(
+		&self,
+		oracle: &MultilinearPolyOracle<F>,
+	) -> Result<MultilinearExtension<P>, Error>
+	where
+		F: TowerField,
+		P: PackedField<Scalar = F>,
+	{
+		let n_vars = oracle.n_vars();
+		match &oracle.variant {
+			MultilinearPolyVariant::Committed | MultilinearPolyVariant::Packed(_) => {
+				let poly = self.get_multilin_poly(oracle.id())?;
+				multilinear_from_fn(n_vars, |i| Ok(poly.evaluate_on_hypercube(i)?))
+			}
+			MultilinearPolyVariant::Transparent(transparent) => multilinear_from_fn(n_vars, |i| {
+				let point = decompose_index_to_hypercube_point(n_vars, i);
+				Ok(transparent.poly().evaluate(&point)?)
+			}),
+			MultilinearPolyVariant::Structured(circuit) => multilinear_from_fn(n_vars, |i| {
+				// The circuit may have more variables than the oracle.
+				let point = decompose_index_to_hypercube_point(circuit.n_vars(), i);
+				Ok(circuit.evaluate(&point)?)
+			}),
+			MultilinearPolyVariant::Repeating { id, .. } => {
+				let poly = self.get_multilin_poly(*id)?;
+				let inner_len = 1 << poly.n_vars();
+				multilinear_from_fn(n_vars, |i| Ok(poly.evaluate_on_hypercube(i % inner_len)?))
+			}
+			MultilinearPolyVariant::Projected(projected) => {
+				let poly = self.get_multilin_poly(projected.id())?;
+				let query = MultilinearQuery::<P>::expand(projected.values());
+				Ok(poly.evaluate_partial(query.to_ref(), projected.start_index())?)
+			}
+			MultilinearPolyVariant::Shifted(shifted) => {
+				let poly = self.get_multilin_poly(shifted.id())?;
+				let block_len = 1 << shifted.block_size();
+				let offset = shifted.shift_offset();
+				multilinear_from_fn(n_vars, |index| {
+					let block_start = index - index % block_len;
+					let offset_after = index - block_start;
+					let offset_before = match shifted.shift_variant() {
+						ShiftVariant::CircularLeft => {
+							Some((offset_after + block_len - offset) % block_len)
+						}
+						ShiftVariant::LogicalLeft => offset_after.checked_sub(offset),
+						ShiftVariant::LogicalRight => {
+							Some(offset_after + offset).filter(|&before| before < block_len)
+						}
+					};
+					match offset_before {
+						Some(offset_before) => {
+							Ok(poly.evaluate_on_hypercube(block_start + offset_before)?)
+						}
+						None => Ok(F::ZERO),
+					}
+				})
+			}
+			MultilinearPolyVariant::LinearCombination(linear_combination) => {
+				let polys = linear_combination
+					.polys()
+					.map(|id| self.get_multilin_poly(id))
+					.collect::<Result<Vec<_>, _>>()?;
+				multilinear_from_fn(n_vars, |i| {
+					iter::zip(linear_combination.coefficients(), &polys).try_fold(
+						linear_combination.offset(),
+						|acc, (coeff, poly)| {
+							Ok(acc + poly.evaluate_on_hypercube_and_scale(i, coeff)?)
+						},
+					)
+				})
+			}
+			MultilinearPolyVariant::ZeroPadded(padded) => {
+				let poly = self.get_multilin_poly(padded.id())?;
+				Ok(poly.zero_pad(
+					padded.n_pad_vars(),
+					padded.start_index(),
+					padded.nonzero_index(),
+				)?)
+			}
+			MultilinearPolyVariant::Composite(composite_mle) => {
+				let polys = composite_mle
+					.polys()
+					.map(|id| self.get_multilin_poly(id))
+					.collect::<Result<Vec<_>, _>>()?;
+				let composite = MultilinearComposite::new(n_vars, composite_mle.c(), polys)?;
+				multilinear_from_fn(n_vars, |i| Ok(composite.evaluate_on_hypercube(i)?))
+			}
+		}
+	}
+
 	pub fn update_multilin_poly(
 		&mut self,
 		witnesses: impl IntoIterator<Item = (OracleId, MultilinearWitness<'a, P>)>,
@@ -106,3 +390,171 @@ where
 		Ok(())
 	}
 }
+
+/// Builds the multilinear extension of the hypercube evaluations `eval(0), eval(1), ...`.
+fn multilinear_from_fn<P: PackedField>(
+	n_vars: usize,
+	mut eval: impl FnMut(usize) -> Result<P::Scalar, Error>,
+) -> Result<MultilinearExtension<P>, Error> {
+	let mut evals = vec![P::zero(); 1 << n_vars.saturating_sub(P::LOG_WIDTH)];
+	for index in 0..1 << n_vars {
+		set_packed_slice(&mut evals, index, eval(index)?);
+	}
+	Ok(MultilinearExtension::new(n_vars, evals)?)
+}
+
+#[cfg(test)]
+mod tests {
+	use assert_matches::assert_matches;
+	use binius_field::{BinaryField32b, PackedBinaryField4x32b};
+
+	use super::*;
+
+	type F = BinaryField32b;
+	type P = PackedBinaryField4x32b;
+
+	fn shifted_evals(variant: ShiftVariant) -> Vec<F> {
+		let mut oracles = MultilinearOracleSet::<F>::new();
+		let source_id = oracles.add_committed(3, F::TOWER_LEVEL);
+		let shifted_id = oracles.add_shifted(source_id, 1, 2, variant).unwrap();
+
+		let source_evals = (1..=8).map(F::new).collect::<Vec<_>>();
+		let source = MultilinearExtension::from_values(
+			source_evals
+				.chunks(P::WIDTH)
+				.map(|chunk| P::from_scalars(chunk.iter().copied()))
+				.collect(),
+		)
+		.unwrap();
+
+		let mut witness = MultilinearExtensionIndex::<P>::new();
+		witness
+			.update_multilin_poly([(source_id, source.specialize_arc_dyn())])
+			.unwrap();
+		witness.populate_shift(&oracles, shifted_id).unwrap();
+
+		let shifted = witness.get_multilin_poly(shifted_id).unwrap();
+		(0..8)
+			.map(|i| shifted.evaluate_on_hypercube(i).unwrap())
+			.collect()
+	}
+
+	#[test]
+	fn test_populate_shift_block_boundaries() {
+		let evals = |values: [u32; 8]| values.map(F::new).to_vec();
+		assert_eq!(shifted_evals(ShiftVariant::CircularLeft), evals([4, 1, 2, 3, 8, 5, 6, 7]));
+		assert_eq!(shifted_evals(ShiftVariant::LogicalLeft), evals([0, 1, 2, 3, 0, 5, 6, 7]));
+		assert_eq!(shifted_evals(ShiftVariant::LogicalRight), evals([2, 3, 4, 0, 6, 7, 8, 0]));
+	}
+
+	#[test]
+	fn test_materialize_linear_combination() {
+		let mut oracles = MultilinearOracleSet::<F>::new();
+		let a_id = oracles.add_committed(3, F::TOWER_LEVEL);
+		let b_id = oracles.add_committed(3, F::TOWER_LEVEL);
+		let (offset, coeff_a, coeff_b) = (F::new(7), F::new(3), F::new(5));
+		let combination_id = oracles
+			.add_linear_combination_with_offset(3, offset, [(a_id, coeff_a), (b_id, coeff_b)])
+			.unwrap();
+
+		let a_evals = (1..=8).map(F::new).collect::<Vec<_>>();
+		let b_evals = (11..=18).map(F::new).collect::<Vec<_>>();
+		let to_witness = |evals: &[F]| {
+			MultilinearExtension::from_values(
+				evals
+					.chunks(P::WIDTH)
+					.map(|chunk| P::from_scalars(chunk.iter().copied()))
+					.collect(),
+			)
+			.unwrap()
+			.specialize_arc_dyn()
+		};
+		let mut witness = MultilinearExtensionIndex::<P>::new();
+		witness
+			.update_multilin_poly([(a_id, to_witness(&a_evals)), (b_id, to_witness(&b_evals))])
+			.unwrap();
+
+		let combination = witness.materialize(&oracles[combination_id]).unwrap();
+		assert_eq!(combination.n_vars(), 3);
+		for (i, (&a, &b)) in a_evals.iter().zip(&b_evals).enumerate() {
+			assert_eq!(
+				combination.evaluate_on_hypercube(i).unwrap(),
+				offset + coeff_a * a + coeff_b * b
+			);
+		}
+	}
+
+	#[test]
+	fn test_populate_linear_combinations() {
+		let mut oracles = MultilinearOracleSet::<F>::new();
+		let a_id = oracles.add_committed(4, F::TOWER_LEVEL);
+		let b_id = oracles.add_committed(4, F::TOWER_LEVEL);
+		let combination_ids = [(1, 2), (3, 5), (7, 11)].map(|(coeff_a, coeff_b)| {
+			oracles
+				.add_linear_combination(4, [(a_id, F::new(coeff_a)), (b_id, F::new(coeff_b))])
+				.unwrap()
+		});
+
+		let to_witness = |evals: Vec<F>| {
+			MultilinearExtension::from_values(
+				evals
+					.chunks(P::WIDTH)
+					.map(|chunk| P::from_scalars(chunk.iter().copied()))
+					.collect(),
+			)
+			.unwrap()
+			.specialize_arc_dyn()
+		};
+		let mut witness = MultilinearExtensionIndex::<P>::new();
+		assert_matches!(
+			witness.populate_linear_combinations(&oracles, &combination_ids),
+			Err(Error::MissingWitness { id }) if id == a_id
+		);
+		assert_matches!(
+			witness.populate_linear_combinations(&oracles, &[a_id]),
+			Err(Error::NotLinearCombinationOracle { .. })
+		);
+
+		let mut short_witness = MultilinearExtensionIndex::<P>::new();
+		short_witness
+			.update_multilin_poly([
+				(a_id, to_witness((1..=8).map(F::new).collect())),
+				(b_id, to_witness((21..=36).map(F::new).collect())),
+			])
+			.unwrap();
+		assert_matches!(
+			short_witness.populate_linear_combinations(&oracles, &combination_ids),
+			Err(Error::IncorrectNumberOfVariables {
+				expected: 4,
+				actual: 3,
+				..
+			})
+		);
+
+		witness
+			.update_multilin_poly([
+				(a_id, to_witness((1..=16).map(F::new).collect())),
+				(b_id, to_witness((21..=36).map(F::new).collect())),
+			])
+			.unwrap();
+		witness
+			.populate_linear_combinations(&oracles, &combination_ids)
+			.unwrap();
+
+		for (id, (coeff_a, coeff_b)) in iter::zip(combination_ids, [(1, 2), (3, 5), (7, 11)]) {
+			let combination = witness.get_multilin_poly(id).unwrap();
+			for i in 0..1 << 4 {
+				let expected = F::new(coeff_a) * F::new(i + 1) + F::new(coeff_b) * F::new(i + 21);
+				assert_eq!(combination.evaluate_on_hypercube(i as usize).unwrap(), expected);
+			}
+		}
+	}
+
+	#[test]
+	fn test_populate_shift_rejects_unshifted_oracle() {
+		let mut oracles = MultilinearOracleSet::<F>::new();
+		let id = oracles.add_committed(3, F::TOWER_LEVEL);
+		let mut witness = MultilinearExtensionIndex::<P>::new();
+		assert_matches!(witness.populate_shift(&oracles, id), Err(Error::NotShiftedOracle { .. }));
+	}
+}
@@ -19,6 +19,11 @@ fn square_main<T: PackedField>(val: T) -> T {
 	val.square()
 }
 
+/// Baseline for `square_main`, squaring with a general multiplication.
+fn square_mul<T: PackedField>(val: T) -> T {
+	val * val
+}
+
 cfg_if! {
 	if #[cfg(feature = "benchmark_alternative_strategies")] {
 		use binius_field::{
@@ -56,6 +61,7 @@ cfg_if! {
 			bench_type @ unary_op,
 			strategies @ (
 				(main, PackedField, square_main),
+				(mul, PackedField, square_mul),
 				(pairwise, TaggedSquare::<PairwiseStrategy>, square_pairwise),
 				(pairwise_recursive, TaggedSquare::<PairwiseRecursiveStrategy>, square_pairwise_recursive),
 				(pairwise_table, TaggedSquare::<PairwiseTableStrategy>, square_pairwise_table),
@@ -70,6 +76,7 @@ cfg_if! {
 			bench_type @ unary_op,
 			strategies @ (
 				(main, PackedField, square_main),
+				(mul, PackedField, square_mul),
 			)
 		);
 	}
@@ -298,6 +298,11 @@ impl UnderlierWithBitOps for M128 {
 		Self(u128::fill_with_bit(val))
 	}
 
+	#[inline]
+	fn blend(mask: Self, a: Self, b: Self) -> Self {
+		unsafe { vbslq_u8(mask.into(), a.into(), b.into()).into() }
+	}
+
 	#[inline(always)]
 	unsafe fn get_subvalue<T>(&self, i: usize) -> T
 	where
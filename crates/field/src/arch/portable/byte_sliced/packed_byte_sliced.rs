@@ -7,7 +7,11 @@ use std::{
 	ops::{Add, AddAssign, Mul, MulAssign, Sub, SubAssign},
 };
 
-use binius_utils::checked_arithmetics::checked_log_2;
+use binius_utils::{
+	DeserializeBytes, SerializationError, SerializationMode, SerializeBytes,
+	bytes::{Buf, BufMut},
+	checked_arithmetics::checked_log_2,
+};
 use bytemuck::{Pod, Zeroable};
 
 use super::{invert::invert_or_zero, multiply::mul, square::square};
@@ -128,6 +132,8 @@ macro_rules! define_byte_sliced_3d {
 
 			const LOG_WIDTH: usize = <$packed_storage>::LOG_WIDTH + Self::LOG_HEIGHT;
 
+			const IS_BYTE_SLICED: bool = true;
+
 			#[allow(clippy::modulo_one)]
 			#[inline(always)]
 			unsafe fn get_unchecked(&self, i: usize) -> Self::Scalar {
@@ -332,6 +338,33 @@ macro_rules! define_byte_sliced_3d {
 
 		byte_sliced_common!($name, $packed_storage, $scalar_type, $storage_tower_level);
 
+		/// The scalars are serialized one after another in lane order, so that the bytes do not
+		/// depend on the byte-sliced layout.
+		impl SerializeBytes for $name {
+			fn serialize(
+				&self,
+				mut write_buf: impl BufMut,
+				mode: SerializationMode,
+			) -> Result<(), SerializationError> {
+				for scalar in self.iter() {
+					scalar.serialize(&mut write_buf, mode)?;
+				}
+				Ok(())
+			}
+		}
+
+		impl DeserializeBytes for $name {
+			fn deserialize(
+				mut read_buf: impl Buf,
+				mode: SerializationMode,
+			) -> Result<Self, SerializationError> {
+				let scalars = (0..Self::WIDTH)
+					.map(|_| <$scalar_type>::deserialize(&mut read_buf, mode))
+					.collect::<Result<Vec<_>, _>>()?;
+				Ok(Self::from_scalars(scalars))
+			}
+		}
+
 		impl<Inner: Transformation<$packed_storage, $packed_storage>> Transformation<$name, $name> for TransformationWrapperNxN<Inner, {<$scalar_tower_level as TowerLevel>::WIDTH}> {
 			fn transform(&self, data: &$name) -> $name {
 				let mut result = <$name>::default();
@@ -713,6 +746,8 @@ macro_rules! define_byte_sliced_3d_1b {
 
 			const LOG_WIDTH: usize = <$packed_storage>::LOG_WIDTH + Self::LOG_HEIGHT;
 
+			const IS_BYTE_SLICED: bool = true;
+
 			#[allow(clippy::modulo_one)]
 			#[inline(always)]
 			unsafe fn get_unchecked(&self, i: usize) -> Self::Scalar {
@@ -877,6 +912,36 @@ macro_rules! define_byte_sliced_3d_1b {
 
 		byte_sliced_common!($name, $packed_storage, BinaryField1b, $storage_tower_level);
 
+		/// The scalars are serialized as the bit-packed fields of [`Self::transpose_to`], so that
+		/// the bytes do not depend on the byte-sliced layout.
+		impl SerializeBytes for $name {
+			fn serialize(
+				&self,
+				mut write_buf: impl BufMut,
+				mode: SerializationMode,
+			) -> Result<(), SerializationError> {
+				let mut packed = [Default::default(); Self::HEIGHT_BYTES];
+				self.transpose_to(&mut packed);
+				for packed in &packed {
+					packed.serialize(&mut write_buf, mode)?;
+				}
+				Ok(())
+			}
+		}
+
+		impl DeserializeBytes for $name {
+			fn deserialize(
+				mut read_buf: impl Buf,
+				mode: SerializationMode,
+			) -> Result<Self, SerializationError> {
+				let mut packed = [Default::default(); Self::HEIGHT_BYTES];
+				for packed in &mut packed {
+					*packed = DeserializeBytes::deserialize(&mut read_buf, mode)?;
+				}
+				Ok(Self::transpose_from(&packed))
+			}
+		}
+
 		impl PackedTransformationFactory<$name> for $name {
 			type PackedTransformation<Data: AsRef<[<$name as PackedField>::Scalar]> + Sync> =
 				IDTransformation;
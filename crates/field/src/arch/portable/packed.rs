@@ -18,7 +18,7 @@ use bytemuck::{Pod, TransparentWrapper, Zeroable};
 use rand::RngCore;
 use subtle::{Choice, ConstantTimeEq};
 
-use super::packed_arithmetic::UnderlierWithBitConstants;
+use super::packed_arithmetic::{UnderlierWithBitConstants, broadcast_lowest_bit};
 use crate::{
 	BinaryField, PackedField,
 	arithmetic_traits::{Broadcast, InvertOrZero, MulAlpha, Square},
@@ -330,6 +330,18 @@ where
 			.map_skippable(|underlier| Scalar::from_underlier(underlier))
 	}
 
+	#[inline]
+	fn select(mask: Self, a: Self, b: Self) -> Self {
+		// The lowest bit of the one of every binary field is set, so it tells the zero and one
+		// lanes of the mask apart.
+		let lowest_bits = <Self as Broadcast<Scalar>>::broadcast(Scalar::from_underlier(
+			<Scalar::Underlier as UnderlierWithBitOps>::ONE,
+		));
+		let lane_mask =
+			broadcast_lowest_bit(mask.0 & lowest_bits.0, Scalar::N_BITS.ilog2() as usize);
+		U::blend(lane_mask, a.0, b.0).into()
+	}
+
 	#[inline]
 	fn interleave(self, other: Self, log_block_len: usize) -> (Self, Self) {
 		assert!(log_block_len < Self::LOG_WIDTH);
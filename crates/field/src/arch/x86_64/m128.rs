@@ -365,6 +365,15 @@ impl UnderlierWithBitOps for M128 {
 		Self(unsafe { _mm_set1_epi8(val.wrapping_neg() as i8) })
 	}
 
+	#[inline(always)]
+	fn blend(mask: Self, a: Self, b: Self) -> Self {
+		#[cfg(target_feature = "avx512vl")]
+		let result = unsafe { _mm_ternarylogic_epi64::<0xCA>(mask.0, a.0, b.0) };
+		#[cfg(not(target_feature = "avx512vl"))]
+		let result = unsafe { _mm_or_si128(_mm_and_si128(mask.0, a.0), _mm_andnot_si128(mask.0, b.0)) };
+		Self(result)
+	}
+
 	#[inline(always)]
 	fn from_fn<T>(mut f: impl FnMut(usize) -> T) -> Self
 	where
@@ -375,6 +375,19 @@ impl UnderlierWithBitOps for M256 {
 		Self(unsafe { _mm256_set1_epi8(val.wrapping_neg() as i8) })
 	}
 
+	#[inline(always)]
+	fn blend(mask: Self, a: Self, b: Self) -> Self {
+		cfg_if! {
+			if #[cfg(target_feature = "avx512vl")] {
+				Self(unsafe { _mm256_ternarylogic_epi64::<0xCA>(mask.0, a.0, b.0) })
+			} else {
+				Self(unsafe {
+					_mm256_or_si256(_mm256_and_si256(mask.0, a.0), _mm256_andnot_si256(mask.0, b.0))
+				})
+			}
+		}
+	}
+
 	#[inline(always)]
 	fn from_fn<T>(mut f: impl FnMut(usize) -> T) -> Self
 	where
@@ -424,6 +424,11 @@ impl UnderlierWithBitOps for M512 {
 		Self(unsafe { _mm512_set1_epi8(val.wrapping_neg() as i8) })
 	}
 
+	#[inline(always)]
+	fn blend(mask: Self, a: Self, b: Self) -> Self {
+		Self(unsafe { _mm512_ternarylogic_epi64::<0xCA>(mask.0, a.0, b.0) })
+	}
+
 	#[inline(always)]
 	fn from_fn<T>(mut f: impl FnMut(usize) -> T) -> Self
 	where
@@ -1143,6 +1143,17 @@ pub(crate) mod tests {
 		assert_eq!(<BinaryField128b as ExtensionField<BinaryField8b>>::DEGREE, 16);
 	}
 
+	#[test]
+	fn test_min_tower_level() {
+		assert_eq!(BinaryField128b::ZERO.min_tower_level(), 0);
+		assert_eq!(BinaryField128b::ONE.min_tower_level(), 0);
+		assert_eq!(BinaryField128b::new(2).min_tower_level(), 1);
+		assert_eq!(BinaryField128b::new(125).min_tower_level(), 3);
+		assert_eq!(BinaryField128b::new(1 << 16).min_tower_level(), 5);
+		assert_eq!(BinaryField128b::new(1 << 64).min_tower_level(), 7);
+		assert_eq!(BinaryField32b::new(125).min_tower_level(), 3);
+	}
+
 	#[test]
 	fn test_field_formatting() {
 		assert_eq!(format!("{}", BinaryField4b::from(3)), "0x3");
@@ -33,6 +33,8 @@ pub mod packed_extension;
 pub mod packed_extension_ops;
 mod packed_polyval;
 pub mod polyval;
+#[cfg(feature = "test_utils")]
+pub mod test_utils;
 #[cfg(test)]
 mod tests;
 pub mod tower;
@@ -10,6 +10,7 @@ use std::{
 	ops::{Add, AddAssign, Mul, MulAssign, Sub, SubAssign},
 };
 
+use binius_maybe_rayon::prelude::*;
 use binius_utils::{
 	iter::IterExtensions,
 	random_access_sequence::{RandomAccessSequence, RandomAccessSequenceMut},
@@ -23,7 +24,7 @@ use super::{
 	binary_field_arithmetic::TowerFieldArithmetic,
 };
 use crate::{
-	BinaryField, Field, PackedExtension, arithmetic_traits::InvertOrZero,
+	BinaryField, Field, PackedExtension, PackedFieldIndexable, arithmetic_traits::InvertOrZero,
 	is_packed_field_indexable, underlier::WithUnderlier, unpack_if_possible_mut,
 };
 
@@ -69,6 +70,13 @@ pub trait PackedField:
 	/// WIDTH is guaranteed to equal 2^LOG_WIDTH.
 	const WIDTH: usize = 1 << Self::LOG_WIDTH;
 
+	/// Whether the scalars are stored in byte-sliced layout, i.e. transposed so that each
+	/// underlier holds the same byte of several scalars.
+	///
+	/// Byte-sliced packed fields are inefficient to deinterleave, which is relevant when choosing
+	/// how to fold multilinears over them.
+	const IS_BYTE_SLICED: bool = false;
+
 	/// Get the scalar at a given index without bounds checking.
 	/// # Safety
 	/// The caller must ensure that `i` is less than `WIDTH`.
@@ -202,6 +210,20 @@ pub trait PackedField:
 	/// Returns the packed inverse values or zeroes at indices where `self` is zero.
 	fn invert_or_zero(self) -> Self;
 
+	/// Returns the lanes of `a` where the lanes of `mask` are one, and those of `b` where they are
+	/// zero.
+	///
+	/// The lanes of `mask` must be zero or one. Packed types over an underlier spread the mask to
+	/// whole lanes and blend the underliers bitwise, see [`UnderlierWithBitOps::blend`]. Other
+	/// packed types compute the selection arithmetically as `b + mask * (a - b)`. Neither branches
+	/// nor accesses individual lanes.
+	///
+	/// [`UnderlierWithBitOps::blend`]: crate::underlier::UnderlierWithBitOps::blend
+	#[inline]
+	fn select(mask: Self, a: Self, b: Self) -> Self {
+		b + mask * (a - b)
+	}
+
 	/// Interleaves blocks of this packed vector with another packed vector.
 	///
 	/// The operation can be seen as stacking the two vectors, dividing them into 2x2 matrices of
@@ -447,6 +469,50 @@ pub fn pack_slice<P: PackedField>(scalars: &[P::Scalar]) -> Vec<P> {
 		.collect()
 }
 
+/// Computes the prefix products of the scalars of `data`.
+///
+/// The value at index `i` is the product of the scalars `0..=i`. The scan is work-efficient: the
+/// up-sweep computes the prefix products within blocks of scalars in parallel, the products of the
+/// blocks are scanned serially, and the down-sweep multiplies every block by the product of the
+/// blocks preceding it in parallel. This takes about $2n$ multiplications for $n$ scalars.
+pub fn par_prefix_product<P: PackedFieldIndexable>(data: &[P]) -> Vec<P::Scalar> {
+	// The number of scalars handled by a single parallel task.
+	const BLOCK_SIZE: usize = 1 << 12;
+
+	let mut prefix_products = P::unpack_scalars(data).to_vec();
+
+	prefix_products
+		.par_chunks_mut(BLOCK_SIZE)
+		.for_each(|block| {
+			for i in 1..block.len() {
+				let prev = block[i - 1];
+				block[i] *= prev;
+			}
+		});
+
+	// The offset of every block but the first is the product of the blocks preceding it.
+	let block_offsets = prefix_products
+		.chunks(BLOCK_SIZE)
+		.map(|block| *block.last().expect("chunks are non-empty"))
+		.scan(P::Scalar::ONE, |product, block_product| {
+			*product *= block_product;
+			Some(*product)
+		})
+		.collect::<Vec<_>>();
+
+	let first_block_len = BLOCK_SIZE.min(prefix_products.len());
+	prefix_products[first_block_len..]
+		.par_chunks_mut(BLOCK_SIZE)
+		.zip(block_offsets)
+		.for_each(|(block, offset)| {
+			for scalar in block {
+				*scalar *= offset;
+			}
+		});
+
+	prefix_products
+}
+
 /// Copy scalar elements to a vector of packed field elements.
 pub fn copy_packed_from_scalars_slice<P: PackedField>(src: &[P::Scalar], dst: &mut [P]) {
 	unpack_if_possible_mut(
@@ -646,14 +712,75 @@ mod tests {
 		BinaryField1b, BinaryField2b, BinaryField4b, BinaryField8b, BinaryField16b, BinaryField32b,
 		BinaryField64b, BinaryField128b, BinaryField128bPolyval, PackedField,
 		arch::{
-			byte_sliced::*, packed_1::*, packed_2::*, packed_4::*, packed_8::*, packed_16::*,
-			packed_32::*, packed_64::*, packed_128::*, packed_256::*, packed_512::*,
-			packed_aes_8::*, packed_aes_16::*, packed_aes_32::*, packed_aes_64::*,
-			packed_aes_128::*, packed_aes_256::*, packed_aes_512::*, packed_polyval_128::*,
-			packed_polyval_256::*, packed_polyval_512::*,
+			OptimalUnderlier, OptimalUnderlier256b, OptimalUnderlier512b, byte_sliced::*,
+			packed_1::*, packed_2::*, packed_4::*, packed_8::*, packed_16::*, packed_32::*,
+			packed_64::*, packed_128::*, packed_256::*, packed_512::*, packed_aes_8::*,
+			packed_aes_16::*, packed_aes_32::*, packed_aes_64::*, packed_aes_128::*,
+			packed_aes_256::*, packed_aes_512::*, packed_polyval_128::*, packed_polyval_256::*,
+			packed_polyval_512::*,
 		},
+		as_packed_field::PackedType,
 	};
 
+	fn check_select_alternating_mask<P: PackedField>() {
+		let mut rng = StdRng::seed_from_u64(0);
+		let a = P::random(&mut rng);
+		let b = P::random(&mut rng);
+		let mask = P::from_fn(|i| {
+			if i % 2 == 0 {
+				P::Scalar::ONE
+			} else {
+				P::Scalar::ZERO
+			}
+		});
+
+		let selected = P::select(mask, a, b);
+		for i in 0..P::WIDTH {
+			let expected = if i % 2 == 0 { a.get(i) } else { b.get(i) };
+			assert_eq!(selected.get(i), expected);
+		}
+		assert_eq!(P::select(P::one(), a, b), a);
+		assert_eq!(P::select(P::zero(), a, b), b);
+	}
+
+	#[test]
+	fn test_select_alternating_mask() {
+		// The optimal underlier may hold a single 128-bit lane, so wider underliers are checked
+		// as well.
+		check_select_alternating_mask::<PackedType<OptimalUnderlier, BinaryField128b>>();
+		check_select_alternating_mask::<PackedType<OptimalUnderlier256b, BinaryField128b>>();
+		check_select_alternating_mask::<PackedType<OptimalUnderlier512b, BinaryField128b>>();
+
+		// The mask is spread from the lowest bit of every lane, which depends on the lane width
+		// and on the representation of one.
+		check_select_alternating_mask::<PackedBinaryField128x1b>();
+		check_select_alternating_mask::<PackedBinaryField32x4b>();
+		check_select_alternating_mask::<PackedBinaryField16x8b>();
+		check_select_alternating_mask::<PackedBinaryField8x64b>();
+		check_select_alternating_mask::<PackedAESBinaryField32x8b>();
+		check_select_alternating_mask::<PackedBinaryPolyval4x128b>();
+		check_select_alternating_mask::<PackedBinaryField8x1b>();
+		check_select_alternating_mask::<ByteSlicedAES32x128b>();
+	}
+
+	#[test]
+	fn test_par_prefix_product_matches_serial_scan() {
+		let mut rng = StdRng::seed_from_u64(0);
+		for n_packed in [0, 1, 3, (1 << 16) / PackedBinaryField4x32b::WIDTH] {
+			let data = iter::repeat_with(|| PackedBinaryField4x32b::random(&mut rng))
+				.take(n_packed)
+				.collect::<Vec<_>>();
+
+			let expected = PackedField::iter_slice(&data)
+				.scan(BinaryField32b::ONE, |product, scalar| {
+					*product *= scalar;
+					Some(*product)
+				})
+				.collect::<Vec<_>>();
+			assert_eq!(par_prefix_product(&data), expected);
+		}
+	}
+
 	trait PackedFieldTest {
 		fn run<P: PackedField>(&self);
 	}
@@ -875,6 +1002,27 @@ mod tests {
 		run_for_all_packed_fields(&PackedFieldIterationTest);
 	}
 
+	fn check_broadcast<P: PackedField>(mut rng: impl RngCore) {
+		let scalar = <P::Scalar as Field>::random(&mut rng);
+		let packed = P::broadcast(scalar);
+		assert!(packed.iter().all(|lane| lane == scalar));
+		assert_eq!(packed.iter().count(), P::WIDTH);
+	}
+
+	struct PackedFieldBroadcastTest;
+
+	impl PackedFieldTest for PackedFieldBroadcastTest {
+		fn run<P: PackedField>(&self) {
+			check_broadcast::<P>(StdRng::seed_from_u64(0));
+		}
+	}
+
+	#[test]
+	fn test_broadcast() {
+		run_for_all_packed_fields(&PackedFieldBroadcastTest);
+		check_broadcast::<PackedType<OptimalUnderlier, BinaryField128b>>(StdRng::seed_from_u64(0));
+	}
+
 	fn check_copy_from_scalars<P: PackedField>(mut rng: impl RngCore) {
 		let scalars = (0..100)
 			.map(|_| <<P as PackedField>::Scalar as Field>::random(&mut rng))
@@ -0,0 +1,203 @@
+// Copyright 2025 Irreducible Inc.
+
+//! Utilities for generating packed field test vectors with a given structure, and for checking
+//! the conformance of packed field implementations.
+
+use std::iter::repeat_with;
+
+use binius_utils::{DeserializeBytes, SerializationMode, SerializeBytes};
+use rand::{RngCore, SeedableRng, rngs::StdRng};
+
+// This module shadows the glob re-export of the packed arithmetic checks used by the unit
+// tests of this crate, so they are re-exported from here.
+#[cfg(test)]
+pub(crate) use crate::packed_binary_field::test_utils::*;
+use crate::{BinaryField, Field, PackedField};
+
+/// Returns a random packed value whose scalars are all nonzero.
+///
+/// Every scalar is sampled uniformly from the nonzero field elements, by rejecting zero draws.
+/// This is useful for products that must not vanish, like the factors of a grand product.
+pub fn random_packed_nonzero<P: PackedField>(mut rng: impl RngCore) -> P {
+	P::from_fn(|_| {
+		loop {
+			let scalar = <P::Scalar as Field>::random(&mut rng);
+			if scalar != P::Scalar::ZERO {
+				break scalar;
+			}
+		}
+	})
+}
+
+/// Returns a random packed value with the same scalar in every lane.
+pub fn random_packed_uniform_lanes<P: PackedField>(rng: impl RngCore) -> P {
+	P::broadcast(<P::Scalar as Field>::random(rng))
+}
+
+/// Asserts that a random packed value round-trips through its own serialization, and that the
+/// serialized bytes are the canonical bytes of its scalars in lane order. Returns the bytes.
+///
+/// The canonical bytes of a scalar are its [`SerializationMode::CanonicalTower`] serialization.
+/// Scalars of at least a byte are laid out one after another, and narrower scalars are packed
+/// into bytes starting from the least significant bit. The scalars are sampled in lane order from
+/// a [`StdRng`] seeded with `seed`, so the bytes returned for two packings of the same field agree
+/// on the lanes they have in common.
+pub fn assert_serialize_roundtrip<P>(seed: u64) -> Vec<u8>
+where
+	P: PackedField<Scalar: BinaryField + SerializeBytes> + SerializeBytes + DeserializeBytes,
+{
+	let mut rng = StdRng::seed_from_u64(seed);
+	let scalars = repeat_with(|| <P::Scalar as Field>::random(&mut rng))
+		.take(P::WIDTH)
+		.collect::<Vec<_>>();
+	let packed = P::from_scalars(scalars.iter().copied());
+	assert_eq!(packed.iter().collect::<Vec<_>>(), scalars);
+
+	let mut lane_bytes = Vec::new();
+	for scalar in packed.iter() {
+		scalar
+			.serialize(&mut lane_bytes, SerializationMode::CanonicalTower)
+			.expect("serializing to a vector cannot fail");
+	}
+	let scalar_bits = <P::Scalar as BinaryField>::N_BITS;
+	let expected = if scalar_bits >= 8 {
+		lane_bytes
+	} else {
+		lane_bytes
+			.chunks(8 / scalar_bits)
+			.map(|lanes| {
+				lanes
+					.iter()
+					.enumerate()
+					.fold(0u8, |byte, (i, &lane)| byte | (lane << (i * scalar_bits)))
+			})
+			.collect()
+	};
+
+	let mut bytes = Vec::new();
+	packed
+		.serialize(&mut bytes, SerializationMode::CanonicalTower)
+		.expect("serializing to a vector cannot fail");
+	assert_eq!(bytes, expected);
+
+	let mut read_buf = bytes.as_slice();
+	let deserialized = P::deserialize(&mut read_buf, SerializationMode::CanonicalTower)
+		.expect("the bytes were serialized from a value of the same type");
+	assert!(read_buf.is_empty());
+	assert_eq!(deserialized, packed);
+
+	bytes
+}
+
+#[cfg(test)]
+mod tests {
+	use rand::{SeedableRng, rngs::StdRng};
+
+	use super::*;
+	use crate::{
+		AESTowerField8b, AESTowerField16b, AESTowerField32b, AESTowerField64b, AESTowerField128b,
+		BinaryField1b, BinaryField2b, BinaryField4b, BinaryField8b, BinaryField16b, BinaryField32b,
+		BinaryField64b, BinaryField128b, PackedBinaryField4x32b, PackedBinaryField32x4b,
+		PackedBinaryField128x1b,
+		arch::{
+			OptimalUnderlier, OptimalUnderlier128b, OptimalUnderlier256b, OptimalUnderlier512b,
+			OptimalUnderlierByteSliced,
+		},
+		as_packed_field::{PackScalar, PackedType},
+	};
+
+	fn check_nonzero<P: PackedField>(rng: &mut StdRng) {
+		for _ in 0..1000 {
+			let packed = random_packed_nonzero::<P>(&mut *rng);
+			assert!(packed.iter().all(|scalar| scalar != P::Scalar::ZERO));
+		}
+	}
+
+	#[test]
+	fn test_random_packed_nonzero() {
+		let mut rng = StdRng::seed_from_u64(0);
+		check_nonzero::<PackedBinaryField128x1b>(&mut rng);
+		check_nonzero::<PackedBinaryField32x4b>(&mut rng);
+		check_nonzero::<PackedBinaryField4x32b>(&mut rng);
+
+		// The only nonzero element of the base field fills every lane.
+		let packed = random_packed_nonzero::<PackedBinaryField128x1b>(&mut rng);
+		assert_eq!(packed, PackedBinaryField128x1b::broadcast(BinaryField1b::ONE));
+	}
+
+	#[test]
+	fn test_random_packed_uniform_lanes() {
+		let mut rng = StdRng::seed_from_u64(0);
+		for _ in 0..100 {
+			let packed = random_packed_uniform_lanes::<PackedBinaryField4x32b>(&mut rng);
+			let first = packed.get(0);
+			assert!(packed.iter().all(|scalar| scalar == first));
+		}
+	}
+
+	/// Asserts that the canonical bytes of every pair of packings agree on their common lanes.
+	fn assert_common_prefix(bytes: &[Vec<u8>]) {
+		for a in bytes {
+			for b in bytes {
+				let len = a.len().min(b.len());
+				assert_eq!(a[..len], b[..len]);
+			}
+		}
+	}
+
+	/// Checks the bit-packed packings of `F` and returns their bytes.
+	fn check_packings<F>() -> Vec<Vec<u8>>
+	where
+		F: BinaryField + SerializeBytes,
+		OptimalUnderlier128b: PackScalar<F>,
+		OptimalUnderlier256b: PackScalar<F>,
+		OptimalUnderlier512b: PackScalar<F>,
+		OptimalUnderlier: PackScalar<F>,
+		PackedType<OptimalUnderlier128b, F>: SerializeBytes + DeserializeBytes,
+		PackedType<OptimalUnderlier256b, F>: SerializeBytes + DeserializeBytes,
+		PackedType<OptimalUnderlier512b, F>: SerializeBytes + DeserializeBytes,
+		PackedType<OptimalUnderlier, F>: SerializeBytes + DeserializeBytes,
+	{
+		let bytes = vec![
+			assert_serialize_roundtrip::<PackedType<OptimalUnderlier128b, F>>(0),
+			assert_serialize_roundtrip::<PackedType<OptimalUnderlier256b, F>>(0),
+			assert_serialize_roundtrip::<PackedType<OptimalUnderlier512b, F>>(0),
+			assert_serialize_roundtrip::<PackedType<OptimalUnderlier, F>>(0),
+		];
+		assert_common_prefix(&bytes);
+		bytes
+	}
+
+	fn check_byte_sliced_packing<F>() -> Vec<u8>
+	where
+		F: BinaryField + SerializeBytes,
+		OptimalUnderlierByteSliced: PackScalar<F>,
+		PackedType<OptimalUnderlierByteSliced, F>: SerializeBytes + DeserializeBytes,
+	{
+		assert_serialize_roundtrip::<PackedType<OptimalUnderlierByteSliced, F>>(0)
+	}
+
+	#[test]
+	fn test_serialize_roundtrip() {
+		// The byte-sliced packing of 1b scalars must agree with the bit-packed ones.
+		let mut bytes = check_packings::<BinaryField1b>();
+		bytes.push(check_byte_sliced_packing::<BinaryField1b>());
+		assert_common_prefix(&bytes);
+
+		check_packings::<BinaryField2b>();
+		check_packings::<BinaryField4b>();
+		check_packings::<BinaryField8b>();
+		check_packings::<BinaryField16b>();
+		check_packings::<BinaryField32b>();
+		check_packings::<BinaryField64b>();
+		check_packings::<BinaryField128b>();
+
+		// The byte-sliced packings of AES scalars serialize to the canonical bytes of their lanes,
+		// which the round-trip check compares with.
+		check_byte_sliced_packing::<AESTowerField8b>();
+		check_byte_sliced_packing::<AESTowerField16b>();
+		check_byte_sliced_packing::<AESTowerField32b>();
+		check_byte_sliced_packing::<AESTowerField64b>();
+		check_byte_sliced_packing::<AESTowerField128b>();
+	}
+}
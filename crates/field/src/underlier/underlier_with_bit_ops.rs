@@ -31,6 +31,12 @@ pub trait UnderlierWithBitOps:
 	/// `val` must be 0 or 1.
 	fn fill_with_bit(val: u8) -> Self;
 
+	/// Returns the bits of `a` where the bits of `mask` are set, and those of `b` elsewhere.
+	#[inline(always)]
+	fn blend(mask: Self, a: Self, b: Self) -> Self {
+		(a & mask) | (b & !mask)
+	}
+
 	#[inline]
 	fn from_fn<T>(mut f: impl FnMut(usize) -> T) -> Self
 	where
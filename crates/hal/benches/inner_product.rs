@@ -0,0 +1,46 @@
+// Copyright 2025 Irreducible Inc.
+
+use std::iter::repeat_with;
+
+use binius_field::{Field, PackedBinaryField2x128b, PackedField};
+use binius_hal::{ComputationBackend, make_portable_backend};
+use criterion::{Criterion, criterion_group, criterion_main};
+use rand::{SeedableRng, rngs::StdRng};
+
+/// The naive serial loop the backend method replaces, over unpacked scalars.
+fn naive_inner_product<P: PackedField>(a: &[P], b: &[P]) -> P::Scalar {
+	let mut acc = P::Scalar::ZERO;
+	for (a, b) in P::iter_slice(a).zip(P::iter_slice(b)) {
+		acc += a * b;
+	}
+	acc
+}
+
+fn bench_inner_product(c: &mut Criterion) {
+	type P = PackedBinaryField2x128b;
+
+	let mut group = c.benchmark_group("inner_product");
+	let mut rng = StdRng::seed_from_u64(0);
+	let backend = make_portable_backend();
+
+	let log_size = 20;
+	let [a, b] = [(); 2].map(|_| {
+		repeat_with(|| P::random(&mut rng))
+			.take(1 << (log_size - P::LOG_WIDTH))
+			.collect::<Vec<_>>()
+	});
+
+	assert_eq!(naive_inner_product(&a, &b), backend.inner_product(&a, &b).unwrap());
+
+	group.throughput(criterion::Throughput::Elements(1 << log_size));
+	group.bench_function(format!("naive/{log_size}"), |bench| {
+		bench.iter(|| naive_inner_product(&a, &b));
+	});
+	group.bench_function(format!("backend/{log_size}"), |bench| {
+		bench.iter(|| backend.inner_product(&a, &b).unwrap());
+	});
+	group.finish();
+}
+
+criterion_group!(inner_product, bench_inner_product);
+criterion_main!(inner_product);
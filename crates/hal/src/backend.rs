@@ -5,15 +5,18 @@ use std::{
 	ops::{Deref, DerefMut},
 };
 
-use binius_field::{Field, PackedExtension, PackedField};
+use binius_field::{ExtensionField, Field, PackedExtension, PackedField};
 use binius_math::{
-	CompositionPoly, EvaluationOrder, MultilinearExtension, MultilinearPoly, MultilinearQuery,
-	MultilinearQueryRef,
+	CompositionPoly, EvaluationDomain, EvaluationOrder, MultilinearExtension, MultilinearPoly,
+	MultilinearQuery, MultilinearQueryRef,
 };
 use binius_maybe_rayon::iter::FromParallelIterator;
 use tracing::instrument;
 
-use crate::{Error, RoundEvals, SumcheckEvaluator, SumcheckMultilinear};
+use crate::{
+	Error, RoundEvals, SumcheckEvaluator, SumcheckMultilinear, inner_product::inner_product,
+	sumcheck_round_evals::sumcheck_round_evals,
+};
 
 /// HAL-managed memory containing the result of its operations.
 pub trait HalSlice<P: Debug + Send + Sync>:
@@ -74,12 +77,43 @@ pub trait ComputationBackend: Send + Sync + Debug {
 		P: PackedField,
 		M: MultilinearPoly<P> + Send + Sync;
 
+	/// Computes the evaluations of a sumcheck round polynomial at the finite points of `domain`.
+	///
+	/// The multilinears are fully materialized, all of the same length, and the round variable is
+	/// the highest-indexed one. The round polynomial is the sum over the remaining hypercube of the
+	/// composition applied to the multilinears with the round variable set to each domain point.
+	/// Domains containing the Karatsuba infinity point are not supported.
+	///
+	/// The default implementation is sequential.
+	fn sumcheck_round_evals<FDomain, P>(
+		&self,
+		multilinears: &[&[P]],
+		composition: &dyn CompositionPoly<P>,
+		domain: &EvaluationDomain<FDomain>,
+	) -> Result<Vec<P::Scalar>, Error>
+	where
+		FDomain: Field,
+		P: PackedField<Scalar: ExtensionField<FDomain>>,
+	{
+		sumcheck_round_evals(multilinears, composition, domain)
+	}
+
 	/// Partially evaluate the polynomial with assignment to the high-indexed variables.
 	fn evaluate_partial_high<P: PackedField>(
 		&self,
 		multilinear: &impl MultilinearPoly<P>,
 		query_expansion: MultilinearQueryRef<P>,
 	) -> Result<MultilinearExtension<P>, Error>;
+
+	/// Computes the inner product of the scalars of `a` and `b`.
+	///
+	/// The evaluation of a multilinear at a point is the inner product of its evaluations with the
+	/// tensor expansion of the point.
+	///
+	/// The default implementation is sequential.
+	fn inner_product<P: PackedField>(&self, a: &[P], b: &[P]) -> Result<P::Scalar, Error> {
+		inner_product(a, b)
+	}
 }
 
 /// Makes it unnecessary to clone backends.
@@ -150,6 +184,19 @@ where
 		)
 	}
 
+	fn sumcheck_round_evals<FDomain, P>(
+		&self,
+		multilinears: &[&[P]],
+		composition: &dyn CompositionPoly<P>,
+		domain: &EvaluationDomain<FDomain>,
+	) -> Result<Vec<P::Scalar>, Error>
+	where
+		FDomain: Field,
+		P: PackedField<Scalar: ExtensionField<FDomain>>,
+	{
+		T::sumcheck_round_evals(self, multilinears, composition, domain)
+	}
+
 	fn evaluate_partial_high<P: PackedField>(
 		&self,
 		multilinear: &impl MultilinearPoly<P>,
@@ -157,6 +204,10 @@ where
 	) -> Result<MultilinearExtension<P>, Error> {
 		T::evaluate_partial_high(self, multilinear, query_expansion)
 	}
+
+	fn inner_product<P: PackedField>(&self, a: &[P], b: &[P]) -> Result<P::Scalar, Error> {
+		T::inner_product(self, a, b)
+	}
 }
 
 pub trait ComputationBackendExt: ComputationBackend {
@@ -169,6 +220,20 @@ pub trait ComputationBackendExt: ComputationBackend {
 		let tensor_product = self.tensor_product_full_query(query)?;
 		Ok(MultilinearQuery::with_expansion(query.len(), tensor_product)?)
 	}
+
+	/// Evaluates several multilinears at the same `point`.
+	///
+	/// The tensor expansion of `point` is computed once by the backend and shared across all
+	/// multilinears, see [`binius_math::batch_evaluate`].
+	#[instrument(skip_all, level = "debug")]
+	fn batch_evaluate<P: PackedField>(
+		&self,
+		multilins: &[&[P]],
+		point: &[P::Scalar],
+	) -> Result<Vec<P::Scalar>, Error> {
+		let query = self.multilinear_query(point)?;
+		Ok(binius_math::batch_evaluate(multilins, &query)?)
+	}
 }
 
 impl<Backend> ComputationBackendExt for Backend where Backend: ComputationBackend {}
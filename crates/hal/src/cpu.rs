@@ -2,16 +2,18 @@
 
 use std::fmt::Debug;
 
-use binius_field::{Field, PackedExtension, PackedField};
+use binius_field::{ExtensionField, Field, PackedExtension, PackedField};
 use binius_math::{
-	CompositionPoly, EvaluationOrder, MultilinearExtension, MultilinearPoly, MultilinearQueryRef,
-	eq_ind_partial_eval,
+	CompositionPoly, EvaluationDomain, EvaluationOrder, MultilinearExtension, MultilinearPoly,
+	MultilinearQueryRef, eq_ind_partial_eval,
 };
 use tracing::instrument;
 
 use crate::{
 	ComputationBackend, Error, RoundEvals, SumcheckEvaluator, SumcheckMultilinear,
-	sumcheck_folding::fold_multilinears, sumcheck_round_calculation::calculate_round_evals,
+	inner_product::inner_product_par, sumcheck_folding::fold_multilinears,
+	sumcheck_round_calculation::calculate_round_evals,
+	sumcheck_round_evals::sumcheck_round_evals_par,
 };
 
 /// Implementation of ComputationBackend for the default Backend that uses the CPU for all
@@ -79,6 +81,20 @@ impl ComputationBackend for CpuBackend {
 		fold_multilinears(evaluation_order, n_vars, multilinears, challenge, tensor_query)
 	}
 
+	#[instrument(skip_all, name = "CpuBackend::sumcheck_round_evals")]
+	fn sumcheck_round_evals<FDomain, P>(
+		&self,
+		multilinears: &[&[P]],
+		composition: &dyn CompositionPoly<P>,
+		domain: &EvaluationDomain<FDomain>,
+	) -> Result<Vec<P::Scalar>, Error>
+	where
+		FDomain: Field,
+		P: PackedField<Scalar: ExtensionField<FDomain>>,
+	{
+		sumcheck_round_evals_par(multilinears, composition, domain)
+	}
+
 	#[instrument(skip_all, name = "CpuBackend::evaluate_partial_high")]
 	fn evaluate_partial_high<P: PackedField>(
 		&self,
@@ -87,4 +103,9 @@ impl ComputationBackend for CpuBackend {
 	) -> Result<MultilinearExtension<P>, Error> {
 		Ok(multilinear.evaluate_partial_high(query_expansion)?)
 	}
+
+	#[instrument(skip_all, name = "CpuBackend::inner_product")]
+	fn inner_product<P: PackedField>(&self, a: &[P], b: &[P]) -> Result<P::Scalar, Error> {
+		inner_product_par(a, b)
+	}
 }
@@ -17,6 +17,14 @@ pub enum Error {
 	NoScratchSpace,
 	#[error("incorrect multilinear access destination slice lengths")]
 	IncorrectDestSliceLengths,
+	#[error("the composition has {expected} variables, but {actual} multilinears were provided")]
+	CompositionArityMismatch { expected: usize, actual: usize },
+	#[error("multilinears must have equal, even and non-zero packed lengths")]
+	IncorrectMultilinearLengths,
+	#[error("the Karatsuba infinity evaluation point is not supported")]
+	InfinityPointUnsupported,
+	#[error("inner product operands have mismatched lengths {lhs} and {rhs}")]
+	InnerProductLengthMismatch { lhs: usize, rhs: usize },
 	#[error("{0}")]
 	FieldError(#[from] binius_field::Error),
 }
@@ -0,0 +1,91 @@
+// Copyright 2025 Irreducible Inc.
+
+//! Inner products of packed field slices.
+//!
+//! The evaluation of a multilinear at a point is the inner product of its hypercube evaluations
+//! with the tensor expansion of the point, which makes this the innermost loop of multilinear
+//! evaluation.
+
+use binius_field::PackedField;
+use binius_maybe_rayon::prelude::*;
+use binius_utils::bail;
+
+use crate::Error;
+
+fn validate_args<P: PackedField>(a: &[P], b: &[P]) -> Result<(), Error> {
+	if a.len() != b.len() {
+		bail!(Error::InnerProductLengthMismatch {
+			lhs: a.len(),
+			rhs: b.len(),
+		});
+	}
+	Ok(())
+}
+
+/// Sums the lane-wise products of `a` and `b` without the horizontal reduction.
+fn packed_inner_product<P: PackedField>(a: &[P], b: &[P]) -> P {
+	a.iter().zip(b).map(|(&a, &b)| a * b).sum()
+}
+
+/// Computes the inner product $\sum_i a_i b_i$ of the scalars of `a` and `b`.
+pub(crate) fn inner_product<P: PackedField>(a: &[P], b: &[P]) -> Result<P::Scalar, Error> {
+	validate_args(a, b)?;
+	Ok(packed_inner_product(a, b).iter().sum())
+}
+
+/// Parallel version of [`inner_product`].
+pub(crate) fn inner_product_par<P: PackedField>(a: &[P], b: &[P]) -> Result<P::Scalar, Error> {
+	// The number of packed elements handled by a single parallel task.
+	const CHUNK_SIZE: usize = 1 << 10;
+
+	validate_args(a, b)?;
+	let packed = a
+		.par_chunks(CHUNK_SIZE)
+		.zip(b.par_chunks(CHUNK_SIZE))
+		.map(|(a, b)| packed_inner_product(a, b))
+		.reduce(P::zero, |lhs, rhs| lhs + rhs);
+	Ok(packed.iter().sum())
+}
+
+#[cfg(test)]
+mod tests {
+	use std::iter::repeat_with;
+
+	use binius_field::{BinaryField128b, PackedBinaryField2x128b};
+	use rand::{SeedableRng, rngs::StdRng};
+
+	use super::*;
+
+	#[test]
+	fn test_inner_product_matches_naive() {
+		type P = PackedBinaryField2x128b;
+
+		let mut rng = StdRng::seed_from_u64(0);
+		// Covers an empty input, a single chunk and several chunks with a partial last one.
+		for len in [0, 1, 7, 3 << 10] {
+			let a = repeat_with(|| P::random(&mut rng))
+				.take(len)
+				.collect::<Vec<_>>();
+			let b = repeat_with(|| P::random(&mut rng))
+				.take(len)
+				.collect::<Vec<_>>();
+			let expected = PackedField::iter_slice(&a)
+				.zip(PackedField::iter_slice(&b))
+				.map(|(a, b)| a * b)
+				.sum::<BinaryField128b>();
+
+			assert_eq!(inner_product(&a, &b).unwrap(), expected);
+			assert_eq!(inner_product_par(&a, &b).unwrap(), expected);
+		}
+	}
+
+	#[test]
+	fn test_inner_product_length_mismatch() {
+		let (a, b) =
+			(vec![PackedBinaryField2x128b::zero(); 2], vec![PackedBinaryField2x128b::zero(); 3]);
+		assert!(matches!(
+			inner_product(&a, &b),
+			Err(Error::InnerProductLengthMismatch { lhs: 2, rhs: 3 })
+		));
+	}
+}
@@ -12,10 +12,12 @@ mod backend;
 mod common;
 mod cpu;
 mod error;
+mod inner_product;
 mod sumcheck_evaluator;
 mod sumcheck_folding;
 mod sumcheck_multilinear;
 mod sumcheck_round_calculation;
+mod sumcheck_round_evals;
 
 pub use backend::*;
 pub use cpu::*;
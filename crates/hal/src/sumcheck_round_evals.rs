@@ -0,0 +1,275 @@
+// Copyright 2025 Irreducible Inc.
+
+//! Standalone evaluation of a sumcheck round polynomial over packed multilinears.
+//!
+//! Unlike [`calculate_round_evals`](crate::sumcheck_round_calculation), which works over lazily
+//! accessed [`MultilinearPoly`](binius_math::MultilinearPoly) instances and a set of evaluators,
+//! the functions here operate on fully materialized multilinears and a single composition. This is
+//! the innermost loop of the sumcheck prover in its simplest form.
+
+use std::ops::Range;
+
+use binius_field::{ExtensionField, Field, PackedField};
+use binius_math::{CompositionPoly, EvaluationDomain};
+use binius_maybe_rayon::prelude::*;
+use binius_utils::bail;
+
+use crate::Error;
+
+/// Validates the arguments to the round evaluation and returns the number of packed elements in
+/// each half of the multilinears.
+fn validate_args<FDomain, P>(
+	multilinears: &[&[P]],
+	composition: &dyn CompositionPoly<P>,
+	domain: &EvaluationDomain<FDomain>,
+) -> Result<usize, Error>
+where
+	FDomain: Field,
+	P: PackedField,
+{
+	if composition.n_vars() != multilinears.len() {
+		bail!(Error::CompositionArityMismatch {
+			expected: composition.n_vars(),
+			actual: multilinears.len(),
+		});
+	}
+
+	if domain.with_infinity() {
+		bail!(Error::InfinityPointUnsupported);
+	}
+
+	let len = multilinears
+		.first()
+		.map_or(0, |multilinear| multilinear.len());
+	if len == 0
+		|| len % 2 != 0
+		|| multilinears
+			.iter()
+			.any(|multilinear| multilinear.len() != len)
+	{
+		bail!(Error::IncorrectMultilinearLengths);
+	}
+
+	Ok(len / 2)
+}
+
+/// Accumulates the packed composition evaluations at every domain point over the given range of
+/// packed indices into the lower halves of the multilinears.
+fn accumulate_round_evals<P>(
+	multilinears: &[&[P]],
+	composition: &dyn CompositionPoly<P>,
+	points: &[P],
+	indices: Range<usize>,
+) -> Result<Vec<P>, Error>
+where
+	P: PackedField,
+{
+	let half_len = multilinears
+		.first()
+		.map_or(0, |multilinear| multilinear.len() / 2);
+	let mut query = vec![P::zero(); multilinears.len()];
+	let mut accumulators = vec![P::zero(); points.len()];
+	for i in indices {
+		for (&point, accumulator) in points.iter().zip(&mut accumulators) {
+			for (query_elem, multilinear) in query.iter_mut().zip(multilinears) {
+				let lo = multilinear[i];
+				let hi = multilinear[half_len + i];
+				*query_elem = lo + (hi - lo) * point;
+			}
+			*accumulator += composition.evaluate(&query)?;
+		}
+	}
+	Ok(accumulators)
+}
+
+fn broadcast_points<FDomain, P>(domain: &EvaluationDomain<FDomain>) -> Vec<P>
+where
+	FDomain: Field,
+	P: PackedField<Scalar: ExtensionField<FDomain>>,
+{
+	domain
+		.finite_points()
+		.iter()
+		.map(|&point| P::broadcast(point.into()))
+		.collect()
+}
+
+fn sum_packed<P: PackedField>(accumulators: Vec<P>) -> Vec<P::Scalar> {
+	accumulators
+		.into_iter()
+		.map(|packed| packed.iter().sum())
+		.collect()
+}
+
+/// Computes the evaluations of a sumcheck round polynomial at the points of `domain`.
+///
+/// The round variable is the highest-indexed variable of the multilinears, so that the lower and
+/// upper halves of each packed slice are the restrictions to 0 and 1, respectively. The returned
+/// vector has one entry per finite point of `domain`.
+pub(crate) fn sumcheck_round_evals<FDomain, P>(
+	multilinears: &[&[P]],
+	composition: &dyn CompositionPoly<P>,
+	domain: &EvaluationDomain<FDomain>,
+) -> Result<Vec<P::Scalar>, Error>
+where
+	FDomain: Field,
+	P: PackedField<Scalar: ExtensionField<FDomain>>,
+{
+	let half_len = validate_args(multilinears, composition, domain)?;
+	let points = broadcast_points::<_, P>(domain);
+	let accumulators = accumulate_round_evals(multilinears, composition, &points, 0..half_len)?;
+	Ok(sum_packed(accumulators))
+}
+
+/// Parallel version of [`sumcheck_round_evals`].
+pub(crate) fn sumcheck_round_evals_par<FDomain, P>(
+	multilinears: &[&[P]],
+	composition: &dyn CompositionPoly<P>,
+	domain: &EvaluationDomain<FDomain>,
+) -> Result<Vec<P::Scalar>, Error>
+where
+	FDomain: Field,
+	P: PackedField<Scalar: ExtensionField<FDomain>>,
+{
+	// The number of packed elements handled by a single parallel task.
+	const CHUNK_SIZE: usize = 1 << 8;
+
+	let half_len = validate_args(multilinears, composition, domain)?;
+	let points = broadcast_points::<_, P>(domain);
+	let accumulators = (0..half_len.div_ceil(CHUNK_SIZE))
+		.into_par_iter()
+		.map(|chunk_index| {
+			let start = chunk_index * CHUNK_SIZE;
+			let end = (start + CHUNK_SIZE).min(half_len);
+			accumulate_round_evals(multilinears, composition, &points, start..end)
+		})
+		.try_reduce(
+			|| vec![P::zero(); points.len()],
+			|mut lhs, rhs| {
+				for (lhs_val, rhs_val) in lhs.iter_mut().zip(rhs) {
+					*lhs_val += rhs_val;
+				}
+				Ok(lhs)
+			},
+		)?;
+	Ok(sum_packed(accumulators))
+}
+
+#[cfg(test)]
+mod tests {
+	use std::iter::repeat_with;
+
+	use binius_field::{BinaryField8b, BinaryField128b, PackedBinaryField2x128b};
+	use binius_math::ArithCircuit;
+	use rand::{SeedableRng, rngs::StdRng};
+
+	use super::*;
+
+	#[derive(Debug)]
+	struct ProductComposition {
+		n_vars: usize,
+	}
+
+	impl<P: PackedField> CompositionPoly<P> for ProductComposition {
+		fn n_vars(&self) -> usize {
+			self.n_vars
+		}
+
+		fn degree(&self) -> usize {
+			self.n_vars
+		}
+
+		fn binary_tower_level(&self) -> usize {
+			0
+		}
+
+		fn expression(&self) -> ArithCircuit<P::Scalar> {
+			(0..self.n_vars).map(ArithCircuit::var).product()
+		}
+
+		fn evaluate(&self, query: &[P]) -> Result<P, binius_math::Error> {
+			Ok(query.iter().copied().product())
+		}
+	}
+
+	/// A domain of four finite points, as the round evaluation does not support infinity.
+	fn finite_domain() -> EvaluationDomain<BinaryField8b> {
+		EvaluationDomain::from_points((0..4).map(BinaryField8b::new).collect(), false).unwrap()
+	}
+
+	/// Evaluates the round polynomial scalar by scalar from its definition.
+	fn naive_round_evals(
+		multilinears: &[Vec<BinaryField128b>],
+		domain: &EvaluationDomain<BinaryField8b>,
+	) -> Vec<BinaryField128b> {
+		let half_len = multilinears[0].len() / 2;
+		domain
+			.finite_points()
+			.iter()
+			.map(|&point| {
+				let point = BinaryField128b::from(point);
+				(0..half_len)
+					.map(|i| {
+						multilinears
+							.iter()
+							.map(|multilinear| {
+								let (lo, hi) = (multilinear[i], multilinear[half_len + i]);
+								lo + (hi - lo) * point
+							})
+							.product::<BinaryField128b>()
+					})
+					.sum()
+			})
+			.collect()
+	}
+
+	#[test]
+	fn test_round_evals_match_naive() {
+		type P = PackedBinaryField2x128b;
+
+		let mut rng = StdRng::seed_from_u64(0);
+		let domain = finite_domain();
+		// Large enough to span several parallel chunks.
+		let log_size = 11;
+		for n_multilinears in [1, 2, 3] {
+			let multilinears = repeat_with(|| {
+				repeat_with(|| P::random(&mut rng))
+					.take(1 << (log_size - P::LOG_WIDTH))
+					.collect::<Vec<_>>()
+			})
+			.take(n_multilinears)
+			.collect::<Vec<_>>();
+			let multilinear_slices = multilinears.iter().map(Vec::as_slice).collect::<Vec<_>>();
+			let scalars = multilinears
+				.iter()
+				.map(|multilinear| PackedField::iter_slice(multilinear).collect::<Vec<_>>())
+				.collect::<Vec<_>>();
+			let composition = ProductComposition {
+				n_vars: n_multilinears,
+			};
+
+			let expected = naive_round_evals(&scalars, &domain);
+			assert_eq!(
+				sumcheck_round_evals(&multilinear_slices, &composition, &domain).unwrap(),
+				expected
+			);
+			assert_eq!(
+				sumcheck_round_evals_par(&multilinear_slices, &composition, &domain).unwrap(),
+				expected
+			);
+		}
+	}
+
+	#[test]
+	fn test_round_evals_reject_mismatched_lengths() {
+		type P = PackedBinaryField2x128b;
+
+		let domain = finite_domain();
+		let (lhs, rhs) = (vec![P::zero(); 4], vec![P::zero(); 8]);
+		let composition = ProductComposition { n_vars: 2 };
+		assert!(matches!(
+			sumcheck_round_evals(&[&lhs, &rhs], &composition, &domain),
+			Err(Error::IncorrectMultilinearLengths)
+		));
+	}
+}
@@ -37,3 +37,12 @@ pub trait GroestlShortInternal {
 pub use arch::{Groestl256Parallel, GroestlShortImpl};
 pub use compression::*;
 pub use digest::Groestl256;
+
+/// Grøstl-512 hasher state.
+///
+/// Grøstl-512 uses the long variant of the Grøstl permutation and produces 512-bit digests. Used
+/// with `binius_core`'s `HasherChallenger`, it emits 64 bytes per squeeze instead of 32, which
+/// provides a wider security margin when sampling 128-bit field elements. There is no
+/// architecture-optimized implementation of the long variant, so this is the portable reference
+/// implementation.
+pub type Groestl512 = groestl_crypto::Groestl512;
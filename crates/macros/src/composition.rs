@@ -0,0 +1,207 @@
+// Copyright 2025 Irreducible Inc.
+
+use quote::{ToTokens, format_ident, quote};
+use syn::{Token, parse::Parse, parse_quote, spanned::Spanned};
+
+/// A composition polynomial over the variables `v0, v1, ...` with canonical tower constants.
+///
+/// The expression is kept as a single syntax tree, from which both the evaluation and the
+/// arithmetic expression of the implementation are generated.
+#[derive(Debug)]
+pub(crate) struct CompositionItem {
+	name: Option<syn::Ident>,
+	expr: syn::Expr,
+	n_vars: usize,
+	degree: usize,
+	tower_level: usize,
+}
+
+impl ToTokens for CompositionItem {
+	fn to_tokens(&self, tokens: &mut proc_macro2::TokenStream) {
+		let Self {
+			name,
+			expr,
+			n_vars,
+			degree,
+			tower_level,
+		} = self;
+		let struct_name = name
+			.clone()
+			.unwrap_or_else(|| parse_quote!(UnnamedComposition));
+		let const_field = tower_field(*tower_level);
+
+		let eval = translate(
+			expr,
+			&|i| parse_quote!(query[#i]),
+			&|lit| parse_quote!(P::broadcast(P::Scalar::from(#const_field::new(#lit)))),
+		);
+		let arith_expr = translate(
+			expr,
+			&|i| parse_quote!(binius_math::ArithExpr::<P::Scalar>::Var(#i)),
+			&|lit| {
+				parse_quote!(binius_math::ArithExpr::<P::Scalar>::Const(P::Scalar::from(
+					#const_field::new(#lit)
+				)))
+			},
+		);
+
+		let result = quote! {
+			#[derive(Debug, Clone, Copy)]
+			struct #struct_name;
+
+			impl<P> binius_math::CompositionPoly<P> for #struct_name
+			where
+				P: binius_field::PackedField<Scalar: binius_field::ExtensionField<#const_field>>,
+			{
+				fn n_vars(&self) -> usize {
+					#n_vars
+				}
+
+				fn degree(&self) -> usize {
+					#degree
+				}
+
+				fn binary_tower_level(&self) -> usize {
+					#tower_level
+				}
+
+				fn expression(&self) -> binius_math::ArithCircuit<P::Scalar> {
+					binius_math::ArithCircuit::from(#arith_expr)
+				}
+
+				fn evaluate(&self, query: &[P]) -> Result<P, binius_math::Error> {
+					if query.len() != #n_vars {
+						return Err(binius_math::Error::IncorrectQuerySize {
+							expected: #n_vars,
+							actual: query.len(),
+						});
+					}
+					Ok(#eval)
+				}
+			}
+		};
+
+		if name.is_some() {
+			tokens.extend(result);
+		} else {
+			tokens.extend(quote! {
+				{
+					#result
+					#struct_name
+				}
+			});
+		}
+	}
+}
+
+impl Parse for CompositionItem {
+	fn parse(input: syn::parse::ParseStream) -> syn::Result<Self> {
+		let name = if input.peek(syn::Ident) && input.peek2(Token![=]) {
+			let name = input.parse::<syn::Ident>()?;
+			input.parse::<Token![=]>()?;
+			Some(name)
+		} else {
+			None
+		};
+		let expr = input.parse::<syn::Expr>()?;
+
+		let mut summary = Summary::default();
+		summarize(&expr, &mut summary)?;
+
+		Ok(Self {
+			name,
+			expr,
+			n_vars: summary.n_vars,
+			degree: summary.degree,
+			tower_level: summary.tower_level,
+		})
+	}
+}
+
+/// The properties of a composition expression that are derived from its syntax tree.
+#[derive(Debug, Default)]
+struct Summary {
+	n_vars: usize,
+	degree: usize,
+	tower_level: usize,
+}
+
+/// Accumulates the number of variables and the tower level of `expr` into `summary`, and sets
+/// its degree to the degree of `expr`.
+fn summarize(expr: &syn::Expr, summary: &mut Summary) -> syn::Result<()> {
+	match expr {
+		syn::Expr::Lit(lit) => {
+			let value = int_literal(lit)?.base10_parse::<u128>()?;
+			summary.tower_level = summary.tower_level.max(literal_tower_level(value));
+			summary.degree = 0;
+		}
+		syn::Expr::Path(path) => {
+			let index = var_index(path)?;
+			summary.n_vars = summary.n_vars.max(index + 1);
+			summary.degree = 1;
+		}
+		syn::Expr::Paren(paren) => summarize(&paren.expr, summary)?,
+		syn::Expr::Binary(binary) => {
+			summarize(&binary.left, summary)?;
+			let left = summary.degree;
+			summarize(&binary.right, summary)?;
+			let right = summary.degree;
+			summary.degree = match binary.op {
+				syn::BinOp::Add(_) | syn::BinOp::Sub(_) => left.max(right),
+				syn::BinOp::Mul(_) => left + right,
+				op => return Err(syn::Error::new(op.span(), "unsupported operator")),
+			};
+		}
+		expr => return Err(syn::Error::new(expr.span(), "unsupported expression")),
+	}
+	Ok(())
+}
+
+/// Rebuilds `expr` with its variables and literals replaced.
+fn translate(
+	expr: &syn::Expr,
+	var: &impl Fn(usize) -> syn::Expr,
+	lit: &impl Fn(&syn::LitInt) -> syn::Expr,
+) -> syn::Expr {
+	match expr {
+		syn::Expr::Lit(expr_lit) => lit(int_literal(expr_lit).expect("checked by summarize")),
+		syn::Expr::Path(path) => var(var_index(path).expect("checked by summarize")),
+		syn::Expr::Paren(paren) => {
+			let inner = translate(&paren.expr, var, lit);
+			parse_quote!((#inner))
+		}
+		syn::Expr::Binary(binary) => {
+			let left = translate(&binary.left, var, lit);
+			let right = translate(&binary.right, var, lit);
+			let op = &binary.op;
+			parse_quote!(#left #op #right)
+		}
+		_ => unreachable!("checked by summarize"),
+	}
+}
+
+fn int_literal(expr: &syn::ExprLit) -> syn::Result<&syn::LitInt> {
+	match &expr.lit {
+		syn::Lit::Int(int) => Ok(int),
+		lit => Err(syn::Error::new(lit.span(), "expected an integer literal")),
+	}
+}
+
+/// Parses a variable of the form `v<index>`.
+fn var_index(path: &syn::ExprPath) -> syn::Result<usize> {
+	path.path
+		.get_ident()
+		.and_then(|ident| ident.to_string().strip_prefix('v')?.parse().ok())
+		.ok_or_else(|| syn::Error::new(path.span(), "expected a variable v0, v1, ..."))
+}
+
+/// The level of the smallest binary tower field containing the canonical element `value`.
+const fn literal_tower_level(value: u128) -> usize {
+	let n_bits = u128::BITS - value.leading_zeros();
+	n_bits.next_power_of_two().trailing_zeros() as usize
+}
+
+fn tower_field(tower_level: usize) -> syn::Type {
+	let name = format_ident!("BinaryField{}b", 1usize << tower_level);
+	parse_quote!(binius_field::#name)
+}
@@ -3,6 +3,7 @@
 extern crate proc_macro;
 mod arith_circuit_poly;
 mod arith_expr;
+mod composition;
 mod composition_poly;
 mod deserialize_bytes;
 
@@ -13,7 +14,7 @@ use syn::{Data, DeriveInput, Fields, ItemImpl, parse_macro_input, parse_quote, s
 
 use crate::{
 	arith_circuit_poly::ArithCircuitPolyItem, arith_expr::ArithExprItem,
-	composition_poly::CompositionPolyItem,
+	composition::CompositionItem, composition_poly::CompositionPolyItem,
 };
 
 /// Useful for concisely creating structs that implement CompositionPoly.
@@ -46,6 +47,42 @@ pub fn composition_poly(input: TokenStream) -> TokenStream {
 		.into()
 }
 
+/// Implements CompositionPoly for an arithmetic expression over the variables `v0, v1, ...`.
+///
+/// All methods of the implementation are derived from the single expression, so that
+/// `expression` and `evaluate` always agree. The number of variables is one more than the highest
+/// variable index, and the degree is the total degree of the expression. Integer literals are
+/// constants of the canonical binary tower, and the binary tower level is the level of the
+/// smallest tower field containing all of them.
+///
+/// ```
+/// use binius_macros::composition;
+/// use binius_math::CompositionPoly;
+/// use binius_field::{BinaryField8b, BinaryField128b as F, Field};
+///
+/// // Defines a named struct without any fields that implements CompositionPoly
+/// composition!(MyComposition = v0 * v1 + v2 * 125);
+/// let query = [F::ONE, F::ONE, F::ONE];
+/// assert_eq!(
+///     MyComposition.evaluate(&query).unwrap(),
+///     F::ONE + F::from(BinaryField8b::new(125))
+/// );
+/// assert_eq!(CompositionPoly::<F>::degree(&MyComposition), 2);
+/// assert_eq!(CompositionPoly::<F>::binary_tower_level(&MyComposition), 3);
+///
+/// // If you omit the name you get an anonymous instance instead, which can be used inline
+/// assert_eq!(
+///     composition!(v0 * v1 + v2 * 125).evaluate(&query).unwrap(),
+///     F::ONE + F::from(BinaryField8b::new(125))
+/// );
+/// ```
+#[proc_macro]
+pub fn composition(input: TokenStream) -> TokenStream {
+	parse_macro_input!(input as CompositionItem)
+		.into_token_stream()
+		.into()
+}
+
 /// Define polynomial expressions compactly using named positional arguments
 ///
 /// ```
@@ -205,6 +205,30 @@ pub enum ArithCircuitStep<F: Field> {
 	Var(usize),
 }
 
+/// An instruction of a register machine that evaluates an [`ArithCircuit`].
+///
+/// Every instruction writes its result to the register `dst`, reading its operands from registers
+/// that were written by earlier instructions.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ArithInstruction<F: Field> {
+	LoadVar { dst: usize, var: usize },
+	LoadConst { dst: usize, value: F },
+	Add { dst: usize, lhs: usize, rhs: usize },
+	Mul { dst: usize, lhs: usize, rhs: usize },
+}
+
+impl<F: Field> ArithInstruction<F> {
+	/// The register written by the instruction.
+	pub const fn dst(&self) -> usize {
+		match *self {
+			Self::LoadVar { dst, .. }
+			| Self::LoadConst { dst, .. }
+			| Self::Add { dst, .. }
+			| Self::Mul { dst, .. } => dst,
+		}
+	}
+}
+
 impl<F: Field> Default for ArithCircuitStep<F> {
 	fn default() -> Self {
 		Self::Const(F::ZERO)
@@ -365,6 +389,28 @@ impl<F: Field> ArithCircuit<F> {
 	}
 
 	pub fn evaluate(&self, query: &[F]) -> Result<F, Error> {
+		Ok(self.evaluate_with(|index| query[index]))
+	}
+
+	/// Evaluates the circuit with the missing inputs, those that are `None`, set to zero.
+	///
+	/// This is a debugging aid for inspecting partially built circuits, not an operation of any
+	/// protocol.
+	///
+	/// ## Throws
+	///
+	/// * `Error::IncorrectQuerySize` if the query has fewer than [`Self::n_vars`] entries
+	pub fn evaluate_partial(&self, query: &[Option<F>]) -> Result<F, Error> {
+		if query.len() < self.n_vars() {
+			return Err(Error::IncorrectQuerySize {
+				expected: self.n_vars(),
+				actual: query.len(),
+			});
+		}
+		Ok(self.evaluate_with(|index| query[index].unwrap_or(F::ZERO)))
+	}
+
+	fn evaluate_with(&self, var: impl Fn(usize) -> F) -> F {
 		let mut step_evals = Vec::<F>::with_capacity(self.steps.len());
 		for step in &self.steps {
 			match step {
@@ -376,10 +422,86 @@ impl<F: Field> ArithCircuit<F> {
 				}
 				ArithCircuitStep::Pow(base, exp) => step_evals.push(step_evals[*base].pow(*exp)),
 				ArithCircuitStep::Const(value) => step_evals.push(*value),
-				ArithCircuitStep::Var(index) => step_evals.push(query[*index]),
+				ArithCircuitStep::Var(index) => step_evals.push(var(*index)),
 			}
 		}
-		Ok(step_evals.pop().unwrap_or_default())
+		step_evals.pop().unwrap_or_default()
+	}
+
+	/// Lowers the circuit to a list of register machine instructions.
+	///
+	/// The instructions are in topological order and each writes a fresh register, so the number
+	/// of registers equals the number of instructions. Powers are expanded into multiplications
+	/// with the square-and-multiply method, except that a power with exponent 0 loads the constant
+	/// one, and a power with exponent 1 emits no instruction and evaluates to the register of its
+	/// base. The value of the circuit is thus left in the register of the last instruction, unless
+	/// the circuit ends in a power with exponent 1 of an earlier step, whose register then holds
+	/// it.
+	pub fn to_instructions(&self) -> Vec<ArithInstruction<F>> {
+		fn push_instruction<F: Field>(
+			instructions: &mut Vec<ArithInstruction<F>>,
+			make: impl FnOnce(usize) -> ArithInstruction<F>,
+		) -> usize {
+			let dst = instructions.len();
+			instructions.push(make(dst));
+			dst
+		}
+
+		let mut instructions = Vec::with_capacity(self.steps.len());
+		let mut step_registers = Vec::<usize>::with_capacity(self.steps.len());
+		for step in &self.steps {
+			let register = match *step {
+				ArithCircuitStep::Var(var) => push_instruction(&mut instructions, |dst| {
+					ArithInstruction::LoadVar { dst, var }
+				}),
+				ArithCircuitStep::Const(value) => push_instruction(&mut instructions, |dst| {
+					ArithInstruction::LoadConst { dst, value }
+				}),
+				ArithCircuitStep::Add(left, right) => {
+					let (lhs, rhs) = (step_registers[left], step_registers[right]);
+					push_instruction(&mut instructions, |dst| ArithInstruction::Add {
+						dst,
+						lhs,
+						rhs,
+					})
+				}
+				ArithCircuitStep::Mul(left, right) => {
+					let (lhs, rhs) = (step_registers[left], step_registers[right]);
+					push_instruction(&mut instructions, |dst| ArithInstruction::Mul {
+						dst,
+						lhs,
+						rhs,
+					})
+				}
+				ArithCircuitStep::Pow(_, 0) => push_instruction(&mut instructions, |dst| {
+					ArithInstruction::LoadConst { dst, value: F::ONE }
+				}),
+				ArithCircuitStep::Pow(base, exp) => {
+					let base = step_registers[base];
+					let mut acc = base;
+					for i in (0..exp.ilog2()).rev() {
+						acc =
+							push_instruction(&mut instructions, move |dst| ArithInstruction::Mul {
+								dst,
+								lhs: acc,
+								rhs: acc,
+							});
+						if (exp >> i) & 1 == 1 {
+							acc = push_instruction(&mut instructions, move |dst| {
+								ArithInstruction::Mul {
+									dst,
+									lhs: acc,
+									rhs: base,
+								}
+							});
+						}
+					}
+					acc
+				}
+			};
+			step_registers.push(register);
+		}
+		instructions
 	}
 
 	pub fn convert_field<FTgt: Field + From<F>>(&self) -> ArithCircuit<FTgt> {
@@ -537,6 +659,63 @@ impl<F: Field> ArithCircuit<F> {
 		usage
 	}
 
+	/// Renders the expression in infix notation with the minimal parentheses.
+	///
+	/// Unlike the [`Display`] implementation, which parenthesizes every operation, parentheses are
+	/// only written where the precedence of the operators requires them, with powers binding
+	/// tighter than products and products tighter than sums. Variables are written as `x{index}`
+	/// and constants in the [`Display`] form of the field.
+	pub fn to_infix_string(&self) -> String {
+		// Precedence levels of sums, products, powers and atoms.
+		const ADD: u8 = 0;
+		const MUL: u8 = 1;
+		const POW: u8 = 2;
+		const ATOM: u8 = 3;
+
+		fn write_step<F: Field>(
+			step: usize,
+			steps: &[ArithCircuitStep<F>],
+			min_precedence: u8,
+			out: &mut String,
+		) {
+			let (precedence, rendered) = match &steps[step] {
+				ArithCircuitStep::Const(value) => (ATOM, format!("{value}")),
+				ArithCircuitStep::Var(index) => (ATOM, format!("x{index}")),
+				ArithCircuitStep::Add(left, right) => {
+					let mut rendered = String::new();
+					write_step(*left, steps, ADD, &mut rendered);
+					rendered.push_str(" + ");
+					write_step(*right, steps, ADD, &mut rendered);
+					(ADD, rendered)
+				}
+				ArithCircuitStep::Mul(left, right) => {
+					let mut rendered = String::new();
+					write_step(*left, steps, MUL, &mut rendered);
+					rendered.push_str(" * ");
+					write_step(*right, steps, MUL, &mut rendered);
+					(MUL, rendered)
+				}
+				ArithCircuitStep::Pow(base, exp) => {
+					let mut rendered = String::new();
+					write_step(*base, steps, ATOM, &mut rendered);
+					rendered.push_str(&format!("^{exp}"));
+					(POW, rendered)
+				}
+			};
+			if precedence < min_precedence {
+				out.push('(');
+				out.push_str(&rendered);
+				out.push(')');
+			} else {
+				out.push_str(&rendered);
+			}
+		}
+
+		let mut out = String::new();
+		write_step(self.steps.len() - 1, &self.steps, ADD, &mut out);
+		out
+	}
+
 	/// Fold constants in the circuit.
 	fn optimize_constants(&mut self) {
 		for step_index in 0..self.steps.len() {
@@ -851,6 +1030,17 @@ impl<F: Field> PartialEq for ArithCircuit<F> {
 	}
 }
 
+/// Hashes the structure of the expression, consistently with [`PartialEq`].
+impl<F: Field> Hash for ArithCircuit<F> {
+	fn hash<H: Hasher>(&self, state: &mut H) {
+		StepNode {
+			index: self.steps.len() - 1,
+			steps: &self.steps,
+		}
+		.hash(state);
+	}
+}
+
 impl<F: Field> Add for ArithCircuit<F> {
 	type Output = Self;
 
@@ -1153,14 +1343,30 @@ impl Add for EvalCost {
 
 #[cfg(test)]
 mod tests {
-	use std::collections::HashSet;
+	use std::{collections::HashSet, iter::repeat_with};
 
 	use assert_matches::assert_matches;
 	use binius_field::{BinaryField, BinaryField1b, BinaryField8b, BinaryField128b};
 	use binius_utils::{DeserializeBytes, SerializationMode, SerializeBytes};
+	use rand::{SeedableRng, rngs::StdRng};
 
 	use super::*;
 
+	#[test]
+	fn test_to_infix_string() {
+		type F = BinaryField8b;
+		let [x0, x1, x2] = [0, 1, 2].map(ArithCircuit::<F>::var);
+
+		let expr = x0.clone() * x1.clone() + x2.clone() * ArithCircuit::constant(F::new(125));
+		assert_eq!(expr.to_infix_string(), "x0 * x1 + x2 * 0x7d");
+
+		let expr = (x0.clone() + x1.clone()) * x2.clone();
+		assert_eq!(expr.to_infix_string(), "(x0 + x1) * x2");
+
+		let expr = (x0.clone() * x1).pow(2) + x0.clone().pow(3) + (x0 + x2).pow(2);
+		assert_eq!(expr.to_infix_string(), "(x0 * x1)^2 + x0^3 + (x0 + x2)^2");
+	}
+
 	#[test]
 	fn test_degree_with_pow() {
 		let expr = ArithCircuit::constant(BinaryField8b::new(6)).pow(7);
@@ -1430,6 +1636,25 @@ mod tests {
 		assert_eq!(circuit, expected_circuit);
 	}
 
+	#[test]
+	fn test_hash_is_structural() {
+		type F = BinaryField8b;
+		let circuit = (ArithCircuit::<F>::var(0) + ArithCircuit::var(1))
+			* (ArithCircuit::var(0) + ArithCircuit::var(1));
+		let mut deduplicated = circuit.clone();
+		deduplicated.deduplicate_steps();
+		assert_ne!(circuit.steps, deduplicated.steps);
+
+		let hash = |circuit: &ArithCircuit<F>| {
+			let mut hasher = std::hash::DefaultHasher::new();
+			circuit.hash(&mut hasher);
+			hasher.finish()
+		};
+		assert_eq!(circuit, deduplicated);
+		assert_eq!(hash(&circuit), hash(&deduplicated));
+		assert_ne!(hash(&circuit), hash(&(ArithCircuit::var(0) * ArithCircuit::var(1))));
+	}
+
 	#[test]
 	fn test_compress_unused_steps() {
 		type F = BinaryField8b;
@@ -1526,4 +1751,78 @@ mod tests {
 			.unwrap();
 		assert_eq!(result, F::new(2) + F::new(3) * (F::new(4) + F::new(5)).pow(5));
 	}
+
+	#[test]
+	fn test_evaluate_partial() {
+		type F = BinaryField8b;
+		let expr = ArithCircuit::<F>::var(0) * ArithCircuit::var(1)
+			+ ArithCircuit::var(2) * ArithCircuit::constant(F::new(125));
+		let result = expr
+			.evaluate_partial(&[Some(F::new(2)), Some(F::new(3)), None])
+			.unwrap();
+		assert_eq!(result, F::new(2) * F::new(3));
+		assert_eq!(
+			expr.evaluate_partial(&[Some(F::new(2)), Some(F::new(3)), Some(F::new(4))])
+				.unwrap(),
+			expr.evaluate(&[F::new(2), F::new(3), F::new(4)]).unwrap()
+		);
+		assert_matches!(
+			expr.evaluate_partial(&[Some(F::new(2)), None]),
+			Err(Error::IncorrectQuerySize {
+				expected: 3,
+				actual: 2
+			})
+		);
+	}
+
+	fn execute_instructions<F: Field>(instructions: &[ArithInstruction<F>], query: &[F]) -> F {
+		let mut registers = vec![F::ZERO; instructions.len()];
+		for instruction in instructions {
+			registers[instruction.dst()] = match *instruction {
+				ArithInstruction::LoadVar { var, .. } => query[var],
+				ArithInstruction::LoadConst { value, .. } => value,
+				ArithInstruction::Add { lhs, rhs, .. } => registers[lhs] + registers[rhs],
+				ArithInstruction::Mul { lhs, rhs, .. } => registers[lhs] * registers[rhs],
+			};
+		}
+		registers[instructions
+			.last()
+			.expect("instructions are not empty")
+			.dst()]
+	}
+
+	#[test]
+	fn test_to_instructions() {
+		type F = BinaryField128b;
+		let mut rng = StdRng::seed_from_u64(0);
+
+		let x = || ArithCircuit::<F>::var(0);
+		let y = || ArithCircuit::<F>::var(1);
+		let z = || ArithCircuit::<F>::var(2);
+		let exprs = [
+			x(),
+			ArithCircuit::constant(F::new(7)),
+			x() * y() + z(),
+			(x() + y()) * (z() + ArithCircuit::constant(F::new(3))).pow(5),
+			x().pow(0) + y().pow(1) + z().pow(12),
+			(x() * y() + z()).pow(3) * (x() * y() + z()),
+		];
+
+		for expr in exprs {
+			let instructions = expr.to_instructions();
+			for (i, instruction) in instructions.iter().enumerate() {
+				assert_eq!(instruction.dst(), i);
+			}
+
+			for _ in 0..8 {
+				let query = repeat_with(|| <F as Field>::random(&mut rng))
+					.take(3)
+					.collect::<Vec<_>>();
+				assert_eq!(
+					execute_instructions(&instructions, &query),
+					expr.evaluate(&query).unwrap()
+				);
+			}
+		}
+	}
 }
@@ -0,0 +1,103 @@
+// Copyright 2025 Irreducible Inc.
+
+use binius_field::{PackedField, util::inner_product_par};
+use binius_maybe_rayon::prelude::*;
+use binius_utils::bail;
+use tracing::instrument;
+
+use crate::{Error, MultilinearQueryRef};
+
+/// Evaluates several multilinears, given by their packed evaluations over the hypercube, at the
+/// same point.
+///
+/// The point is supplied as its tensor expansion, which is computed once by the caller and shared
+/// across all multilinears, so that each evaluation reduces to a single inner product.
+///
+/// Every multilinear must have exactly as many packed elements as the query expansion.
+#[instrument(skip_all, level = "debug")]
+pub fn batch_evaluate<'a, P: PackedField>(
+	multilins: &[&[P]],
+	query: impl Into<MultilinearQueryRef<'a, P>>,
+) -> Result<Vec<P::Scalar>, Error> {
+	let query = query.into();
+	let expansion = query.expansion();
+
+	if let Some(multilin) = multilins
+		.iter()
+		.find(|multilin| multilin.len() != expansion.len())
+	{
+		bail!(Error::IncorrectQuerySize {
+			expected: expansion.len(),
+			actual: multilin.len(),
+		});
+	}
+
+	Ok(multilins
+		.par_iter()
+		.map(|multilin| inner_product_par(expansion, multilin))
+		.collect())
+}
+
+#[cfg(test)]
+mod tests {
+	use assert_matches::assert_matches;
+	use binius_field::{BinaryField128b, Field, PackedBinaryField2x128b};
+	use rand::{SeedableRng, rngs::StdRng};
+
+	use super::*;
+	use crate::{MultilinearExtension, MultilinearQuery};
+
+	type P = PackedBinaryField2x128b;
+	type F = BinaryField128b;
+
+	#[test]
+	fn test_batch_evaluate_matches_individual_evaluation() {
+		const N_VARS: usize = 10;
+		const N_MULTILINS: usize = 8;
+
+		let mut rng = StdRng::seed_from_u64(0);
+		let multilins = (0..N_MULTILINS)
+			.map(|_| {
+				(0..1 << (N_VARS - P::LOG_WIDTH))
+					.map(|_| P::random(&mut rng))
+					.collect::<Vec<_>>()
+			})
+			.collect::<Vec<_>>();
+		let point = (0..N_VARS)
+			.map(|_| <F as Field>::random(&mut rng))
+			.collect::<Vec<_>>();
+
+		let query = MultilinearQuery::<P>::expand(&point);
+		let multilin_refs = multilins.iter().map(Vec::as_slice).collect::<Vec<_>>();
+		let evals = batch_evaluate(&multilin_refs, &query).unwrap();
+
+		let expected_evals = multilins
+			.iter()
+			.map(|multilin| {
+				MultilinearExtension::from_values_slice(multilin)
+					.unwrap()
+					.evaluate(&query)
+					.unwrap()
+			})
+			.collect::<Vec<F>>();
+		assert_eq!(evals, expected_evals);
+	}
+
+	#[test]
+	fn test_batch_evaluate_length_mismatch() {
+		let mut rng = StdRng::seed_from_u64(0);
+		let point = (0..4)
+			.map(|_| <F as Field>::random(&mut rng))
+			.collect::<Vec<_>>();
+		let query = MultilinearQuery::<P>::expand(&point);
+		let multilin = vec![P::zero(); 4];
+
+		assert_matches!(
+			batch_evaluate(&[multilin.as_slice()], &query),
+			Err(Error::IncorrectQuerySize {
+				expected: 8,
+				actual: 4
+			})
+		);
+	}
+}
@@ -37,6 +37,21 @@ where
 	/// - There are no operations performed between scalar values within the same packed value.
 	fn evaluate(&self, query: &[P]) -> Result<P, Error>;
 
+	/// Evaluates the polynomial with the missing inputs, those that are `None`, set to zero.
+	///
+	/// This is a debugging aid for evaluating compositions in partially built circuits, before all
+	/// of their inputs are wired, and not an operation of any protocol.
+	///
+	/// This method has a default implementation.
+	fn evaluate_partial(&self, query: &[Option<P>]) -> Result<P, Error> {
+		stackalloc_with_default(query.len(), |full_query| {
+			for (full_query_elem, query_elem) in full_query.iter_mut().zip(query) {
+				*full_query_elem = query_elem.unwrap_or_else(P::zero);
+			}
+			self.evaluate(full_query)
+		})
+	}
+
 	/// Batch evaluation that admits non-strided argument layout.
 	/// `batch_query` is a slice of slice references of equal length, which furthermore should equal
 	/// the length of `evals` parameter.
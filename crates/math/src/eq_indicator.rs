@@ -0,0 +1,165 @@
+// Copyright 2025 Irreducible Inc.
+
+use binius_field::{Field, PackedField};
+use binius_utils::bail;
+
+use crate::{Error, EvaluationOrder, eq_ind_partial_eval};
+
+/// The equality indicator of a point, folded one sumcheck round at a time.
+///
+/// After $k$ rounds with challenges $c_0, \ldots, c_{k-1}$, the folded indicator is the equality
+/// indicator with the first $k$ substituted variables bound to the challenges, which equals
+/// $\widetilde{eq}(r_{sub}, c) \cdot \widetilde{eq}(r_{rem}, X)$ for the substituted and remaining
+/// coordinates $r_{sub}$ and $r_{rem}$ of the point. It is stored as the scalar prefix and the
+/// expansion over the remaining coordinates, so that the memory at round $k$ is $O(2^{n - k})$.
+#[derive(Debug, Clone)]
+pub struct EqIndicator<P: PackedField> {
+	evaluation_order: EvaluationOrder,
+	point: Vec<P::Scalar>,
+	scalar: P::Scalar,
+	expansion: Vec<P>,
+}
+
+impl<P: PackedField> EqIndicator<P> {
+	/// Expands the equality indicator of `point`, to be folded in `evaluation_order`.
+	pub fn new(evaluation_order: EvaluationOrder, point: &[P::Scalar]) -> Self {
+		Self {
+			evaluation_order,
+			point: point.to_vec(),
+			scalar: P::Scalar::ONE,
+			expansion: eq_ind_partial_eval(point),
+		}
+	}
+
+	/// The number of remaining variables.
+	pub fn n_vars(&self) -> usize {
+		self.point.len()
+	}
+
+	/// The equality indicator of the substituted coordinates at the challenges.
+	pub const fn scalar(&self) -> P::Scalar {
+		self.scalar
+	}
+
+	/// The equality indicator of the remaining coordinates over the hypercube, without the
+	/// [`Self::scalar`] factor.
+	pub fn expansion(&self) -> &[P] {
+		&self.expansion
+	}
+
+	/// The folded equality indicator over the hypercube of the remaining variables.
+	pub fn folded(&self) -> Vec<P> {
+		let scalar = P::broadcast(self.scalar);
+		self.expansion.iter().map(|&eval| eval * scalar).collect()
+	}
+
+	/// Binds the next variable in the evaluation order to `challenge`.
+	///
+	/// In high-to-low order the expansion is folded in place, by summing its halves. In
+	/// low-to-high order it is expanded anew from the remaining coordinates.
+	///
+	/// ## Throws
+	///
+	/// * `Error::ConstantFold` if there are no remaining variables
+	pub fn next_round(&mut self, challenge: P::Scalar) -> Result<(), Error> {
+		let coordinate = match self.evaluation_order {
+			EvaluationOrder::LowToHigh if !self.point.is_empty() => self.point.remove(0),
+			EvaluationOrder::HighToLow if !self.point.is_empty() => {
+				self.point.pop().expect("point is not empty")
+			}
+			_ => bail!(Error::ConstantFold),
+		};
+		self.scalar *=
+			(P::Scalar::ONE - coordinate) * (P::Scalar::ONE - challenge) + coordinate * challenge;
+
+		let n_vars = self.n_vars();
+		match self.evaluation_order {
+			// The halves of the expansion are its restrictions to the last variable being zero
+			// and one, which sum to the expansion over the remaining coordinates.
+			EvaluationOrder::HighToLow if n_vars >= P::LOG_WIDTH => {
+				let half_len = 1 << (n_vars - P::LOG_WIDTH);
+				let (evals_0, evals_1) = self.expansion.split_at_mut(half_len);
+				for (eval_0, &eval_1) in evals_0.iter_mut().zip(evals_1.iter()) {
+					*eval_0 += eval_1;
+				}
+				self.expansion.truncate(half_len);
+			}
+			_ => self.expansion = eq_ind_partial_eval(&self.point),
+		}
+		Ok(())
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use std::iter::repeat_with;
+
+	use assert_matches::assert_matches;
+	use binius_field::{BinaryField32b, PackedBinaryField4x32b};
+	use rand::{SeedableRng, rngs::StdRng};
+
+	use super::*;
+
+	type P = PackedBinaryField4x32b;
+	type F = BinaryField32b;
+
+	/// Folds the scalar evaluations of a multilinear at its lowest or highest variable.
+	fn fold(evals: &[F], evaluation_order: EvaluationOrder, challenge: F) -> Vec<F> {
+		let half_len = evals.len() / 2;
+		(0..half_len)
+			.map(|i| {
+				let (eval_0, eval_1) = match evaluation_order {
+					EvaluationOrder::LowToHigh => (evals[2 * i], evals[2 * i + 1]),
+					EvaluationOrder::HighToLow => (evals[i], evals[half_len + i]),
+				};
+				eval_0 + (eval_1 - eval_0) * challenge
+			})
+			.collect()
+	}
+
+	fn scalars(packed: &[P], n_vars: usize) -> Vec<F> {
+		P::iter_slice(packed).take(1 << n_vars).collect()
+	}
+
+	#[test]
+	fn test_next_round_matches_folding_full_indicator() {
+		let mut rng = StdRng::seed_from_u64(0);
+		let n_vars = 7;
+		let point = repeat_with(|| <F as Field>::random(&mut rng))
+			.take(n_vars)
+			.collect::<Vec<_>>();
+
+		for evaluation_order in [EvaluationOrder::LowToHigh, EvaluationOrder::HighToLow] {
+			let mut eq_ind = EqIndicator::<P>::new(evaluation_order, &point);
+			let mut expected = scalars(&eq_ind_partial_eval::<P>(&point), n_vars);
+			for round in 0..n_vars {
+				assert_eq!(scalars(&eq_ind.folded(), n_vars - round), expected);
+
+				let challenge = <F as Field>::random(&mut rng);
+				eq_ind.next_round(challenge).unwrap();
+				expected = fold(&expected, evaluation_order, challenge);
+			}
+			assert_eq!(eq_ind.n_vars(), 0);
+			assert_eq!(scalars(&eq_ind.folded(), 0), expected);
+			assert_matches!(eq_ind.next_round(F::ONE), Err(Error::ConstantFold));
+		}
+	}
+
+	#[test]
+	fn test_boolean_challenges_slice_full_indicator() {
+		let mut rng = StdRng::seed_from_u64(0);
+		let n_vars = 6;
+		let point = repeat_with(|| <F as Field>::random(&mut rng))
+			.take(n_vars)
+			.collect::<Vec<_>>();
+		let full = scalars(&eq_ind_partial_eval::<P>(&point), n_vars);
+
+		// Binding the high variables to one selects the last block of the full indicator.
+		let mut eq_ind = EqIndicator::<P>::new(EvaluationOrder::HighToLow, &point);
+		for round in 1..=n_vars {
+			eq_ind.next_round(F::ONE).unwrap();
+			let block_len = 1 << (n_vars - round);
+			assert_eq!(scalars(&eq_ind.folded(), n_vars - round), full[full.len() - block_len..]);
+		}
+	}
+}
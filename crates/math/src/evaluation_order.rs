@@ -1,5 +1,7 @@
 // Copyright 2025 Irreducible Inc.
 
+use binius_field::PackedField;
+
 /// Sumcheck evaluation order.
 ///
 /// While one can reasonably perform sumcheck over any permutation of the variables,
@@ -17,3 +19,60 @@ pub enum EvaluationOrder {
 	/// Substituting higher indexed variables first.
 	HighToLow,
 }
+
+/// Extension trait for querying the evaluation order that performs best for a packed field.
+pub trait OptimalEvaluationOrder: PackedField {
+	/// Returns the preferred sumcheck evaluation order for multilinears over this packed field.
+	///
+	/// Byte-sliced packed fields prefer [`EvaluationOrder::HighToLow`], because low-to-high
+	/// folding needs deinterleaving, which is expensive in byte-sliced layout. All other packed
+	/// fields prefer [`EvaluationOrder::LowToHigh`] for its locality of access.
+	fn optimal_evaluation_order() -> EvaluationOrder {
+		if Self::IS_BYTE_SLICED {
+			EvaluationOrder::HighToLow
+		} else {
+			EvaluationOrder::LowToHigh
+		}
+	}
+}
+
+impl<P: PackedField> OptimalEvaluationOrder for P {}
+
+#[cfg(test)]
+mod tests {
+	use binius_field::{
+		AESTowerField8b, AESTowerField128b, BinaryField1b, BinaryField128b,
+		arch::{OptimalUnderlier, OptimalUnderlierByteSliced},
+		as_packed_field::PackedType,
+	};
+
+	use super::*;
+
+	#[test]
+	fn test_byte_sliced_prefers_high_to_low() {
+		assert_eq!(
+			PackedType::<OptimalUnderlierByteSliced, AESTowerField128b>::optimal_evaluation_order(),
+			EvaluationOrder::HighToLow
+		);
+		assert_eq!(
+			PackedType::<OptimalUnderlierByteSliced, AESTowerField8b>::optimal_evaluation_order(),
+			EvaluationOrder::HighToLow
+		);
+		assert_eq!(
+			PackedType::<OptimalUnderlierByteSliced, BinaryField1b>::optimal_evaluation_order(),
+			EvaluationOrder::HighToLow
+		);
+	}
+
+	#[test]
+	fn test_bit_packed_prefers_low_to_high() {
+		assert_eq!(
+			PackedType::<OptimalUnderlier, BinaryField128b>::optimal_evaluation_order(),
+			EvaluationOrder::LowToHigh
+		);
+		assert_eq!(
+			PackedType::<OptimalUnderlier, AESTowerField128b>::optimal_evaluation_order(),
+			EvaluationOrder::LowToHigh
+		);
+	}
+}
@@ -14,8 +14,10 @@
 //! dependency cycles.
 
 mod arith_expr;
+mod batch_evaluate;
 mod binary_subspace;
 mod composition_poly;
+mod eq_indicator;
 mod error;
 mod evaluation_order;
 mod fold;
@@ -31,8 +33,10 @@ mod tensor_prod_eq_ind;
 mod univariate;
 
 pub use arith_expr::*;
+pub use batch_evaluate::*;
 pub use binary_subspace::*;
 pub use composition_poly::*;
+pub use eq_indicator::*;
 pub use error::*;
 pub use evaluation_order::*;
 pub use fold::*;
@@ -153,6 +153,27 @@ impl<P: PackedField, Data: Deref<Target = [P]>> MultilinearExtension<P, Data> {
 		let subcube_eval = self.packed_evaluate_on_hypercube(index / P::WIDTH)?;
 		Ok(subcube_eval.get(index % P::WIDTH))
 	}
+
+	/// Returns the same multilinear over the subfield `PSub::Scalar`, if all of its hypercube
+	/// evaluations lie in the subfield.
+	///
+	/// The narrower representation is cheaper to commit. Returns `None` if any evaluation escapes
+	/// the subfield.
+	pub fn try_downcast_subfield<PSub>(&self) -> Option<MultilinearExtension<PSub>>
+	where
+		PSub: PackedField,
+		P::Scalar: ExtensionField<PSub::Scalar>,
+	{
+		let scalars = P::iter_slice(&self.evals)
+			.take(self.size())
+			.map(|eval| eval.try_into().ok())
+			.collect::<Option<Vec<PSub::Scalar>>>()?;
+		let evals = scalars
+			.chunks(PSub::WIDTH)
+			.map(|chunk| PSub::from_scalars(chunk.iter().copied()))
+			.collect();
+		Some(MultilinearExtension { mu: self.mu, evals })
+	}
 }
 
 impl<P, Data> MultilinearExtension<P, Data>
@@ -864,4 +885,44 @@ mod tests {
 		)
 		.unwrap();
 	}
+
+	#[test]
+	fn test_try_downcast_subfield() {
+		let mut rng = StdRng::seed_from_u64(0);
+		let values =
+			repeat_with(|| BinaryField128b::from(<BinaryField8b as Field>::random(&mut rng)))
+				.take(1 << 6)
+				.collect::<Vec<_>>();
+		let mle = MultilinearExtension::from_values(values.clone()).unwrap();
+
+		let downcast = mle
+			.try_downcast_subfield::<PackedBinaryField16x8b>()
+			.unwrap();
+		assert_eq!(downcast.n_vars(), 6);
+		for (i, &value) in values.iter().enumerate() {
+			assert_eq!(BinaryField128b::from(downcast.evaluate_on_hypercube(i).unwrap()), value);
+		}
+
+		let mut values = values;
+		values[17] = BinaryField128b::new(1 << 100);
+		let mle = MultilinearExtension::from_values(values).unwrap();
+		assert!(
+			mle.try_downcast_subfield::<PackedBinaryField16x8b>()
+				.is_none()
+		);
+	}
+
+	#[test]
+	fn test_try_downcast_subfield_tiny_nvars() {
+		// Only the first two scalars of the packed element are evaluations of the multilinear.
+		let packed =
+			PackedBinaryField4x32b::from_scalars([1, 2, 0x100, 0x10000].map(BinaryField32b::new));
+		let mle = MultilinearExtension::new(1, vec![packed]).unwrap();
+
+		let downcast = mle
+			.try_downcast_subfield::<PackedBinaryField16x8b>()
+			.unwrap();
+		assert_eq!(downcast.n_vars(), 1);
+		assert_eq!(downcast.evaluate_on_hypercube(1).unwrap(), BinaryField8b::new(2));
+	}
 }
@@ -261,11 +261,18 @@ where
 }
 
 /// Evaluate a univariate polynomial specified by its monomial coefficients.
-pub fn evaluate_univariate<F: Field>(coeffs: &[F], x: F) -> F {
+///
+/// The polynomial is evaluated at every lane of the packed point `x`, with the coefficients
+/// broadcast across lanes. A field is a packed field of width one, so this is also the scalar
+/// evaluation.
+///
+/// Each Horner step is a single packed multiplication, which uses the architecture-specific
+/// multiplication of `P`, followed by an addition, which is a XOR in binary fields.
+pub fn evaluate_univariate<P: PackedField>(coeffs: &[P::Scalar], x: P) -> P {
 	// Evaluate using Horner's method
 	coeffs
 		.iter()
-		.rfold(F::ZERO, |eval, &coeff| eval * x + coeff)
+		.rfold(P::zero(), |eval, &coeff| eval * x + P::broadcast(coeff))
 }
 
 fn compute_barycentric_weights<F: Field>(points: &[F]) -> Result<Vec<F>, Error> {
@@ -308,7 +315,8 @@ mod tests {
 
 	use assert_matches::assert_matches;
 	use binius_field::{
-		AESTowerField32b, BinaryField8b, BinaryField32b, util::inner_product_unchecked,
+		AESTowerField32b, BinaryField8b, BinaryField32b, PackedBinaryField4x32b,
+		util::inner_product_unchecked,
 	};
 	use itertools::assert_equal;
 	use proptest::{collection::vec, proptest};
@@ -381,6 +389,21 @@ mod tests {
 		assert_eq!(evaluate_univariate(&[], x), BinaryField32b::ZERO);
 	}
 
+	#[test]
+	fn test_evaluate_univariate_packed() {
+		let mut rng = StdRng::seed_from_u64(0);
+		for n_coeffs in [0, 1, 2, 7] {
+			let coeffs = repeat_with(|| <BinaryField32b as Field>::random(&mut rng))
+				.take(n_coeffs)
+				.collect::<Vec<_>>();
+			let x = PackedBinaryField4x32b::random(&mut rng);
+			let eval = evaluate_univariate(&coeffs, x);
+			for (eval, x) in eval.iter().zip(x.iter()) {
+				assert_eq!(eval, evaluate_univariate_naive(&coeffs, x));
+			}
+		}
+	}
+
 	#[test]
 	fn test_random_extrapolate() {
 		let mut rng = StdRng::seed_from_u64(0);
@@ -5,7 +5,7 @@ use bytes::{Buf, BufMut};
 use thiserror::Error;
 
 /// Serialize data according to Mode param
-#[auto_impl(Box, &)]
+#[auto_impl(Box, &, Arc)]
 pub trait SerializeBytes {
 	fn serialize(
 		&self,
@@ -64,6 +64,14 @@ impl<T: DeserializeBytes> DeserializeBytes for Box<T> {
 		Ok(Self::new(T::deserialize(read_buf, mode)?))
 	}
 }
+impl<T: DeserializeBytes> DeserializeBytes for std::sync::Arc<T> {
+	fn deserialize(read_buf: impl Buf, mode: SerializationMode) -> Result<Self, SerializationError>
+	where
+		Self: Sized,
+	{
+		Ok(Self::new(T::deserialize(read_buf, mode)?))
+	}
+}
 
 impl SerializeBytes for usize {
 	fn serialize(